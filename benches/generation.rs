@@ -0,0 +1,87 @@
+/// Pomiary wydajności obliczania generacji
+/// 
+/// Mierzy `next_generation`, `count_alive_neighbors` i `generate_random_board`
+/// dla plansz 9, 51, 101 i 201 przy kilku gęstościach żywych komórek. Plansze
+/// są losowane ze stałym ziarnem, więc kolejne pomiary dotyczą tych samych danych.
+/// 
+/// Uruchomienie (criterion porównuje wyniki z poprzednim uruchomieniem):
+/// 
+///     cargo bench --bench generation
+/// 
+/// Argument filtruje pomiary po nazwie, np. `cargo bench --bench generation -- next_generation/201`.
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use Game_of_life::config::rules::GameConfig;
+use Game_of_life::logic::board::Board;
+use Game_of_life::logic::randomizer::generate_random_board;
+
+/// Rozmiary mierzonych plansz
+const BOARD_SIZES: [usize; 4] = [9, 51, 101, 201];
+
+/// Gęstości żywych komórek mierzonych plansz
+const DENSITIES: [f32; 3] = [0.05, 0.25, 0.5];
+
+/// Ziarno losowania plansz
+const SEED: u64 = 42;
+
+/// Konfiguracja losowania planszy o podanej gęstości (bez bonusu za sąsiadów)
+fn config_with_density(density: f32) -> GameConfig {
+    let mut config = GameConfig::default();
+    config.set_randomizer_base_probability(density);
+    config.set_randomizer_neighbor_bonus(0.0);
+    config.set_randomizer_seed(Some(SEED));
+    config
+}
+
+/// Wywołuje `bench` dla każdego rozmiaru i gęstości planszy w grupie o podanej nazwie
+/// 
+/// Identyfikator pomiaru ma postać `<rozmiar>/<gęstość>%`, a przepustowość
+/// jest liczona w komórkach planszy.
+fn bench_sizes_and_densities<F>(c: &mut Criterion, name: &str, mut bench: F)
+where
+    F: FnMut(&mut criterion::Bencher, &Board, &GameConfig),
+{
+    let mut group = c.benchmark_group(name);
+    for size in BOARD_SIZES {
+        group.throughput(Throughput::Elements((size * size) as u64));
+        for density in DENSITIES {
+            let config = config_with_density(density);
+            let board = generate_random_board(&Board::new(size, size), &config);
+            let id = BenchmarkId::new(size.to_string(), format!("{:.0}%", density * 100.0));
+            group.bench_with_input(id, &board, |b, board| bench(b, board, &config));
+        }
+    }
+    group.finish();
+}
+
+fn next_generation(c: &mut Criterion) {
+    bench_sizes_and_densities(c, "next_generation", |b, board, config| {
+        let mut neighbor_counts = Vec::new();
+        b.iter(|| board.next_generation_with_buffer(black_box(config), &mut neighbor_counts));
+    });
+}
+
+fn count_alive_neighbors(c: &mut Criterion) {
+    bench_sizes_and_densities(c, "count_alive_neighbors", |b, board, config| {
+        b.iter(|| {
+            let mut total = 0;
+            for y in 0..board.height() {
+                for x in 0..board.width() {
+                    total += board.count_alive_neighbors(black_box(x), black_box(y), config);
+                }
+            }
+            total
+        });
+    });
+}
+
+fn random_board(c: &mut Criterion) {
+    bench_sizes_and_densities(c, "generate_random_board", |b, board, config| {
+        let empty = Board::new(board.width(), board.height());
+        b.iter(|| generate_random_board(black_box(&empty), config));
+    });
+}
+
+criterion_group!(benches, next_generation, count_alive_neighbors, random_board);
+criterion_main!(benches);
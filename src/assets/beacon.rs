@@ -0,0 +1,23 @@
+use super::patterns::{Pattern, Position};
+
+/// Tworzy wzorzec Beacon - oscylator o okresie 2
+pub fn create_beacon() -> Pattern {
+    let beacon_cells = vec![
+        // Lewy górny blok (bez prawego dolnego rogu)
+        Position::new(0, 0), Position::new(1, 0),
+        Position::new(0, 1),
+        
+        // Prawy dolny blok (bez lewego górnego rogu)
+        Position::new(3, 2),
+        Position::new(2, 3), Position::new(3, 3),
+    ];
+
+    Pattern::new(
+        "Beacon".to_string(),
+        "Oscylator o okresie 2 - dwa bloki, których stykające się rogi migają".to_string(),
+        (4, 4), // rozmiar 4x4
+        (2, 2), // centrum w środku
+        beacon_cells,
+        None, // brak obrazka na razie
+    )
+}
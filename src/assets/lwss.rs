@@ -0,0 +1,24 @@
+use super::patterns::{Pattern, Position};
+
+/// Tworzy wzorzec Lightweight Spaceship (LWSS) - najmniejszy statek poruszający się poziomo
+pub fn create_lwss() -> Pattern {
+    let lwss_cells = vec![
+        // .O..O
+        Position::new(1, 0), Position::new(4, 0),
+        // O....
+        Position::new(0, 1),
+        // O...O
+        Position::new(0, 2), Position::new(4, 2),
+        // OOOO.
+        Position::new(0, 3), Position::new(1, 3), Position::new(2, 3), Position::new(3, 3),
+    ];
+
+    Pattern::new(
+        "LWSS".to_string(),
+        "Lightweight Spaceship - statek przesuwający się o 2 komórki co 4 generacje".to_string(),
+        (5, 4), // rozmiar 5x4
+        (2, 2), // centrum w środku
+        lwss_cells,
+        None, // brak obrazka na razie
+    )
+}
@@ -2,5 +2,9 @@ pub mod patterns;
 pub mod carpet;
 pub mod pulsar;
 pub mod glider_gun;
+pub mod lwss;
+pub mod beacon;
+pub mod toad;
+pub mod r_pentomino;
 
 pub use patterns::*;
@@ -1,5 +1,11 @@
-use std::collections::HashMap;
-use super::{carpet, pulsar, glider_gun};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use super::{carpet, pulsar, glider_gun, lwss, beacon, toad, r_pentomino};
+use crate::logic::board::Board;
+use crate::config::rules::{GameConfig, MAX_BOARD_DIMENSION};
 
 /// Reprezentuje pozycję na planszy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -44,6 +50,72 @@ impl Pattern {
         }
     }
 
+    /// Tworzy wzór z żywych komórek planszy
+    /// 
+    /// Wzór obejmuje prostokąt otaczający żywe komórki, a centrum leży w jego środku.
+    /// Zwraca None dla pustej planszy.
+    pub fn from_board(name: String, board: &Board) -> Option<Pattern> {
+        let (min_x, min_y, max_x, max_y) = board.alive_bounding_box()?;
+        let width = (max_x - min_x + 1) as u32;
+        let height = (max_y - min_y + 1) as u32;
+        
+        let cells = board
+            .iter_alive_cells()
+            .map(|(x, y)| Position::new((x - min_x) as i32, (y - min_y) as i32))
+            .collect();
+        
+        Some(Pattern::new(
+            name,
+            "Wzór zapisany z planszy".to_string(),
+            (width, height),
+            ((width / 2) as i32, (height / 2) as i32), // centrum w środku
+            cells,
+            None, // podgląd rysowany jako miniatura planszy
+        ))
+    }
+
+    /// Zwraca wzór obrócony o 90° zgodnie z ruchem wskazówek zegara
+    pub fn rotated_cw(&self) -> Pattern {
+        let (_, height) = self.size;
+        self.transformed((self.size.1, self.size.0), |x, y| (height as i32 - 1 - y, x))
+    }
+
+    /// Zwraca wzór odbity w poziomie (lewa strona zamienia się z prawą)
+    pub fn mirrored_horizontal(&self) -> Pattern {
+        let (width, _) = self.size;
+        self.transformed(self.size, |x, y| (width as i32 - 1 - x, y))
+    }
+
+    /// Zwraca wzór odbity w pionie (góra zamienia się z dołem)
+    pub fn mirrored_vertical(&self) -> Pattern {
+        let (_, height) = self.size;
+        self.transformed(self.size, |x, y| (x, height as i32 - 1 - y))
+    }
+
+    /// Tworzy nowy wzór, przekształcając komórki i centrum podaną funkcją
+    fn transformed<F>(&self, new_size: (u32, u32), transform: F) -> Pattern
+    where
+        F: Fn(i32, i32) -> (i32, i32),
+    {
+        let cells = self.cells
+            .iter()
+            .map(|pos| {
+                let (x, y) = transform(pos.x, pos.y);
+                Position::new(x, y)
+            })
+            .collect();
+        
+        Pattern::new(
+            self.name.clone(),
+            self.description.clone(),
+            new_size,
+            transform(self.center_offset.0, self.center_offset.1),
+            cells,
+            // Obrazek przedstawia oryginalną orientację
+            None,
+        )
+    }
+
     /// Zwraca pozycje komórek względem podanego centrum
     pub fn get_cells_at_center(&self, center: Position) -> Vec<Position> {
         let offset_x = center.x - self.center_offset.0;
@@ -70,19 +142,738 @@ impl Pattern {
     }
 }
 
+/// Błąd parsowania wzoru w formacie RLE
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RleError {
+    /// Brak linii nagłówka `x = N, y = M`
+    MissingHeader,
+    /// Nieprawidłowy nagłówek
+    InvalidHeader(String),
+    /// Nieznany znak w treści wzoru
+    UnexpectedCharacter { character: char, line: usize },
+    /// Komórka wychodzi poza rozmiar zadeklarowany w nagłówku
+    OutOfBounds { x: u32, y: u32 },
+    /// Treść wzoru nie kończy się znakiem `!`
+    MissingTerminator,
+    /// Rozmiar z nagłówka przekracza największą planszę
+    TooLarge { width: u32, height: u32 },
+}
+
+impl fmt::Display for RleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RleError::MissingHeader => write!(f, "missing RLE header line (x = N, y = M)"),
+            RleError::InvalidHeader(reason) => write!(f, "invalid RLE header: {}", reason),
+            RleError::UnexpectedCharacter { character, line } => {
+                write!(f, "unexpected character '{}' in RLE body on line {}", character, line)
+            }
+            RleError::OutOfBounds { x, y } => {
+                write!(f, "cell ({}, {}) lies outside the size declared in the header", x, y)
+            }
+            RleError::MissingTerminator => write!(f, "RLE body is not terminated with '!'"),
+            RleError::TooLarge { width, height } => write!(
+                f,
+                "pattern size {}x{} exceeds the maximum board size of {}",
+                width, height, MAX_BOARD_DIMENSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RleError {}
+
+impl Pattern {
+    /// Tworzy wzór z tekstu w formacie RLE (Run Length Encoded)
+    /// 
+    /// Obsługuje linie komentarzy `#` (nazwa z `#N`, opis z `#C`/`#c`),
+    /// nagłówek `x = N, y = M, rule = ...` oraz treść złożoną z `b`/`o`/`$`/`!`
+    /// z opcjonalnymi licznikami powtórzeń. Reguła z nagłówka jest ignorowana.
+    /// Nagłówek `x = 0, y = 0` (zapis pustej planszy) daje pusty wzór 0x0.
+    pub fn from_rle(text: &str) -> Result<Pattern, RleError> {
+        let mut name = None;
+        let mut description_lines = Vec::new();
+        let mut size = None;
+        let mut body_lines = Vec::new();
+        
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            
+            if let Some(comment) = line.strip_prefix('#') {
+                // Linie komentarzy mają postać "#<znak> treść"
+                let mut chars = comment.chars();
+                let kind = chars.next();
+                let content = chars.as_str().trim().to_string();
+                match kind {
+                    Some('N') if !content.is_empty() => name = Some(content),
+                    Some('C') | Some('c') if !content.is_empty() => description_lines.push(content),
+                    _ => {}
+                }
+                continue;
+            }
+            
+            if size.is_none() {
+                size = Some(Self::parse_rle_header(line)?);
+                continue;
+            }
+            
+            body_lines.push((index + 1, line));
+        }
+        
+        let (width, height) = size.ok_or(RleError::MissingHeader)?;
+        let cells = Self::parse_rle_body(&body_lines, width, height)?;
+        
+        let description = if description_lines.is_empty() {
+            "Wzór zaimportowany z pliku RLE".to_string()
+        } else {
+            description_lines.join(" ")
+        };
+        
+        Ok(Pattern::new(
+            name.unwrap_or_else(|| RLE_DEFAULT_NAME.to_string()),
+            description,
+            (width, height),
+            ((width / 2) as i32, (height / 2) as i32), // centrum w środku
+            cells,
+            None,
+        ))
+    }
+    
+    /// Parsuje nagłówek RLE i zwraca zadeklarowany rozmiar (szerokość, wysokość)
+    /// 
+    /// Rozmiar większy niż `MAX_BOARD_DIMENSION` jest odrzucany, zanim trafi do planszy.
+    fn parse_rle_header(line: &str) -> Result<(u32, u32), RleError> {
+        let mut width = None;
+        let mut height = None;
+        
+        for part in Self::split_rle_header(line) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| RleError::InvalidHeader(format!("expected key = value, found '{}'", part.trim())))?;
+            let key = key.trim();
+            let value = value.trim();
+            
+            match key {
+                "x" | "y" => {
+                    let parsed = value
+                        .parse::<u32>()
+                        .map_err(|_| RleError::InvalidHeader(format!("'{}' is not a valid {} value", value, key)))?;
+                    if key == "x" {
+                        width = Some(parsed);
+                    } else {
+                        height = Some(parsed);
+                    }
+                }
+                // Reguła i inne klucze nie wpływają na kształt wzoru
+                _ => {}
+            }
+        }
+        
+        match (width, height) {
+            (Some(width), Some(height)) if width as usize > MAX_BOARD_DIMENSION || height as usize > MAX_BOARD_DIMENSION => {
+                Err(RleError::TooLarge { width, height })
+            }
+            (Some(width), Some(height)) => Ok((width, height)),
+            _ => Err(RleError::InvalidHeader("both x and y must be given".to_string())),
+        }
+    }
+    
+    /// Dzieli nagłówek RLE na części "klucz = wartość"
+    /// 
+    /// Nowa część zaczyna się tylko od przecinka, po którym następuje kolejny klucz
+    /// i znak `=`, więc przecinki wewnątrz wartości (np. `rule = B3/S23:T20,10`) są zachowywane.
+    fn split_rle_header(line: &str) -> Vec<&str> {
+        let starts_new_part = |rest: &str| {
+            let rest = rest.trim_start();
+            let key_length = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+            key_length > 0 && rest[key_length..].trim_start().starts_with('=')
+        };
+        
+        let mut parts = Vec::new();
+        let mut part_start = 0;
+        for (index, _) in line.match_indices(',') {
+            if starts_new_part(&line[index + 1..]) {
+                parts.push(&line[part_start..index]);
+                part_start = index + 1;
+            }
+        }
+        parts.push(&line[part_start..]);
+        parts
+    }
+    
+    /// Parsuje treść RLE i zwraca pozycje żywych komórek
+    fn parse_rle_body(lines: &[(usize, &str)], width: u32, height: u32) -> Result<Vec<Position>, RleError> {
+        let mut cells = Vec::new();
+        let mut x: u32 = 0;
+        let mut y: u32 = 0;
+        let mut run_count: Option<u32> = None;
+        
+        for &(line_number, line) in lines {
+            for character in line.chars() {
+                match character {
+                    '0'..='9' => {
+                        let digit = character.to_digit(10).unwrap_or(0);
+                        run_count = Some(run_count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+                    }
+                    'b' | '.' => {
+                        x = x.saturating_add(run_count.take().unwrap_or(1));
+                    }
+                    'o' | 'A' => {
+                        for _ in 0..run_count.take().unwrap_or(1) {
+                            if x >= width || y >= height {
+                                return Err(RleError::OutOfBounds { x, y });
+                            }
+                            cells.push(Position::new(x as i32, y as i32));
+                            x += 1;
+                        }
+                    }
+                    '$' => {
+                        y = y.saturating_add(run_count.take().unwrap_or(1));
+                        x = 0;
+                    }
+                    '!' => return Ok(cells),
+                    c if c.is_whitespace() => {}
+                    c => {
+                        return Err(RleError::UnexpectedCharacter { character: c, line: line_number });
+                    }
+                }
+            }
+        }
+        
+        Err(RleError::MissingTerminator)
+    }
+}
+
+/// Błąd parsowania wzoru w formacie plaintext (.cells)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaintextError {
+    /// Plik nie zawiera żadnego wiersza wzoru
+    Empty,
+    /// Znak inny niż `.` lub `O` w wierszu wzoru
+    UnexpectedCharacter { character: char, line: usize },
+}
+
+impl fmt::Display for PlaintextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlaintextError::Empty => write!(f, "the file contains no pattern rows"),
+            PlaintextError::UnexpectedCharacter { character, line } => {
+                write!(f, "unexpected character '{}' on line {} (expected '.' or 'O')", character, line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlaintextError {}
+
+impl Pattern {
+    /// Tworzy wzór z tekstu w formacie plaintext (.cells)
+    /// 
+    /// Linie zaczynające się od `!` są komentarzami - `!Name:` ustala nazwę wzoru,
+    /// a pozostałe tworzą opis. Wiersze wzoru składają się z `.` (martwa) i `O`
+    /// (żywa); puste linie oznaczają puste wiersze. Rozmiar wynika z najdłuższego
+    /// wiersza i liczby wierszy.
+    pub fn from_plaintext(text: &str) -> Result<Pattern, PlaintextError> {
+        let mut name = None;
+        let mut description_lines = Vec::new();
+        let mut rows: Vec<Vec<bool>> = Vec::new();
+        
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = raw_line.trim_end();
+            
+            if let Some(comment) = line.strip_prefix('!') {
+                let comment = comment.trim();
+                if let Some(pattern_name) = comment.strip_prefix("Name:") {
+                    name = Some(pattern_name.trim().to_string()).filter(|n| !n.is_empty());
+                } else if !comment.is_empty() {
+                    description_lines.push(comment.to_string());
+                }
+                continue;
+            }
+            
+            // Puste linie przed pierwszym wierszem wzoru są pomijane
+            if line.is_empty() && rows.is_empty() {
+                continue;
+            }
+            
+            let row = line
+                .chars()
+                .map(|character| match character {
+                    '.' => Ok(false),
+                    'O' | '*' => Ok(true),
+                    c => Err(PlaintextError::UnexpectedCharacter { character: c, line: index + 1 }),
+                })
+                .collect::<Result<Vec<bool>, _>>()?;
+            rows.push(row);
+        }
+        
+        // Puste linie na końcu pliku nie należą do wzoru
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+        if rows.is_empty() {
+            return Err(PlaintextError::Empty);
+        }
+        
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0) as u32;
+        let height = rows.len() as u32;
+        let cells = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(_, &alive)| alive)
+                    .map(move |(x, _)| Position::new(x as i32, y as i32))
+            })
+            .collect();
+        
+        let description = if description_lines.is_empty() {
+            "Wzór zaimportowany z pliku plaintext".to_string()
+        } else {
+            description_lines.join(" ")
+        };
+        
+        Ok(Pattern::new(
+            name.unwrap_or_else(|| PLAINTEXT_DEFAULT_NAME.to_string()),
+            description,
+            (width, height),
+            ((width / 2) as i32, (height / 2) as i32), // centrum w środku
+            cells,
+            None,
+        ))
+    }
+}
+
+/// Nagłówek plików w formacie Life 1.05
+const LIFE_105_HEADER: &str = "#Life 1.05";
+
+/// Nagłówek plików w formacie Life 1.06
+const LIFE_106_HEADER: &str = "#Life 1.06";
+
+/// Błąd parsowania wzoru w formacie Life 1.05 lub 1.06 (.lif)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifeError {
+    /// Pierwsza linia nie jest oczekiwanym nagłówkiem formatu
+    MissingHeader { expected: &'static str },
+    /// Linia Life 1.06 nie zawiera pary współrzędnych `x y`
+    InvalidCoordinates { line: usize },
+    /// Nieprawidłowy nagłówek bloku Life 1.05 (`#P x y`)
+    InvalidBlockHeader { line: usize },
+    /// Znak inny niż `.` lub `*` w wierszu bloku Life 1.05
+    UnexpectedCharacter { character: char, line: usize },
+    /// Plik nie zawiera żadnej żywej komórki
+    Empty,
+    /// Wzór jest większy niż największa plansza
+    TooLarge { width: u64, height: u64 },
+}
+
+impl fmt::Display for LifeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LifeError::MissingHeader { expected } => write!(f, "the file does not start with \"{}\"", expected),
+            LifeError::InvalidCoordinates { line } => {
+                write!(f, "line {} is not a pair of integer coordinates \"x y\"", line)
+            }
+            LifeError::InvalidBlockHeader { line } => {
+                write!(f, "line {} is not a valid block header \"#P x y\"", line)
+            }
+            LifeError::UnexpectedCharacter { character, line } => {
+                write!(f, "unexpected character '{}' on line {} (expected '.' or '*')", character, line)
+            }
+            LifeError::Empty => write!(f, "the file contains no live cells"),
+            LifeError::TooLarge { width, height } => write!(
+                f,
+                "pattern size {}x{} exceeds the maximum board size of {}",
+                width, height, MAX_BOARD_DIMENSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LifeError {}
+
+impl Pattern {
+    /// Tworzy wzór z tekstu w formacie Life 1.06
+    /// 
+    /// Po nagłówku `#Life 1.06` każda linia zawiera współrzędne `x y` jednej
+    /// żywej komórki (mogą być ujemne). Komórki są przesuwane tak, aby lewy górny
+    /// róg wzoru leżał w (0, 0); powtórzone współrzędne są pomijane.
+    pub fn from_life106(text: &str) -> Result<Pattern, LifeError> {
+        let mut lines = text.lines().enumerate();
+        Self::expect_life_header(&mut lines, LIFE_106_HEADER)?;
+        
+        let mut cells = Vec::new();
+        for (index, raw_line) in lines {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            let coordinates: Vec<i64> = line
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map_err(|_| LifeError::InvalidCoordinates { line: index + 1 })?;
+            let [x, y] = coordinates[..] else {
+                return Err(LifeError::InvalidCoordinates { line: index + 1 });
+            };
+            cells.push((x, y));
+        }
+        
+        Self::from_life_cells(cells, "Wzór zaimportowany z pliku Life 1.06".to_string())
+    }
+    
+    /// Tworzy wzór z tekstu w formacie Life 1.05
+    /// 
+    /// Po nagłówku `#Life 1.05` wzór składa się z bloków: linia `#P x y` podaje
+    /// położenie lewego górnego rogu bloku, a kolejne wiersze `.` (martwa) i `*`
+    /// (żywa) jego zawartość. Linie `#D` tworzą opis, pozostałe linie `#` (np. reguły)
+    /// są pomijane. Wiersze przed pierwszym `#P` należą do bloku w (0, 0).
+    pub fn from_life105(text: &str) -> Result<Pattern, LifeError> {
+        let mut lines = text.lines().enumerate();
+        Self::expect_life_header(&mut lines, LIFE_105_HEADER)?;
+        
+        let mut description_lines = Vec::new();
+        let mut cells = Vec::new();
+        let (mut block_x, mut row_y) = (0i64, 0i64);
+        
+        for (index, raw_line) in lines {
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            
+            if let Some(block) = line.strip_prefix("#P") {
+                let coordinates: Vec<i64> = block
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| LifeError::InvalidBlockHeader { line: index + 1 })?;
+                let [x, y] = coordinates[..] else {
+                    return Err(LifeError::InvalidBlockHeader { line: index + 1 });
+                };
+                (block_x, row_y) = (x, y);
+                continue;
+            }
+            if let Some(description) = line.strip_prefix("#D") {
+                let description = description.trim();
+                if !description.is_empty() {
+                    description_lines.push(description.to_string());
+                }
+                continue;
+            }
+            if line.starts_with('#') {
+                continue;
+            }
+            
+            for (column, character) in line.chars().enumerate() {
+                match character {
+                    '.' => {}
+                    '*' | 'O' => cells.push((block_x + column as i64, row_y)),
+                    c => return Err(LifeError::UnexpectedCharacter { character: c, line: index + 1 }),
+                }
+            }
+            row_y += 1;
+        }
+        
+        let description = if description_lines.is_empty() {
+            "Wzór zaimportowany z pliku Life 1.05".to_string()
+        } else {
+            description_lines.join(" ")
+        };
+        Self::from_life_cells(cells, description)
+    }
+    
+    /// Sprawdza czy pierwsza niepusta linia jest nagłówkiem podanego formatu Life
+    fn expect_life_header<'a>(
+        lines: &mut impl Iterator<Item = (usize, &'a str)>,
+        header: &'static str,
+    ) -> Result<(), LifeError> {
+        let first_line = lines.find(|(_, line)| !line.trim().is_empty()).map(|(_, line)| line.trim());
+        match first_line {
+            Some(line) if line.eq_ignore_ascii_case(header) => Ok(()),
+            _ => Err(LifeError::MissingHeader { expected: header }),
+        }
+    }
+    
+    /// Tworzy wzór z bezwzględnych współrzędnych żywych komórek formatu Life
+    /// 
+    /// Współrzędne są przesuwane do nieujemnych, a centrum leży w środku wzoru.
+    fn from_life_cells(mut cells: Vec<(i64, i64)>, description: String) -> Result<Pattern, LifeError> {
+        cells.sort_unstable();
+        cells.dedup();
+        
+        let min_x = cells.iter().map(|&(x, _)| x).min().ok_or(LifeError::Empty)?;
+        let max_x = cells.iter().map(|&(x, _)| x).max().ok_or(LifeError::Empty)?;
+        let min_y = cells.iter().map(|&(_, y)| y).min().ok_or(LifeError::Empty)?;
+        let max_y = cells.iter().map(|&(_, y)| y).max().ok_or(LifeError::Empty)?;
+        
+        let width = max_x.abs_diff(min_x) + 1;
+        let height = max_y.abs_diff(min_y) + 1;
+        if width > MAX_BOARD_DIMENSION as u64 || height > MAX_BOARD_DIMENSION as u64 {
+            return Err(LifeError::TooLarge { width, height });
+        }
+        
+        let cells = cells
+            .into_iter()
+            .map(|(x, y)| Position::new((x - min_x) as i32, (y - min_y) as i32))
+            .collect();
+        let (width, height) = (width as u32, height as u32);
+        
+        Ok(Pattern::new(
+            LIFE_DEFAULT_NAME.to_string(),
+            description,
+            (width, height),
+            ((width / 2) as i32, (height / 2) as i32), // centrum w środku
+            cells,
+            None,
+        ))
+    }
+}
+
+/// Nazwa nadawana wzorom RLE bez komentarza `#N`
+pub const RLE_DEFAULT_NAME: &str = "RLE Pattern";
+
+/// Nazwa nadawana wzorom plaintext bez komentarza `!Name:`
+pub const PLAINTEXT_DEFAULT_NAME: &str = "Plaintext Pattern";
+
+/// Nazwa nadawana wzorom Life 1.05/1.06 (format nie zapisuje nazwy wzoru)
+pub const LIFE_DEFAULT_NAME: &str = "Life Pattern";
+
+/// Format pliku wzoru używany przy wczytywaniu i zapisywaniu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PatternFormat {
+    /// Run Length Encoded (.rle)
+    #[default]
+    Rle,
+    /// Plaintext (.cells)
+    Plaintext,
+    /// Life 1.05 - bloki wierszy `.`/`*` (.lif)
+    Life105,
+    /// Life 1.06 - lista współrzędnych żywych komórek (.lif)
+    Life106,
+}
+
+impl PatternFormat {
+    /// Wszystkie obsługiwane formaty (do wyboru w interfejsie)
+    pub const ALL: [PatternFormat; 4] = [
+        PatternFormat::Rle,
+        PatternFormat::Plaintext,
+        PatternFormat::Life105,
+        PatternFormat::Life106,
+    ];
+    
+    /// Zwraca nazwę formatu wyświetlaną w interfejsie
+    pub fn name(self) -> &'static str {
+        match self {
+            PatternFormat::Rle => "RLE",
+            PatternFormat::Plaintext => "Plaintext",
+            PatternFormat::Life105 => "Life 1.05",
+            PatternFormat::Life106 => "Life 1.06",
+        }
+    }
+    
+    /// Zwraca rozszerzenie pliku (bez kropki)
+    pub fn extension(self) -> &'static str {
+        match self {
+            PatternFormat::Rle => "rle",
+            PatternFormat::Plaintext => "cells",
+            PatternFormat::Life105 | PatternFormat::Life106 => "lif",
+        }
+    }
+    
+    /// Parsuje tekst wzoru w tym formacie
+    pub fn parse(self, text: &str) -> Result<Pattern, String> {
+        match self {
+            PatternFormat::Rle => Pattern::from_rle(text).map_err(|error| format!("Invalid RLE: {}", error)),
+            PatternFormat::Plaintext => Pattern::from_plaintext(text).map_err(|error| format!("Invalid plaintext: {}", error)),
+            PatternFormat::Life105 => Pattern::from_life105(text).map_err(|error| format!("Invalid Life 1.05: {}", error)),
+            PatternFormat::Life106 => Pattern::from_life106(text).map_err(|error| format!("Invalid Life 1.06: {}", error)),
+        }
+    }
+    
+    /// Zwraca nazwę nadawaną wzorom bez nazwy zapisanej w pliku
+    pub fn default_pattern_name(self) -> &'static str {
+        match self {
+            PatternFormat::Rle => RLE_DEFAULT_NAME,
+            PatternFormat::Plaintext => PLAINTEXT_DEFAULT_NAME,
+            PatternFormat::Life105 | PatternFormat::Life106 => LIFE_DEFAULT_NAME,
+        }
+    }
+    
+    /// Eksportuje żywy obszar planszy w tym formacie
+    /// 
+    /// Konfiguracja dostarcza reguły zapisywane w nagłówku RLE.
+    pub fn export(self, board: &Board, config: &GameConfig) -> String {
+        match self {
+            PatternFormat::Rle => board.to_rle(config),
+            PatternFormat::Plaintext => board.to_plaintext(),
+            PatternFormat::Life105 => board.to_life105(),
+            PatternFormat::Life106 => board.to_life106(),
+        }
+    }
+}
+
+/// Katalog aplikacji w katalogu konfiguracji systemu
+const USER_PATTERNS_DIR: &str = "game_of_life";
+
+/// Nazwa pliku z wzorami dodanymi przez użytkownika
+const USER_PATTERNS_FILE: &str = "user_patterns.json";
+
+/// Rozszerzenie kopii pliku wzorów użytkownika, którego nie udało się w pełni wczytać
+const USER_PATTERNS_BACKUP_EXTENSION: &str = "json.bak";
+
+/// Zwraca ścieżkę pliku wzorów użytkownika w katalogu konfiguracji systemu
+/// 
+/// Linux: `$XDG_CONFIG_HOME` lub `~/.config`, macOS: `~/Library/Application Support`,
+/// Windows: `%APPDATA%`. Zwraca None, jeśli katalogu nie da się ustalić.
+pub fn user_patterns_path() -> Option<PathBuf> {
+    let config_dir = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library").join("Application Support"))
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    };
+    config_dir.map(|dir| dir.join(USER_PATTERNS_DIR).join(USER_PATTERNS_FILE))
+}
+
+/// Wzór użytkownika w postaci zapisywanej w pliku JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredPattern {
+    name: String,
+    description: String,
+    size: (u32, u32),
+    /// Żywe komórki (x, y) względem lewego górnego rogu wzoru
+    cells: Vec<(i32, i32)>,
+}
+
+impl StoredPattern {
+    fn from_pattern(pattern: &Pattern) -> Self {
+        Self {
+            name: pattern.name.clone(),
+            description: pattern.description.clone(),
+            size: pattern.size,
+            cells: pattern.cells.iter().map(|cell| (cell.x, cell.y)).collect(),
+        }
+    }
+    
+    /// Zamienia zapisany wzór na `Pattern`
+    /// 
+    /// Zwraca None, gdy rozmiar przekracza największą planszę lub komórka leży poza wzorem.
+    fn into_pattern(self) -> Option<Pattern> {
+        let (width, height) = self.size;
+        if width as usize > MAX_BOARD_DIMENSION || height as usize > MAX_BOARD_DIMENSION {
+            return None;
+        }
+        let inside = |&(x, y): &(i32, i32)| x >= 0 && y >= 0 && (x as u32) < width && (y as u32) < height;
+        if !self.cells.iter().all(inside) {
+            return None;
+        }
+        
+        Some(Pattern::new(
+            self.name,
+            self.description,
+            self.size,
+            ((width / 2) as i32, (height / 2) as i32), // centrum w środku
+            self.cells.into_iter().map(|(x, y)| Position::new(x, y)).collect(),
+            None,
+        ))
+    }
+}
+
 /// Manager predefiniowanych wzorów
+/// 
+/// Wzory dodane przez użytkownika (zapisane z planszy lub wczytane z pliku)
+/// są zapisywane do pliku wzorów użytkownika i wczytywane przy kolejnym uruchomieniu.
 pub struct PatternManager {
     patterns: HashMap<String, Pattern>,
+    /// Nazwy wzorów dodanych przez użytkownika (tylko one mogą być usuwane)
+    user_patterns: HashSet<String>,
+    /// Plik wzorów użytkownika (None - wzory nie są zapisywane)
+    user_patterns_file: Option<PathBuf>,
+    /// Czy plik wzorów użytkownika nie został w pełni wczytany - przed zapisem
+    /// jest wtedy przenoszony do kopii `.json.bak`, aby nie stracić pominiętych wzorów
+    backup_before_save: bool,
 }
 
 impl PatternManager {
     pub fn new() -> Self {
+        Self::with_user_patterns_file(user_patterns_path())
+    }
+    
+    /// Tworzy manager z wbudowanymi wzorami i wzorami użytkownika z podanego pliku
+    /// 
+    /// Brakujący plik jest pomijany, a z uszkodzonego wczytywane są tylko poprawne wzory.
+    pub fn with_user_patterns_file(user_patterns_file: Option<PathBuf>) -> Self {
         let mut manager = Self {
             patterns: HashMap::new(),
+            user_patterns: HashSet::new(),
+            user_patterns_file,
+            backup_before_save: false,
         };
         manager.load_default_patterns();
+        manager.load_user_patterns();
         manager
     }
+    
+    /// Wczytuje wzory użytkownika zapisane przez `save_user_patterns`
+    /// 
+    /// Niepoprawne wpisy są pomijane. Jeśli pliku nie udało się wczytać w całości,
+    /// przed następnym zapisem jest on przenoszony do kopii zapasowej.
+    fn load_user_patterns(&mut self) {
+        let Some(path) = &self.user_patterns_file else {
+            return;
+        };
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) => {
+                self.backup_before_save = error.kind() != io::ErrorKind::NotFound;
+                return;
+            }
+        };
+        
+        let (patterns, complete) = parse_user_patterns(&text);
+        self.backup_before_save = !complete;
+        for pattern in patterns {
+            self.user_patterns.insert(pattern.name.clone());
+            self.patterns.insert(pattern.name.clone(), pattern);
+        }
+    }
+    
+    /// Zapisuje wszystkie wzory użytkownika do pliku JSON (nadpisując poprzednią zawartość)
+    /// 
+    /// Plik, którego nie udało się w pełni wczytać, jest najpierw przenoszony do kopii
+    /// `.json.bak` - jeśli to się nie uda, zapis jest przerywany, a plik pozostaje bez zmian.
+    fn save_user_patterns(&mut self) -> io::Result<()> {
+        let Some(path) = &self.user_patterns_file else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        if self.backup_before_save {
+            if path.exists() {
+                std::fs::rename(path, path.with_extension(USER_PATTERNS_BACKUP_EXTENSION))?;
+            }
+            self.backup_before_save = false;
+        }
+        
+        let mut names: Vec<&String> = self.user_patterns.iter().collect();
+        names.sort();
+        let stored: Vec<StoredPattern> = names
+            .into_iter()
+            .filter_map(|name| self.patterns.get(name))
+            .map(StoredPattern::from_pattern)
+            .collect();
+        std::fs::write(path, serde_json::to_string_pretty(&stored)?)
+    }
 
     /// Ładuje domyślne wzory
     fn load_default_patterns(&mut self) {
@@ -97,23 +888,382 @@ impl PatternManager {
         // Dodaj Glider Gun
         let glider_gun = glider_gun::create_glider_gun();
         self.patterns.insert("Glider Gun".to_string(), glider_gun);
+        
+        // Dodaj Lightweight Spaceship
+        let lwss = lwss::create_lwss();
+        self.patterns.insert("LWSS".to_string(), lwss);
+        
+        // Dodaj Beacon
+        let beacon = beacon::create_beacon();
+        self.patterns.insert("Beacon".to_string(), beacon);
+        
+        // Dodaj Toad
+        let toad = toad::create_toad();
+        self.patterns.insert("Toad".to_string(), toad);
+        
+        // Dodaj R-pentomino
+        let r_pentomino = r_pentomino::create_r_pentomino();
+        self.patterns.insert("R-pentomino".to_string(), r_pentomino);
     }
 
     pub fn get_pattern(&self, name: &str) -> Option<&Pattern> {
         self.patterns.get(name)
     }
 
+    /// Zwraca wszystkie wzory posortowane alfabetycznie według nazwy
     pub fn get_all_patterns(&self) -> Vec<&Pattern> {
-        self.patterns.values().collect()
+        let mut patterns: Vec<&Pattern> = self.patterns.values().collect();
+        patterns.sort_by_key(|pattern| pattern.name.to_lowercase());
+        patterns
     }
 
-    pub fn add_pattern(&mut self, pattern: Pattern) {
+    /// Dodaje wzór użytkownika i zapisuje wzory użytkownika do pliku
+    /// 
+    /// Wzór jest dostępny także wtedy, gdy zapis do pliku się nie powiódł.
+    pub fn add_pattern(&mut self, pattern: Pattern) -> io::Result<()> {
+        self.user_patterns.insert(pattern.name.clone());
         self.patterns.insert(pattern.name.clone(), pattern);
+        self.save_user_patterns()
+    }
+    
+    /// Sprawdza czy wzór o podanej nazwie został dodany przez użytkownika
+    pub fn is_user_pattern(&self, name: &str) -> bool {
+        self.user_patterns.contains(name)
+    }
+    
+    /// Usuwa wzór użytkownika i zapisuje pozostałe wzory użytkownika do pliku
+    /// 
+    /// Wzorów wbudowanych nie można usunąć. Jeśli wzór użytkownika przesłaniał
+    /// wzór wbudowany o tej samej nazwie, wzór wbudowany jest przywracany.
+    pub fn remove_user_pattern(&mut self, name: &str) -> io::Result<()> {
+        if !self.user_patterns.remove(name) {
+            return Ok(());
+        }
+        self.patterns.remove(name);
+        
+        let mut defaults = Self::with_user_patterns_file(None);
+        if let Some(builtin) = defaults.patterns.remove(name) {
+            self.patterns.insert(name.to_string(), builtin);
+        }
+        
+        self.save_user_patterns()
     }
 }
 
+/// Odczytuje wzory z pliku JSON wzorów użytkownika
+/// 
+/// Każdy wpis jest odczytywany osobno, więc niepoprawny wpis nie usuwa pozostałych.
+/// Zwraca poprawne wzory oraz informację, czy odczytano cały plik bez pomijania wpisów.
+fn parse_user_patterns(text: &str) -> (Vec<Pattern>, bool) {
+    let Ok(entries) = serde_json::from_str::<Vec<serde_json::Value>>(text) else {
+        return (Vec::new(), false);
+    };
+    
+    let total = entries.len();
+    let patterns: Vec<Pattern> = entries
+        .into_iter()
+        .filter_map(|entry| serde_json::from_value::<StoredPattern>(entry).ok())
+        .filter_map(StoredPattern::into_pattern)
+        .collect();
+    let complete = patterns.len() == total;
+    (patterns, complete)
+}
+
 impl Default for PatternManager {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::board::CellState;
+    
+    /// Zwraca posortowane współrzędne komórek wzoru
+    fn sorted_cells(pattern: &Pattern) -> Vec<(i32, i32)> {
+        let mut cells: Vec<(i32, i32)> = pattern.cells.iter().map(|cell| (cell.x, cell.y)).collect();
+        cells.sort_unstable();
+        cells
+    }
+    
+    /// Zwraca ścieżkę pliku wzorów użytkownika w osobnym katalogu tymczasowym testu
+    fn temp_patterns_file(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("game_of_life_{}_{}", test_name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(USER_PATTERNS_FILE)
+    }
+    
+    /// Szybowiec lecący w prawo i w dół
+    fn glider() -> Pattern {
+        Pattern::from_rle("x = 3, y = 3\nbo$2bo$3o!").unwrap()
+    }
+    
+    /// Umieszcza wzór na pustej planszy z podanym marginesem z każdej strony
+    fn board_with_pattern(pattern: &Pattern, margin: usize) -> Board {
+        let (width, height) = pattern.size;
+        let mut board = Board::new(width as usize + 2 * margin, height as usize + 2 * margin);
+        for cell in &pattern.cells {
+            board.set_cell(cell.x as usize + margin, cell.y as usize + margin, CellState::Alive);
+        }
+        board
+    }
+    
+    #[test]
+    fn four_rotations_return_the_original_pattern() {
+        let original = glider();
+        let rotated = original.rotated_cw().rotated_cw().rotated_cw().rotated_cw();
+        assert_eq!(sorted_cells(&rotated), sorted_cells(&original));
+        assert_eq!(rotated.size, original.size);
+        assert_eq!(rotated.center_offset, original.center_offset);
+    }
+    
+    #[test]
+    fn rotation_swaps_dimensions_of_rectangular_pattern() {
+        // Litera L o szerokości 3 i wysokości 2
+        let pattern = Pattern::from_rle("x = 3, y = 2\no$3o!").unwrap();
+        let rotated = pattern.rotated_cw();
+        assert_eq!(rotated.size, (2, 3));
+        assert_eq!(sorted_cells(&rotated), vec![(0, 0), (0, 1), (0, 2), (1, 0)]);
+    }
+    
+    #[test]
+    fn mirroring_flips_the_glider_and_twice_restores_it() {
+        let original = glider();
+        
+        assert_eq!(sorted_cells(&original.mirrored_horizontal()), vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 2)]);
+        assert_eq!(sorted_cells(&original.mirrored_vertical()), vec![(0, 0), (1, 0), (1, 2), (2, 0), (2, 1)]);
+        assert_eq!(sorted_cells(&original.mirrored_horizontal().mirrored_horizontal()), sorted_cells(&original));
+        assert_eq!(sorted_cells(&original.mirrored_vertical().mirrored_vertical()), sorted_cells(&original));
+    }
+    
+    /// Żywe komórki planszy po podanej liczbie generacji reguł Conwaya
+    fn alive_after(board: &Board, generations: usize) -> Vec<(usize, usize)> {
+        let config = GameConfig::default();
+        let mut board = board.clone();
+        for _ in 0..generations {
+            board = board.next_generation(&config);
+        }
+        board.iter_alive_cells().collect()
+    }
+    
+    #[test]
+    fn builtin_patterns_fit_their_declared_size() {
+        let manager = PatternManager::with_user_patterns_file(None);
+        let patterns = manager.get_all_patterns();
+        assert_eq!(patterns.len(), 7);
+        
+        for pattern in patterns {
+            let (width, height) = (pattern.size.0 as i32, pattern.size.1 as i32);
+            assert!(!pattern.cells.is_empty(), "{}", pattern.name);
+            assert!(
+                pattern.cells.iter().all(|cell| (0..width).contains(&cell.x) && (0..height).contains(&cell.y)),
+                "{} has cells outside {}x{}", pattern.name, width, height
+            );
+            
+            // Centrum leży w środku zadeklarowanego rozmiaru (Carpet ma pustą ramkę)
+            assert_eq!(pattern.center_offset, (width / 2, height / 2), "{}", pattern.name);
+            assert_eq!(manager.get_pattern(&pattern.name).map(|p| p.size), Some(pattern.size));
+        }
+    }
+    
+    #[test]
+    fn builtin_oscillators_return_after_their_period() {
+        for (pattern, period) in [(beacon::create_beacon(), 2), (toad::create_toad(), 2)] {
+            let board = board_with_pattern(&pattern, 3);
+            let start = alive_after(&board, 0);
+            assert_ne!(alive_after(&board, 1), start, "{} should change after one generation", pattern.name);
+            assert_eq!(alive_after(&board, period), start, "{} should repeat after {} generations", pattern.name, period);
+        }
+    }
+    
+    #[test]
+    fn lwss_moves_two_cells_every_four_generations() {
+        let lwss = lwss::create_lwss();
+        let board = board_with_pattern(&lwss, 6);
+        let shifted: Vec<(usize, usize)> = alive_after(&board, 0).iter().map(|&(x, y)| (x - 2, y)).collect();
+        
+        for generations in 1..4 {
+            assert_ne!(alive_after(&board, generations), shifted);
+        }
+        assert_eq!(alive_after(&board, 4), shifted);
+    }
+    
+    #[test]
+    fn rle_header_rule_may_contain_commas() {
+        let pattern = Pattern::from_rle("x = 3, y = 3, rule = B3/S23:T20,10\nbo$2bo$3o!\n").unwrap();
+        assert_eq!(pattern.size, (3, 3));
+        assert_eq!(sorted_cells(&pattern), vec![(0, 2), (1, 0), (1, 2), (2, 1), (2, 2)]);
+    }
+    
+    #[test]
+    fn rle_header_keys_are_split_regardless_of_spacing() {
+        let pattern = Pattern::from_rle("x=2,y=1,rule=B36/S23:P4,5\n2o!").unwrap();
+        assert_eq!(pattern.size, (2, 1));
+        
+        assert!(matches!(Pattern::from_rle("x = 3, 3\n!"), Err(RleError::InvalidHeader(_))));
+    }
+    
+    #[test]
+    fn rle_header_larger_than_the_board_limit_is_rejected() {
+        assert_eq!(
+            Pattern::from_rle("x = 4000000000, y = 4000000000\no!").unwrap_err(),
+            RleError::TooLarge { width: 4000000000, height: 4000000000 }
+        );
+        let too_wide = format!("x = {}, y = 1\no!", MAX_BOARD_DIMENSION + 1);
+        assert!(matches!(Pattern::from_rle(&too_wide), Err(RleError::TooLarge { .. })));
+        
+        let largest = format!("x = {0}, y = {0}\no!", MAX_BOARD_DIMENSION);
+        assert!(Pattern::from_rle(&largest).is_ok());
+    }
+    
+    #[test]
+    fn plaintext_reads_name_description_and_cells() {
+        let text = "!Name: Glider\n!Lecący w prawo i w dół\n.O.\n..O\nOOO\n";
+        let pattern = Pattern::from_plaintext(text).unwrap();
+        
+        assert_eq!(pattern.name, "Glider");
+        assert_eq!(pattern.description, "Lecący w prawo i w dół");
+        assert_eq!(pattern.size, (3, 3));
+        assert_eq!(sorted_cells(&pattern), sorted_cells(&glider()));
+    }
+    
+    #[test]
+    fn plaintext_keeps_inner_blank_rows_and_pads_short_rows() {
+        let pattern = Pattern::from_plaintext("\nO\n\n...O\n\n").unwrap();
+        assert_eq!(pattern.size, (4, 3));
+        assert_eq!(sorted_cells(&pattern), vec![(0, 0), (3, 2)]);
+    }
+    
+    #[test]
+    fn pulsar_survives_a_plaintext_round_trip() {
+        let pulsar = pulsar::create_pulsar();
+        let exported = board_with_pattern(&pulsar, 4).to_plaintext();
+        
+        let imported = Pattern::from_plaintext(&exported).unwrap();
+        assert_eq!(imported.size, pulsar.size);
+        assert_eq!(sorted_cells(&imported), sorted_cells(&pulsar));
+        assert_eq!(imported.name, PLAINTEXT_DEFAULT_NAME);
+        
+        // Nazwa z komentarza `!Name:` nie wpływa na komórki
+        let named = Pattern::from_plaintext(&format!("!Name: Pulsar\n!Okres 3\n{}", exported)).unwrap();
+        assert_eq!(named.name, "Pulsar");
+        assert_eq!(named.description, "Okres 3");
+        assert_eq!(named.size, pulsar.size);
+        assert_eq!(sorted_cells(&named), sorted_cells(&pulsar));
+    }
+    
+    #[test]
+    fn plaintext_rejects_unknown_characters_and_empty_files() {
+        assert_eq!(
+            Pattern::from_plaintext("!komentarz\n.O.\n.X.\n").unwrap_err(),
+            PlaintextError::UnexpectedCharacter { character: 'X', line: 3 }
+        );
+        assert_eq!(Pattern::from_plaintext("!tylko komentarz\n").unwrap_err(), PlaintextError::Empty);
+    }
+    
+    #[test]
+    fn life106_shifts_negative_coordinates_to_origin() {
+        let text = "#Life 1.06\n0 -1\n1 0\n-1 1\n0 1\n1 1\n1 1\n";
+        let pattern = Pattern::from_life106(text).unwrap();
+        
+        assert_eq!(pattern.size, (3, 3));
+        assert_eq!(sorted_cells(&pattern), sorted_cells(&glider()));
+    }
+    
+    #[test]
+    fn life106_rejects_bad_input() {
+        assert_eq!(
+            Pattern::from_life106("0 0\n").unwrap_err(),
+            LifeError::MissingHeader { expected: LIFE_106_HEADER }
+        );
+        assert_eq!(Pattern::from_life106("#Life 1.06\n0 0\n1\n").unwrap_err(), LifeError::InvalidCoordinates { line: 3 });
+        assert_eq!(Pattern::from_life106("#Life 1.06\n").unwrap_err(), LifeError::Empty);
+        assert!(matches!(Pattern::from_life106("#Life 1.06\n0 0\n5000 0\n"), Err(LifeError::TooLarge { .. })));
+    }
+    
+    #[test]
+    fn life105_places_blocks_and_reads_description() {
+        let text = "#Life 1.05\n#D Dwa bloki\n#N\n#P -1 -1\n.*.\n..*\n***\n#P 5 0\n**\n";
+        let pattern = Pattern::from_life105(text).unwrap();
+        
+        assert_eq!(pattern.description, "Dwa bloki");
+        assert_eq!(pattern.size, (8, 3));
+        assert_eq!(sorted_cells(&pattern), vec![(0, 2), (1, 0), (1, 2), (2, 1), (2, 2), (6, 1), (7, 1)]);
+    }
+    
+    #[test]
+    fn life105_rejects_bad_input() {
+        assert_eq!(
+            Pattern::from_life105("#Life 1.06\n").unwrap_err(),
+            LifeError::MissingHeader { expected: LIFE_105_HEADER }
+        );
+        assert_eq!(Pattern::from_life105("#Life 1.05\n#P 1\n*\n").unwrap_err(), LifeError::InvalidBlockHeader { line: 2 });
+        assert_eq!(
+            Pattern::from_life105("#Life 1.05\n.*x\n").unwrap_err(),
+            LifeError::UnexpectedCharacter { character: 'x', line: 2 }
+        );
+    }
+    
+    #[test]
+    fn user_patterns_survive_a_restart() {
+        let path = temp_patterns_file("restart");
+        let mut manager = PatternManager::with_user_patterns_file(Some(path.clone()));
+        let mut pattern = glider();
+        pattern.name = "My glider".to_string();
+        manager.add_pattern(pattern).unwrap();
+        
+        let reloaded = PatternManager::with_user_patterns_file(Some(path.clone()));
+        assert!(reloaded.is_user_pattern("My glider"));
+        assert_eq!(sorted_cells(reloaded.get_pattern("My glider").unwrap()), sorted_cells(&glider()));
+        assert!(!reloaded.is_user_pattern("Pulsar"));
+        
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+    
+    #[test]
+    fn corrupt_user_pattern_entry_is_skipped_and_file_is_backed_up() {
+        let path = temp_patterns_file("corrupt_entry");
+        let text = r#"[
+            {"name": "First", "description": "", "size": [2, 1], "cells": [[0, 0], [1, 0]]},
+            {"name": "Broken", "description": "", "size": [1, 1], "cells": [[5, 5]]},
+            {"name": "Missing cells", "description": "", "size": [1, 1]},
+            {"name": "Last", "description": "", "size": [1, 1], "cells": [[0, 0]]}
+        ]"#;
+        std::fs::write(&path, text).unwrap();
+        
+        let mut manager = PatternManager::with_user_patterns_file(Some(path.clone()));
+        assert!(manager.is_user_pattern("First"));
+        assert!(manager.is_user_pattern("Last"));
+        assert!(manager.get_pattern("Broken").is_none());
+        assert!(manager.get_pattern("Missing cells").is_none());
+        
+        // Zapis nie może zgubić pominiętych wpisów - oryginał trafia do kopii zapasowej
+        manager.remove_user_pattern("Last").unwrap();
+        let backup = path.with_extension(USER_PATTERNS_BACKUP_EXTENSION);
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), text);
+        
+        let reloaded = PatternManager::with_user_patterns_file(Some(path.clone()));
+        assert!(reloaded.is_user_pattern("First"));
+        assert!(!reloaded.is_user_pattern("Last"));
+        
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+    
+    #[test]
+    fn unreadable_user_patterns_file_is_not_overwritten() {
+        let path = temp_patterns_file("not_json");
+        std::fs::write(&path, "to nie jest JSON").unwrap();
+        
+        let mut manager = PatternManager::with_user_patterns_file(Some(path.clone()));
+        assert!(manager.get_pattern("Pulsar").is_some());
+        manager.add_pattern(glider()).unwrap();
+        
+        let backup = path.with_extension(USER_PATTERNS_BACKUP_EXTENSION);
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), "to nie jest JSON");
+        assert!(PatternManager::with_user_patterns_file(Some(path.clone())).is_user_pattern(&glider().name));
+        
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
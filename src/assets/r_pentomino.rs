@@ -0,0 +1,22 @@
+use super::patterns::{Pattern, Position};
+
+/// Tworzy wzorzec R-pentomino - metuzalem stabilizujący się dopiero po 1103 generacjach
+pub fn create_r_pentomino() -> Pattern {
+    let r_pentomino_cells = vec![
+        // .OO
+        Position::new(1, 0), Position::new(2, 0),
+        // OO.
+        Position::new(0, 1), Position::new(1, 1),
+        // .O.
+        Position::new(1, 2),
+    ];
+
+    Pattern::new(
+        "R-pentomino".to_string(),
+        "Metuzalem - pięć komórek, które ewoluują przez 1103 generacje (potrzebuje dużej planszy)".to_string(),
+        (3, 3), // rozmiar 3x3
+        (1, 1), // centrum w środku
+        r_pentomino_cells,
+        None, // brak obrazka na razie
+    )
+}
@@ -0,0 +1,20 @@
+use super::patterns::{Pattern, Position};
+
+/// Tworzy wzorzec Toad - oscylator o okresie 2
+pub fn create_toad() -> Pattern {
+    let toad_cells = vec![
+        // .OOO
+        Position::new(1, 0), Position::new(2, 0), Position::new(3, 0),
+        // OOO.
+        Position::new(0, 1), Position::new(1, 1), Position::new(2, 1),
+    ];
+
+    Pattern::new(
+        "Toad".to_string(),
+        "Oscylator o okresie 2 - dwa przesunięte względem siebie rzędy po trzy komórki".to_string(),
+        (4, 2), // rozmiar 4x2
+        (2, 1), // centrum w środku
+        toad_cells,
+        None, // brak obrazka na razie
+    )
+}
@@ -3,13 +3,15 @@
 /// Ten moduł zawiera predefiniowane wzory, które mogą być użyte
 /// jako punkt startowy dla symulacji.
 
+use serde::{Deserialize, Serialize};
 use crate::logic::board::{Board, CellState};
+use super::rules::GameConfig;
 
 /// Reprezentuje pozycję komórki na planszy
 pub type Position = (usize, usize);
 
 /// Predefiniowane wzory dla gry w życie
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Pattern {
     /// Pusty wzór (wszystkie komórki martwe)
     Empty,
@@ -24,6 +26,25 @@ pub enum Pattern {
 }
 
 impl Pattern {
+    /// Zwraca nazwę wzoru wyświetlaną w ustawieniach
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pattern::Empty => "Empty",
+            Pattern::Block => "Block",
+            Pattern::Blinker => "Blinker",
+            Pattern::Glider => "Glider",
+            Pattern::Custom(_) => "Custom",
+        }
+    }
+    
+    /// Zwraca predefiniowany wzór o podanej nazwie (np. zapisanej w ustawieniach)
+    pub fn from_name(name: &str) -> Option<Pattern> {
+        get_available_patterns()
+            .into_iter()
+            .find(|(pattern_name, _)| *pattern_name == name)
+            .map(|(_, pattern)| pattern)
+    }
+    
     /// Zwraca listę pozycji żywych komórek dla danego wzoru
     pub fn get_positions(&self) -> Vec<Position> {
         match self {
@@ -84,13 +105,13 @@ impl InitialState {
     }
     
     /// Tworzy planszę z początkowym stanem
-    pub fn create_board(&self) -> Board {
-        let config = crate::config::get_config();
+    pub fn create_board(&self, config: &GameConfig) -> Board {
         let (min_width, min_height) = self.pattern.min_board_size();
         
         // Używamy większego z: rozmiaru z konfiguracji lub minimalnego rozmiaru dla wzoru
-        let width = config.initial_board_size.max(min_width);
-        let height = config.initial_board_size.max(min_height);
+        let (board_width, board_height) = config.get_current_board_size();
+        let width = board_width.max(min_width);
+        let height = board_height.max(min_height);
         
         let mut board = Board::new(width, height);
         self.apply_to_board(&mut board);
@@ -134,6 +155,14 @@ pub fn get_default_initial_state() -> InitialState {
     InitialState::default()
 }
 
+/// Zwraca konfigurację początkowego stanu z wzorem startowym wybranym w konfiguracji
+pub fn get_initial_state(config: &GameConfig) -> InitialState {
+    InitialState {
+        pattern: config.initial_pattern.clone(),
+        ..InitialState::default()
+    }
+}
+
 /// Zwraca listę dostępnych wzorów
 pub fn get_available_patterns() -> Vec<(&'static str, Pattern)> {
     vec![
@@ -5,9 +5,7 @@
 
 pub mod rules;
 pub mod initial_state;
-pub mod manager;
 
 // Re-eksportujemy główne typy i funkcje
-pub use rules::{BoardSizeMode, RandomizerConfig};
-pub use initial_state::{get_default_initial_state};
-pub use manager::{get_config, init_config, modify_config};
+pub use rules::{AppearanceConfig, BoardSizeMode, CellShape, BoundaryMode, NeighborhoodType, NeighborMask, RandomFillShape, RandomizerConfig};
+pub use initial_state::{get_default_initial_state, get_initial_state};
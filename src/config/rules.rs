@@ -3,10 +3,12 @@
 /// Zawiera wszystkie parametry gry, które mogą być modyfikowane
 /// przez użytkownika poprzez GUI.
 
-use std::ops::RangeInclusive;
+use std::collections::HashSet;
+use std::fmt;
+use serde::{Deserialize, Serialize};
 
 /// Tryb zarządzania rozmiarem planszy
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum BoardSizeMode {
     /// Dynamiczny rozmiar - plansza rozszerza się automatycznie
     Dynamic,
@@ -20,20 +22,199 @@ impl Default for BoardSizeMode {
     }
 }
 
+/// Sposób traktowania krawędzi planszy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BoundaryMode {
+    /// Komórki poza planszą są traktowane jako martwe
+    #[default]
+    Bounded,
+    /// Plansza "zawija się" - prawa krawędź sąsiaduje z lewą, a dolna z górną
+    Toroidal,
+}
+
+/// Rodzaj sąsiedztwa używany przy liczeniu sąsiadów komórki
+/// 
+/// Liczby sąsiadów w regułach narodzin i przeżycia odnoszą się do wybranego
+/// sąsiedztwa (od 0 do `max_neighbors()`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NeighborhoodType {
+    /// 8 komórek wokół (w tym po przekątnej)
+    #[default]
+    Moore,
+    /// 4 komórki sąsiadujące bokami
+    VonNeumann,
+    /// 24 komórki w odległości Czebyszewa do 2
+    MooreRange2,
+    /// Wybrane komórki z 8 wokół (maska 3x3)
+    Custom(NeighborMask),
+}
+
+/// Maska sąsiedztwa 3x3 - określa, które z 8 komórek wokół są liczone jako sąsiedzi
+/// 
+/// Środek (sama komórka) nigdy nie jest sąsiadem. Przesunięcia są przechowywane
+/// w kolejności `MOORE_OFFSETS`, więc maski o tych samych komórkach są równe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeighborMask {
+    /// Przesunięcia włączonych sąsiadów (pierwsze `len` pozycji)
+    offsets: [(i32, i32); 8],
+    /// Liczba włączonych sąsiadów
+    len: usize,
+}
+
+impl NeighborMask {
+    /// Tworzy maskę z siatki 3x3 (`grid[wiersz][kolumna]`, środek jest pomijany)
+    pub fn from_grid(grid: [[bool; 3]; 3]) -> Self {
+        let mut mask = NeighborMask { offsets: [(0, 0); 8], len: 0 };
+        for &(dx, dy) in &MOORE_OFFSETS {
+            if grid[(dy + 1) as usize][(dx + 1) as usize] {
+                mask.offsets[mask.len] = (dx, dy);
+                mask.len += 1;
+            }
+        }
+        mask
+    }
+    
+    /// Maska odpowiadająca sąsiedztwu Moore'a (wszystkie 8 komórek)
+    pub fn moore() -> Self {
+        Self::from_grid([[true; 3]; 3])
+    }
+    
+    /// Maska odpowiadająca sąsiedztwu von Neumanna (4 komórki sąsiadujące bokami)
+    pub fn von_neumann() -> Self {
+        Self::from_grid([
+            [false, true, false],
+            [true, false, true],
+            [false, true, false],
+        ])
+    }
+    
+    /// Zwraca siatkę 3x3 włączonych sąsiadów (`grid[wiersz][kolumna]`)
+    pub fn grid(&self) -> [[bool; 3]; 3] {
+        let mut grid = [[false; 3]; 3];
+        for &(dx, dy) in self.offsets() {
+            grid[(dy + 1) as usize][(dx + 1) as usize] = true;
+        }
+        grid
+    }
+    
+    /// Zwraca przesunięcia (dx, dy) włączonych sąsiadów
+    pub fn offsets(&self) -> &[(i32, i32)] {
+        &self.offsets[..self.len]
+    }
+}
+
+/// Przesunięcia sąsiadów w sąsiedztwie Moore'a
+const MOORE_OFFSETS: [(i32, i32); 8] = [
+    (-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1),
+];
+
+/// Przesunięcia sąsiadów w sąsiedztwie von Neumanna
+const VON_NEUMANN_OFFSETS: [(i32, i32); 4] = [
+    (0, -1), (-1, 0), (1, 0), (0, 1),
+];
+
+/// Przesunięcia sąsiadów w sąsiedztwie Moore'a o promieniu 2
+const MOORE_RANGE2_OFFSETS: [(i32, i32); 24] = [
+    (-2, -2), (-1, -2), (0, -2), (1, -2), (2, -2), (-2, -1), (-1, -1), (0, -1),
+    (1, -1), (2, -1), (-2, 0), (-1, 0), (1, 0), (2, 0), (-2, 1), (-1, 1),
+    (0, 1), (1, 1), (2, 1), (-2, 2), (-1, 2), (0, 2), (1, 2), (2, 2),
+];
+
+impl NeighborhoodType {
+    /// Wszystkie rodzaje sąsiedztwa (do wyboru w interfejsie)
+    pub const ALL: [NeighborhoodType; 3] = [
+        NeighborhoodType::Moore,
+        NeighborhoodType::VonNeumann,
+        NeighborhoodType::MooreRange2,
+    ];
+    
+    /// Zwraca przesunięcia (dx, dy) wszystkich sąsiadów komórki
+    pub fn offsets(&self) -> &[(i32, i32)] {
+        match self {
+            NeighborhoodType::Moore => &MOORE_OFFSETS,
+            NeighborhoodType::VonNeumann => &VON_NEUMANN_OFFSETS,
+            NeighborhoodType::MooreRange2 => &MOORE_RANGE2_OFFSETS,
+            NeighborhoodType::Custom(mask) => mask.offsets(),
+        }
+    }
+    
+    /// Zwraca największą możliwą liczbę żywych sąsiadów
+    pub fn max_neighbors(&self) -> usize {
+        self.offsets().len()
+    }
+    
+    /// Zwraca zasięg sąsiedztwa - największą odległość sąsiada od komórki w poziomie lub pionie
+    pub fn radius(self) -> usize {
+        match self {
+            NeighborhoodType::Moore | NeighborhoodType::VonNeumann | NeighborhoodType::Custom(_) => 1,
+            NeighborhoodType::MooreRange2 => 2,
+        }
+    }
+    
+    /// Zwraca nazwę wyświetlaną w interfejsie
+    pub fn name(self) -> &'static str {
+        match self {
+            NeighborhoodType::Moore => "Moore (8)",
+            NeighborhoodType::VonNeumann => "Von Neumann (4)",
+            NeighborhoodType::MooreRange2 => "Moore range 2 (24)",
+            NeighborhoodType::Custom(_) => "Custom (3x3 mask)",
+        }
+    }
+}
+
+/// Nazwany zestaw reguł gry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RulePreset {
+    /// Nazwa wyświetlana w interfejsie
+    pub name: &'static str,
+    /// Reguła w zapisie "B.../S..."
+    pub rule: &'static str,
+}
+
+/// Najmniejszy dozwolony wymiar planszy
+pub const MIN_BOARD_DIMENSION: usize = 3;
+
+/// Największy dozwolony wymiar planszy (wystarczający dla monitorów 4K)
+pub const MAX_BOARD_DIMENSION: usize = 1001;
+
+/// Największy margines od krawędzi wyzwalający rozszerzenie planszy
+pub const MAX_EXPANSION_MARGIN: usize = 10;
+
+/// Największa liczba warstw dodawanych przy jednym rozszerzeniu planszy
+pub const MAX_EXPANSION_LAYERS: usize = 20;
+
+/// Największy bonus randomizera za sąsiada (przy 2 już wylosowanych sąsiadach daje 100%)
+pub const MAX_NEIGHBOR_BONUS: f32 = 0.5;
+
+/// Znane warianty reguł gry dostępne do szybkiego wyboru (dla sąsiedztwa Moore'a)
+pub const RULE_PRESETS: &[RulePreset] = &[
+    RulePreset { name: "Conway's Life", rule: "B3/S23" },
+    RulePreset { name: "HighLife", rule: "B36/S23" },
+    RulePreset { name: "Seeds", rule: "B2/S" },
+    RulePreset { name: "Day & Night", rule: "B3678/S34678" },
+    RulePreset { name: "Replicator", rule: "B1357/S1357" },
+];
+
 /// Struktura zawierająca wszystkie parametry konfiguracyjne gry
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameConfig {
-    /// Przedział liczby sąsiadów potrzebnych do narodzin nowej komórki
+    /// Liczby sąsiadów, przy których rodzi się nowa komórka
     /// Domyślnie: 3 (standardowa reguła Conway'a)
-    pub birth_neighbors: RangeInclusive<usize>,
+    pub birth_neighbors: HashSet<usize>,
     
-    /// Przedział liczby sąsiadów potrzebnych do przeżycia komórki
-    /// Domyślnie: 2-3 (standardowa reguła Conway'a)
-    pub survival_neighbors: RangeInclusive<usize>,
+    /// Liczby sąsiadów, przy których komórka przeżywa
+    /// Domyślnie: 2, 3 (standardowa reguła Conway'a)
+    pub survival_neighbors: HashSet<usize>,
     
     /// Tryb zarządzania rozmiarem planszy
     pub board_size_mode: BoardSizeMode,
     
+    /// Sposób traktowania krawędzi planszy przy liczeniu sąsiadów
+    pub boundary_mode: BoundaryMode,
+    
+    /// Rodzaj sąsiedztwa - określa, które komórki są liczone jako sąsiedzi
+    pub neighborhood: NeighborhoodType,
+    
     /// Maksymalny rozmiar planszy (szerokość i wysokość) - używany w trybie Dynamic
     /// Po osiągnięciu tego rozmiaru plansza nie będzie się dalej rozszerzać
     pub max_board_size: usize,
@@ -41,8 +222,11 @@ pub struct GameConfig {
     /// Początkowy rozmiar planszy przy starcie gry - używany w trybie Dynamic
     pub initial_board_size: usize,
     
-    /// Stały rozmiar planszy - używany w trybie Static
-    pub static_board_size: usize,
+    /// Stała szerokość planszy - używana w trybie Static
+    pub static_board_width: usize,
+    
+    /// Stała wysokość planszy - używana w trybie Static
+    pub static_board_height: usize,
     
     /// Margines od krawędzi planszy, przy którym następuje automatyczne rozszerzenie
     /// (jeśli nie osiągnięto maksymalnego rozmiaru)
@@ -54,21 +238,193 @@ pub struct GameConfig {
     /// Margines pozostawiany przy optymalizacji rozmiaru planszy
     pub optimization_margin: usize,
     
+    /// Maksymalna liczba poprzednich generacji przechowywanych do cofania
+    /// (ogranicza zużycie pamięci przy dużych planszach)
+    pub history_limit: usize,
+    
+    /// Liczba ostatnich generacji porównywanych przy wykrywaniu oscylatorów
+    /// (wykrywane są okresy do tej wartości włącznie)
+    pub detection_window: usize,
+    
+    /// Czy zatrzymywać symulację, gdy plansza przestaje się zmieniać
+    /// (tylko martwa natura - oscylatory nie zatrzymują symulacji)
+    pub pause_on_stable: bool,
+    
+    /// Czy pytać o potwierdzenie, zanim reset wyczyści planszę z żywymi komórkami
+    pub confirm_destructive_reset: bool,
+    
+    /// Prawdopodobieństwo, że komórka spełniająca regułę narodzin faktycznie się narodzi (0.0 - 1.0)
+    pub birth_probability: f32,
+    
+    /// Prawdopodobieństwo, że komórka spełniająca regułę przeżycia faktycznie przeżyje (0.0 - 1.0)
+    pub survival_probability: f32,
+    
+    /// Ziarno losowania reguł probabilistycznych - None oznacza losowanie z entropii systemu
+    /// (to samo ziarno i ta sama plansza zawsze dają tę samą następną generację)
+    pub stochastic_seed: Option<u64>,
+    
     /// Parametry interfejsu użytkownika
     pub ui_config: UIConfig,
     
     /// Konfiguracja randomizera planszy
     pub randomizer_config: RandomizerConfig,
+    
+    /// Kolory komórek i siatki planszy
+    pub appearance: AppearanceConfig,
+    
+    /// Wzór umieszczany na planszy przy uruchomieniu aplikacji i w nowych zakładkach
+    pub initial_pattern: super::initial_state::Pattern,
+}
+
+/// Kolory planszy w formacie RGBA (bez premultiplikacji alfy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppearanceConfig {
+    /// Kolor żywych komórek
+    pub alive_color: [u8; 4],
+    
+    /// Kolor martwych komórek (tło planszy)
+    pub dead_color: [u8; 4],
+    
+    /// Kolor linii siatki
+    pub grid_color: [u8; 4],
+    
+    /// Kształt, którym rysowane są żywe komórki
+    pub cell_shape: CellShape,
+    
+    /// Czy zamiast powyższych kolorów używać kolorów motywu interfejsu
+    pub match_theme: bool,
+    
+    /// Czy podgląd narodzin i śmierci ma kolory legendy panelu (z palety motywu)
+    /// zamiast poniższych kolorów
+    pub themed_preview_colors: bool,
+    
+    /// Kolor podglądu komórek, które się narodzą (alfa określa krycie)
+    pub birth_preview_color: [u8; 4],
+    
+    /// Kolor podglądu komórek, które umrą (alfa określa krycie)
+    pub death_preview_color: [u8; 4],
+    
+    /// Kolor podglądu komórek, które przeżyją (alfa określa krycie)
+    pub survival_preview_color: [u8; 4],
+    
+    /// Czy podglądać także komórki, które przeżyją
+    pub show_survivors: bool,
+    
+    /// Czy zaznaczać pas przy krawędziach, w którym żywe komórki powodują rozszerzenie planszy (tryb Dynamic)
+    pub show_expansion_margin: bool,
+}
+
+impl Default for AppearanceConfig {
+    fn default() -> Self {
+        Self {
+            alive_color: [0, 0, 0, 255],        // Czarne żywe komórki
+            dead_color: [255, 255, 255, 255],   // Białe tło
+            grid_color: [160, 160, 160, 255],   // Szara siatka
+            cell_shape: CellShape::Square,
+            match_theme: false,                 // Klasyczne kolory są czytelniejsze przy analizie wzorów
+            themed_preview_colors: true,        // Podgląd na planszy zgodny z legendą w panelu
+            birth_preview_color: [0, 255, 0, 60],     // Półprzezroczysta zieleń
+            death_preview_color: [255, 0, 0, 40],     // Półprzezroczysta czerwień
+            survival_preview_color: [0, 128, 255, 30], // Słaby błękit, aby zmiany były widoczne
+            show_survivors: false,
+            show_expansion_margin: false,
+        }
+    }
+}
+
+/// Kształt, którym rysowane są żywe komórki
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CellShape {
+    /// Kwadrat wypełniający całą komórkę
+    #[default]
+    Square,
+    /// Kwadrat o zaokrąglonych rogach
+    RoundedSquare,
+    /// Koło wpisane w komórkę
+    Circle,
+}
+
+impl CellShape {
+    /// Wszystkie dostępne kształty (kolejność jak w interfejsie)
+    pub const ALL: [CellShape; 3] = [
+        CellShape::Square,
+        CellShape::RoundedSquare,
+        CellShape::Circle,
+    ];
+    
+    /// Zwraca nazwę kształtu wyświetlaną w interfejsie
+    pub fn name(self) -> &'static str {
+        match self {
+            CellShape::Square => "Square",
+            CellShape::RoundedSquare => "Rounded square",
+            CellShape::Circle => "Circle",
+        }
+    }
+}
+
+/// Obszar planszy, w którym randomizer losuje żywe komórki
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RandomFillShape {
+    /// Cała plansza
+    #[default]
+    WholeBoard,
+    /// Wyśrodkowane koło o promieniu równym połowie mniejszego wymiaru planszy
+    Disk,
+    /// Wyśrodkowany kwadrat o boku równym połowie mniejszego wymiaru planszy
+    Square,
+}
+
+impl RandomFillShape {
+    /// Wszystkie dostępne kształty (kolejność jak w interfejsie)
+    pub const ALL: [RandomFillShape; 3] = [
+        RandomFillShape::WholeBoard,
+        RandomFillShape::Disk,
+        RandomFillShape::Square,
+    ];
+    
+    /// Zwraca nazwę kształtu wyświetlaną w interfejsie
+    pub fn name(self) -> &'static str {
+        match self {
+            RandomFillShape::WholeBoard => "Whole board",
+            RandomFillShape::Disk => "Centered disk",
+            RandomFillShape::Square => "Centered square",
+        }
+    }
+    
+    /// Sprawdza czy komórka (x, y) leży w kształcie na planszy o podanych wymiarach
+    pub fn contains(self, x: usize, y: usize, width: usize, height: usize) -> bool {
+        // Odległość środka komórki od środka planszy
+        let dx = x as f32 - (width as f32 - 1.0) / 2.0;
+        let dy = y as f32 - (height as f32 - 1.0) / 2.0;
+        let half_size = width.min(height) as f32 / 2.0;
+        
+        match self {
+            RandomFillShape::WholeBoard => true,
+            RandomFillShape::Disk => dx * dx + dy * dy <= half_size * half_size,
+            RandomFillShape::Square => dx.abs() < half_size / 2.0 && dy.abs() < half_size / 2.0,
+        }
+    }
 }
 
 /// Konfiguracja randomizera planszy
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RandomizerConfig {
     /// Bazowe prawdopodobieństwo że komórka będzie żywa (0.0 - 1.0)
     pub base_probability: f32,
     
     /// Bonus prawdopodobieństwa za każdego żywego sąsiada (0.0 - 1.0)
     pub neighbor_bonus: f32,
+    
+    /// Ziarno generatora liczb losowych - None oznacza losowanie z entropii systemu
+    /// (to samo ziarno i rozmiar planszy zawsze dają identyczną planszę)
+    pub seed: Option<u64>,
+    
+    /// Obszar, w którym losowane są żywe komórki (poza nim komórki pozostają martwe)
+    pub shape: RandomFillShape,
+    
+    /// Czy losowanie dodaje żywe komórki do aktualnej planszy zamiast zaczynać od pustej
+    /// (istniejące żywe komórki pozostają, losowane są tylko martwe)
+    pub additive: bool,
 }
 
 impl Default for RandomizerConfig {
@@ -76,12 +432,15 @@ impl Default for RandomizerConfig {
         Self {
             base_probability: 0.20,    // 20% bazowe prawdopodobieństwo
             neighbor_bonus: 0.10,      // +10% za każdego sąsiada
+            seed: None,                // Domyślnie każde losowanie jest inne
+            shape: RandomFillShape::WholeBoard,
+            additive: false,           // Domyślnie losowanie zastępuje planszę
         }
     }
 }
 
 /// Konfiguracja parametrów interfejsu użytkownika
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UIConfig {
     /// Domyślna prędkość symulacji (generacje na sekundę)
     pub default_simulation_speed: f32,
@@ -95,15 +454,21 @@ pub struct UIConfig {
     /// Krok zmiany prędkości na suwaку
     pub simulation_speed_step: f32,
     
+    /// Mnożniki domyślnej prędkości dla przycisków szybkiego wyboru (1×, 2×, ...)
+    pub speed_presets: [f32; 4],
+    
     /// Domyślny rozmiar przycisków (szerokość, wysokość)
     pub default_button_size: (f32, f32),
     
+    /// Czy plansza jest okresowo zapisywana do odzyskania po nieoczekiwanym zamknięciu
+    pub autosave: bool,
+    
     /// Rozmiary okna aplikacji
     pub window_config: WindowConfig,
 }
 
 /// Konfiguracja okna aplikacji
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WindowConfig {
     /// Domyślny rozmiar okna (szerokość, wysokość)
     pub default_size: (f32, f32),
@@ -122,7 +487,9 @@ impl Default for UIConfig {
             min_simulation_speed: 0.5,
             max_simulation_speed: 30.0,
             simulation_speed_step: 0.5,
+            speed_presets: [1.0, 2.0, 5.0, 10.0],
             default_button_size: (100.0, 30.0),
+            autosave: true,
             window_config: WindowConfig::default(),
         }
     }
@@ -142,29 +509,49 @@ impl Default for GameConfig {
     fn default() -> Self {
         Self {
             // Standardowe reguły Conway'a: B3/S23
-            birth_neighbors: 3..=3,           // Narodziny przy dokładnie 3 sąsiadach
-            survival_neighbors: 2..=3,        // Przeżycie przy 2 lub 3 sąsiadach
+            birth_neighbors: HashSet::from([3]),       // Narodziny przy dokładnie 3 sąsiadach
+            survival_neighbors: HashSet::from([2, 3]), // Przeżycie przy 2 lub 3 sąsiadach
             
             // Tryb zarządzania planszą
             board_size_mode: BoardSizeMode::Dynamic,
+            boundary_mode: BoundaryMode::Bounded,  // Krawędzie planszy są "martwe"
+            neighborhood: NeighborhoodType::Moore, // Klasyczne 8 sąsiadów
             
             // Ograniczenia rozmiaru planszy (tryb Dynamic)
             max_board_size: 101,              // Maksymalny rozmiar 101x101
             initial_board_size: 9,            // Początkowy rozmiar planszy
             
             // Stały rozmiar planszy (tryb Static)
-            static_board_size: 21,            // Domyślny stały rozmiar 21x21
+            static_board_width: 21,           // Domyślny stały rozmiar 21x21
+            static_board_height: 21,
             
             // Parametry rozszerzania
             expansion_margin: 2,              // Rozszerzaj gdy żywe komórki są 2 pola od krawędzi
             expansion_layers: 1,              // Dodawaj 1 warstwę na raz
             optimization_margin: 3,           // Pozostaw 3 pola marginesu przy optymalizacji
             
+            // Historia generacji
+            history_limit: 100,               // Pamiętaj do 100 poprzednich generacji
+            detection_window: 30,             // Wykrywaj oscylatory o okresie do 30
+            pause_on_stable: false,           // Domyślnie symulacja działa dalej po ustabilizowaniu
+            confirm_destructive_reset: true,  // Chroni narysowaną planszę przed drugim resetem
+            
+            // Reguły probabilistyczne (1.0 - reguły deterministyczne)
+            birth_probability: 1.0,
+            survival_probability: 1.0,
+            stochastic_seed: None,
+            
             // Konfiguracja interfejsu użytkownika
             ui_config: UIConfig::default(),
             
             // Konfiguracja randomizera
             randomizer_config: RandomizerConfig::default(),
+            
+            // Kolory planszy
+            appearance: AppearanceConfig::default(),
+            
+            // Wzór startowy
+            initial_pattern: super::initial_state::Pattern::Empty,
         }
     }
 }
@@ -199,11 +586,13 @@ impl GameConfig {
         proposed_size.min(self.max_board_size)
     }
     
-    /// Zwraca aktualny rozmiar planszy w zależności od trybu
-    pub fn get_current_board_size(&self) -> usize {
+    /// Zwraca aktualny rozmiar planszy (szerokość, wysokość) w zależności od trybu
+    /// 
+    /// W trybie Dynamic plansza startuje jako kwadrat i rośnie w miarę potrzeby.
+    pub fn get_current_board_size(&self) -> (usize, usize) {
         match self.board_size_mode {
-            BoardSizeMode::Dynamic => self.initial_board_size,
-            BoardSizeMode::Static => self.static_board_size,
+            BoardSizeMode::Dynamic => (self.initial_board_size, self.initial_board_size),
+            BoardSizeMode::Static => (self.static_board_width, self.static_board_height),
         }
     }
     
@@ -214,12 +603,51 @@ impl GameConfig {
     
     /// Ustawia nowy przedział dla narodzin komórek
     pub fn set_birth_neighbors(&mut self, min: usize, max: usize) {
-        self.birth_neighbors = min..=max;
+        self.birth_neighbors = (min..=max).collect();
     }
     
     /// Ustawia nowy przedział dla przeżycia komórek
     pub fn set_survival_neighbors(&mut self, min: usize, max: usize) {
-        self.survival_neighbors = min..=max;
+        self.survival_neighbors = (min..=max).collect();
+    }
+    
+    /// Ustawia reguły gry na podstawie zapisu w stylu Golly (np. "B36/S23")
+    /// 
+    /// Akceptuje części B/S w dowolnej kolejności, bez względu na wielkość liter,
+    /// oraz starszy zapis "S/B" bez liter (np. "23/3"). Liczby większe niż 9
+    /// (sąsiedztwo o promieniu 2) zapisuje się po przecinku, np. "B3,10/S2,3".
+    /// Przy błędzie konfiguracja pozostaje bez zmian.
+    pub fn set_rule_from_string(&mut self, rule: &str) -> Result<(), RuleParseError> {
+        let (birth, survival) = parse_rule_string(rule)?;
+        
+        // Liczby sąsiadów muszą mieścić się w wybranym sąsiedztwie
+        let max = self.neighborhood.max_neighbors();
+        if let Some(&count) = birth.iter().chain(&survival).find(|&&count| count > max) {
+            return Err(RuleParseError::CountOutOfRange { count, max });
+        }
+        
+        self.birth_neighbors = birth;
+        self.survival_neighbors = survival;
+        Ok(())
+    }
+    
+    /// Zwraca gotowy zestaw reguł dokładnie odpowiadający aktualnej konfiguracji
+    pub fn active_rule_preset(&self) -> Option<&'static RulePreset> {
+        if self.neighborhood != NeighborhoodType::Moore {
+            return None;
+        }
+        
+        let rule = self.rule_string();
+        RULE_PRESETS.iter().find(|preset| preset.rule == rule)
+    }
+    
+    /// Zwraca aktualne reguły w zapisie "B.../S..." (np. "B3/S23")
+    pub fn rule_string(&self) -> String {
+        format!(
+            "B{}/S{}",
+            format_rule_digits(&self.birth_neighbors),
+            format_rule_digits(&self.survival_neighbors)
+        )
     }
     
     /// Ustawia tryb zarządzania planszą
@@ -227,28 +655,322 @@ impl GameConfig {
         self.board_size_mode = mode;
     }
     
+    /// Ustawia sposób traktowania krawędzi planszy
+    pub fn set_boundary_mode(&mut self, mode: BoundaryMode) {
+        self.boundary_mode = mode;
+    }
+    
+    /// Ustawia rodzaj sąsiedztwa
+    /// 
+    /// Liczby sąsiadów przekraczające maksimum nowego sąsiedztwa są usuwane z reguł.
+    pub fn set_neighborhood(&mut self, neighborhood: NeighborhoodType) {
+        let max = neighborhood.max_neighbors();
+        self.neighborhood = neighborhood;
+        self.birth_neighbors.retain(|&count| count <= max);
+        self.survival_neighbors.retain(|&count| count <= max);
+    }
+    
+    /// Sprawdza czy plansza zawija się na krawędziach
+    pub fn is_toroidal(&self) -> bool {
+        self.boundary_mode == BoundaryMode::Toroidal
+    }
+    
     /// Ustawia maksymalny rozmiar planszy (tryb Dynamic)
     pub fn set_max_board_size(&mut self, size: usize) {
-        self.max_board_size = size.max(3).min(201); // Ograniczenie 3-201
+        self.max_board_size = size.clamp(MIN_BOARD_DIMENSION, MAX_BOARD_DIMENSION);
     }
     
     /// Ustawia początkowy rozmiar planszy (tryb Dynamic)
     pub fn set_initial_board_size(&mut self, size: usize) {
-        self.initial_board_size = size.max(3).min(201); // Ograniczenie 3-201
+        self.initial_board_size = size.clamp(MIN_BOARD_DIMENSION, MAX_BOARD_DIMENSION);
     }
     
     /// Ustawia stały rozmiar planszy (tryb Static)
-    pub fn set_static_board_size(&mut self, size: usize) {
-        self.static_board_size = size.max(3).min(201); // Ograniczenie 3-201
+    pub fn set_static_board_size(&mut self, width: usize, height: usize) {
+        self.static_board_width = width.clamp(MIN_BOARD_DIMENSION, MAX_BOARD_DIMENSION);
+        self.static_board_height = height.clamp(MIN_BOARD_DIMENSION, MAX_BOARD_DIMENSION);
+    }
+    
+    /// Ustawia margines od krawędzi, przy którym plansza jest rozszerzana (1-`MAX_EXPANSION_MARGIN`)
+    pub fn set_expansion_margin(&mut self, margin: usize) {
+        self.expansion_margin = margin.clamp(1, MAX_EXPANSION_MARGIN);
+    }
+    
+    /// Ustawia liczbę warstw dodawanych przy jednym rozszerzeniu (1-`MAX_EXPANSION_LAYERS`)
+    pub fn set_expansion_layers(&mut self, layers: usize) {
+        self.expansion_layers = layers.clamp(1, MAX_EXPANSION_LAYERS);
+    }
+    
+    /// Ustawia limit historii generacji
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit.min(1000); // Ograniczenie 0-1000
+    }
+    
+    /// Ustawia zatrzymywanie symulacji po ustabilizowaniu planszy
+    pub fn set_pause_on_stable(&mut self, enabled: bool) {
+        self.pause_on_stable = enabled;
+    }
+    
+    /// Ustawia pytanie o potwierdzenie przed wyczyszczeniem planszy resetem
+    pub fn set_confirm_destructive_reset(&mut self, enabled: bool) {
+        self.confirm_destructive_reset = enabled;
+    }
+    
+    /// Ustawia prawdopodobieństwo narodzin komórki spełniającej regułę
+    pub fn set_birth_probability(&mut self, probability: f32) {
+        self.birth_probability = probability.clamp(0.0, 1.0);
+    }
+    
+    /// Ustawia prawdopodobieństwo przeżycia komórki spełniającej regułę
+    pub fn set_survival_probability(&mut self, probability: f32) {
+        self.survival_probability = probability.clamp(0.0, 1.0);
+    }
+    
+    /// Ustawia ziarno reguł probabilistycznych (None - losowe ziarno przy każdej generacji)
+    pub fn set_stochastic_seed(&mut self, seed: Option<u64>) {
+        self.stochastic_seed = seed;
+    }
+    
+    /// Sprawdza czy reguły są probabilistyczne (następna generacja nie jest jednoznaczna)
+    pub fn is_stochastic(&self) -> bool {
+        self.birth_probability < 1.0 || self.survival_probability < 1.0
     }
     
     /// Ustawia bazowe prawdopodobieństwo randomizera
     pub fn set_randomizer_base_probability(&mut self, probability: f32) {
-        self.randomizer_config.base_probability = probability.max(0.0).min(1.0);
+        self.randomizer_config.base_probability = probability.clamp(0.0, 1.0);
+    }
+    
+    /// Ustawia ziarno randomizera (None - losowe ziarno przy każdym losowaniu)
+    pub fn set_randomizer_seed(&mut self, seed: Option<u64>) {
+        self.randomizer_config.seed = seed;
+    }
+    
+    /// Ustawia kolory planszy
+    pub fn set_appearance(&mut self, appearance: AppearanceConfig) {
+        self.appearance = appearance;
     }
     
     /// Ustawia bonus prawdopodobieństwa za sąsiada
     pub fn set_randomizer_neighbor_bonus(&mut self, bonus: f32) {
-        self.randomizer_config.neighbor_bonus = bonus.max(0.0).min(1.0);
+        self.randomizer_config.neighbor_bonus = bonus.clamp(0.0, MAX_NEIGHBOR_BONUS);
+    }
+    
+    /// Ustawia obszar, w którym randomizer losuje żywe komórki
+    pub fn set_randomizer_shape(&mut self, shape: RandomFillShape) {
+        self.randomizer_config.shape = shape;
+    }
+    
+    /// Ustawia czy randomizer dodaje komórki do aktualnej planszy zamiast ją zastępować
+    pub fn set_randomizer_additive(&mut self, additive: bool) {
+        self.randomizer_config.additive = additive;
+    }
+}
+
+/// Błąd parsowania zapisu reguł gry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleParseError {
+    /// Pusty zapis reguły
+    Empty,
+    /// Zapis nie składa się z dwóch części oddzielonych '/'
+    InvalidFormat(String),
+    /// Ta sama część (B lub S) występuje dwukrotnie
+    DuplicatePart(char),
+    /// Znak, który nie jest cyfrą liczby sąsiadów
+    InvalidDigit(char),
+    /// Liczba na liście po przecinku, która nie jest liczbą sąsiadów
+    InvalidCount(String),
+    /// Liczba sąsiadów większa niż pozwala wybrane sąsiedztwo
+    CountOutOfRange { count: usize, max: usize },
+}
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleParseError::Empty => write!(f, "rule string is empty"),
+            RuleParseError::InvalidFormat(rule) => {
+                write!(f, "'{}' is not in B.../S... form", rule)
+            }
+            RuleParseError::DuplicatePart(part) => write!(f, "part '{}' is given twice", part),
+            RuleParseError::InvalidDigit(character) => {
+                write!(f, "'{}' is not a valid neighbor count", character)
+            }
+            RuleParseError::InvalidCount(count) => {
+                write!(f, "'{}' is not a valid neighbor count", count)
+            }
+            RuleParseError::CountOutOfRange { count, max } => {
+                write!(f, "{} neighbors is more than this neighborhood has (0-{})", count, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuleParseError {}
+
+/// Parsuje zapis reguły i zwraca zbiory liczby sąsiadów (narodziny, przeżycie)
+fn parse_rule_string(rule: &str) -> Result<(HashSet<usize>, HashSet<usize>), RuleParseError> {
+    let rule = rule.trim();
+    if rule.is_empty() {
+        return Err(RuleParseError::Empty);
+    }
+    
+    let parts: Vec<&str> = rule.split('/').map(str::trim).collect();
+    if parts.len() != 2 {
+        return Err(RuleParseError::InvalidFormat(rule.to_string()));
+    }
+    
+    // Starszy zapis bez liter: "przeżycie/narodziny" (np. "23/3")
+    let has_letters = parts.iter().any(|part| part.starts_with(|c: char| c.is_ascii_alphabetic()));
+    if !has_letters {
+        return Ok((parse_rule_digits(parts[1])?, parse_rule_digits(parts[0])?));
+    }
+    
+    let mut birth = None;
+    let mut survival = None;
+    
+    for part in parts {
+        let mut chars = part.chars();
+        let prefix = chars.next().map(|c| c.to_ascii_uppercase());
+        let target = match prefix {
+            Some('B') => &mut birth,
+            Some('S') => &mut survival,
+            _ => return Err(RuleParseError::InvalidFormat(rule.to_string())),
+        };
+        
+        if target.is_some() {
+            return Err(RuleParseError::DuplicatePart(prefix.unwrap_or('?')));
+        }
+        *target = Some(parse_rule_digits(chars.as_str())?);
+    }
+    
+    match (birth, survival) {
+        (Some(birth), Some(survival)) => Ok((birth, survival)),
+        _ => Err(RuleParseError::InvalidFormat(rule.to_string())),
+    }
+}
+
+/// Parsuje ciąg cyfr liczby sąsiadów (np. "36" -> {3, 6})
+/// 
+/// Lista z przecinkami (np. "3,10") pozwala zapisać liczby większe niż 9.
+/// Przecinek na końcu (np. "10,") oznacza listę z jedną liczbą.
+fn parse_rule_digits(digits: &str) -> Result<HashSet<usize>, RuleParseError> {
+    let list = match digits.trim_end().strip_suffix(',') {
+        Some(list) => Some(list),
+        None => digits.contains(',').then_some(digits),
+    };
+    if let Some(list) = list {
+        return list
+            .split(',')
+            .map(|count| {
+                count
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| RuleParseError::InvalidCount(count.trim().to_string()))
+            })
+            .collect();
+    }
+    
+    digits
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => Ok(digit as usize),
+            None => Err(RuleParseError::InvalidDigit(c)),
+        })
+        .collect()
+}
+
+/// Formatuje zbiór liczby sąsiadów jako posortowany ciąg cyfr (np. {3, 2} -> "23")
+/// 
+/// Jeśli zbiór zawiera liczby większe niż 9, są one oddzielane przecinkami, a pojedyncza
+/// liczba kończy się przecinkiem (np. {10} -> "10,"), żeby nie odczytać jej jako cyfr 1 i 0.
+fn format_rule_digits(counts: &HashSet<usize>) -> String {
+    let mut sorted: Vec<usize> = counts.iter().copied().collect();
+    sorted.sort_unstable();
+    
+    if !sorted.iter().any(|&count| count > 9) {
+        return sorted.iter().map(|count| count.to_string()).collect();
+    }
+    
+    let list = sorted.iter().map(|count| count.to_string()).collect::<Vec<_>>().join(",");
+    if sorted.len() == 1 {
+        format!("{},", list)
+    } else {
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Zapisuje reguły konfiguracji i wczytuje je do nowej konfiguracji z tym samym sąsiedztwem
+    fn round_trip(config: &GameConfig) -> GameConfig {
+        let mut parsed = GameConfig::default();
+        parsed.set_neighborhood(config.neighborhood);
+        parsed.set_rule_from_string(&config.rule_string()).expect("zapis reguły powinien dać się odczytać");
+        parsed
+    }
+    
+    #[test]
+    fn single_count_above_nine_round_trips() {
+        let mut config = GameConfig::default();
+        config.set_neighborhood(NeighborhoodType::MooreRange2);
+        config.birth_neighbors = HashSet::from([10]);
+        config.survival_neighbors = HashSet::from([2, 3]);
+        
+        assert_eq!(config.rule_string(), "B10,/S23");
+        let parsed = round_trip(&config);
+        assert_eq!(parsed.birth_neighbors, config.birth_neighbors);
+        assert_eq!(parsed.survival_neighbors, config.survival_neighbors);
+    }
+    
+    #[test]
+    fn several_counts_above_nine_round_trip() {
+        let mut config = GameConfig::default();
+        config.set_neighborhood(NeighborhoodType::MooreRange2);
+        config.birth_neighbors = HashSet::from([3, 10, 24]);
+        config.survival_neighbors = HashSet::from([12]);
+        
+        assert_eq!(config.rule_string(), "B3,10,24/S12,");
+        let parsed = round_trip(&config);
+        assert_eq!(parsed.birth_neighbors, config.birth_neighbors);
+        assert_eq!(parsed.survival_neighbors, config.survival_neighbors);
+    }
+    
+    #[test]
+    fn digit_rules_keep_the_compact_form() {
+        let mut config = GameConfig::default();
+        config.set_rule_from_string("B36/S23").unwrap();
+        assert_eq!(config.birth_neighbors, HashSet::from([3, 6]));
+        assert_eq!(config.rule_string(), "B36/S23");
+    }
+    
+    #[test]
+    fn trailing_comma_is_accepted() {
+        let mut config = GameConfig::default();
+        config.set_neighborhood(NeighborhoodType::MooreRange2);
+        config.set_rule_from_string("B3,10,/S2,3").unwrap();
+        assert_eq!(config.birth_neighbors, HashSet::from([3, 10]));
+        assert_eq!(config.survival_neighbors, HashSet::from([2, 3]));
+    }
+    
+    #[test]
+    fn config_survives_serialization() {
+        let mut config = GameConfig::default();
+        config.set_neighborhood(NeighborhoodType::Custom(NeighborMask::from_grid([
+            [true, false, true],
+            [false, false, false],
+            [true, false, true],
+        ])));
+        config.set_rule_from_string("B1/S12").unwrap();
+        config.set_boundary_mode(BoundaryMode::Toroidal);
+        config.set_board_size_mode(BoardSizeMode::Static);
+        
+        let text = serde_json::to_string(&config).unwrap();
+        let restored: GameConfig = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.rule_string(), "B1/S12");
+        assert_eq!(restored.neighborhood, config.neighborhood);
+        assert_eq!(restored.boundary_mode, BoundaryMode::Toroidal);
+        assert_eq!(restored.board_size_mode, BoardSizeMode::Static);
     }
 }
@@ -0,0 +1,80 @@
+/// Moduł silnika symulacji bez interfejsu graficznego
+/// 
+/// `GameEngine` przechowuje planszę i własną konfigurację gry, dzięki czemu
+/// symulację można uruchamiać w testach, benchmarkach lub innych programach
+/// niezależnie od okna aplikacji.
+
+use crate::config::rules::GameConfig;
+use crate::logic::board::Board;
+
+/// Silnik symulacji gry w życie działający na własnej konfiguracji
+/// 
+/// Plansza ma stały rozmiar - silnik nie rozszerza jej ani nie przycina
+/// (tym zajmuje się aplikacja w trybie Dynamic).
+#[derive(Debug, Clone)]
+pub struct GameEngine {
+    /// Aktualna plansza
+    board: Board,
+    /// Konfiguracja używana do obliczania kolejnych generacji
+    config: GameConfig,
+    /// Liczba wykonanych generacji
+    generation: u64,
+    /// Bufor liczby sąsiadów używany ponownie w kolejnych krokach
+    neighbor_counts: Vec<u8>,
+}
+
+impl GameEngine {
+    /// Tworzy silnik z podaną planszą i konfiguracją
+    pub fn new(board: Board, config: GameConfig) -> Self {
+        Self {
+            board,
+            config,
+            generation: 0,
+            neighbor_counts: Vec::new(),
+        }
+    }
+    
+    /// Wykonuje jedną generację
+    pub fn step(&mut self) {
+        self.board = self.board.next_generation_with_buffer(&self.config, &mut self.neighbor_counts);
+        self.generation += 1;
+    }
+    
+    /// Wykonuje `generations` kolejnych generacji
+    pub fn run(&mut self, generations: u64) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+    
+    /// Zwraca liczbę żywych komórek
+    pub fn population(&self) -> usize {
+        self.board.count_alive_cells()
+    }
+    
+    /// Zwraca liczbę wykonanych generacji
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+    
+    /// Zwraca aktualną planszę
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+    
+    /// Zastępuje planszę i zeruje licznik generacji
+    pub fn set_board(&mut self, board: Board) {
+        self.board = board;
+        self.generation = 0;
+    }
+    
+    /// Zwraca konfigurację silnika
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+    
+    /// Zwraca konfigurację silnika do modyfikacji (np. zmiany reguł)
+    pub fn config_mut(&mut self) -> &mut GameConfig {
+        &mut self.config
+    }
+}
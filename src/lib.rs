@@ -0,0 +1,9 @@
+/// Biblioteka logiki gry w życie
+/// 
+/// Udostępnia planszę, reguły i silnik symulacji (`engine::GameEngine`) bez
+/// interfejsu graficznego - aplikacja okienkowa korzysta z tych samych modułów.
+
+pub mod config;
+pub mod logic;
+pub mod assets;
+pub mod engine;
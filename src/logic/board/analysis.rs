@@ -0,0 +1,136 @@
+use super::structure::Board;
+use crate::config::rules::GameConfig;
+
+/// Moduł analizy okresowości wzorów
+/// 
+/// Porównuje kolejne generacje po sprowadzeniu żywych komórek do ich prostokąta
+/// otaczającego, dzięki czemu wykrywane są zarówno oscylatory, jak i statki
+/// (wzory powtarzające się w przesuniętym położeniu, np. glider).
+
+/// Wynik analizy okresowości wzoru
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeriodInfo {
+    /// Najmniejsza liczba generacji, po której wzór się powtarza
+    pub period: usize,
+    /// Przesunięcie wzoru (dx, dy) po jednym okresie
+    pub displacement: (i64, i64),
+}
+
+impl PeriodInfo {
+    /// Czy wzór przemieszcza się po planszy (statek)
+    pub fn is_spaceship(&self) -> bool {
+        self.displacement != (0, 0)
+    }
+    
+    /// Zwraca opis wyniku do wyświetlenia w interfejsie
+    pub fn description(&self) -> String {
+        let (dx, dy) = self.displacement;
+        if self.is_spaceship() {
+            format!("Spaceship ({}, {}, period {})", dx, dy, self.period)
+        } else if self.period == 1 {
+            "Still life".to_string()
+        } else {
+            format!("Oscillator period {}", self.period)
+        }
+    }
+}
+
+/// Żywe komórki wzoru względem lewego górnego rogu ich prostokąta otaczającego
+/// wraz z położeniem tego rogu na planszy
+struct NormalizedShape {
+    origin: (usize, usize),
+    cells: Vec<(usize, usize)>,
+}
+
+impl NormalizedShape {
+    /// Tworzy znormalizowany kształt planszy (None dla pustej planszy)
+    fn from_board(board: &Board) -> Option<Self> {
+        let (min_x, min_y, _, _) = board.alive_bounding_box()?;
+        let cells = board.iter_alive_cells()
+            .map(|(x, y)| (x - min_x, y - min_y))
+            .collect();
+        
+        Some(Self { origin: (min_x, min_y), cells })
+    }
+}
+
+impl Board {
+    /// Wyznacza najmniejszy okres wzoru (nie większy niż `max_period`)
+    /// 
+    /// Wzór przesunięty po okresie (statek) również jest uznawany za powtórzony.
+    /// Zwraca None, gdy wzór nie powtarza się w tym czasie lub wymiera.
+    pub fn detect_period(&self, max_period: usize, config: &GameConfig) -> Option<usize> {
+        self.analyze_period(max_period, config).map(|info| info.period)
+    }
+    
+    /// Wyznacza okres wzoru oraz jego przesunięcie po jednym okresie
+    /// 
+    /// Kolejne generacje są liczone na kopii planszy według zasad z podanej konfiguracji.
+    pub fn analyze_period(&self, max_period: usize, config: &GameConfig) -> Option<PeriodInfo> {
+        let initial = NormalizedShape::from_board(self)?;
+        let mut current = self.clone();
+        
+        for period in 1..=max_period {
+            current = current.next_generation(config);
+            let shape = NormalizedShape::from_board(&current)?;
+            
+            if shape.cells == initial.cells {
+                let displacement = (
+                    shape.origin.0 as i64 - initial.origin.0 as i64,
+                    shape.origin.1 as i64 - initial.origin.1 as i64,
+                );
+                return Some(PeriodInfo { period, displacement });
+            }
+        }
+        
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::board::CellState;
+    
+    /// Tworzy planszę z podanymi żywymi komórkami
+    fn board_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Board {
+        let mut board = Board::new(width, height);
+        for &(x, y) in cells {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    #[test]
+    fn block_is_a_still_life() {
+        let block = board_with(6, 6, &[(2, 2), (3, 2), (2, 3), (3, 3)]);
+        let info = block.analyze_period(10, &GameConfig::default()).unwrap();
+        assert_eq!(info, PeriodInfo { period: 1, displacement: (0, 0) });
+        assert_eq!(info.description(), "Still life");
+    }
+    
+    #[test]
+    fn blinker_has_period_two() {
+        let blinker = board_with(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+        assert_eq!(blinker.detect_period(10, &GameConfig::default()), Some(2));
+    }
+    
+    #[test]
+    fn glider_is_a_spaceship_with_period_four() {
+        let glider = board_with(12, 12, &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        let info = glider.analyze_period(10, &GameConfig::default()).unwrap();
+        assert_eq!(info, PeriodInfo { period: 4, displacement: (1, 1) });
+        assert!(info.is_spaceship());
+    }
+    
+    #[test]
+    fn dying_or_slow_patterns_have_no_period() {
+        let config = GameConfig::default();
+        assert_eq!(Board::new(5, 5).detect_period(10, &config), None);
+        assert_eq!(board_with(5, 5, &[(1, 1), (2, 1)]).detect_period(10, &config), None);
+        
+        // Szybowiec nie powtarza się w mniej niż 4 generacje
+        let glider = board_with(12, 12, &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        assert_eq!(glider.detect_period(3, &config), None);
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt;
+use super::structure::{Board, CellState};
+use crate::config::rules::{MAX_BOARD_DIMENSION, MIN_BOARD_DIMENSION};
+
+/// Moduł zapisu i odczytu planszy jako siatki znaków
+/// 
+/// Siatka z dowolnymi znakami żywych i martwych komórek (domyślnie `#` i `.`)
+/// nadaje się do wklejenia w czacie lub zgłoszeniu błędu - jest prostsza
+/// do odczytania niż RLE.
+
+/// Znaki odczytywane jako żywe komórki
+const ALIVE_CHARS: [char; 3] = ['#', 'O', '*'];
+
+/// Błąd odczytu planszy z siatki znaków
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsciiError {
+    /// Tekst nie zawiera żadnego wiersza siatki
+    Empty,
+    /// Siatka jest większa niż największa dozwolona plansza
+    TooLarge { width: usize, height: usize },
+}
+
+impl fmt::Display for AsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsciiError::Empty => write!(f, "the text contains no board"),
+            AsciiError::TooLarge { width, height } => write!(
+                f,
+                "the board ({}x{}) exceeds the maximum of {}",
+                width, height, MAX_BOARD_DIMENSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AsciiError {}
+
+impl Board {
+    /// Zapisuje żywy obszar planszy jako siatkę znaków, wiersz po wierszu
+    /// 
+    /// Zapisywany jest tylko prostokąt obejmujący żywe komórki.
+    /// Pusta plansza daje pusty tekst.
+    pub fn to_ascii(&self, alive_char: char, dead_char: char) -> String {
+        let Some((min_x, min_y, max_x, max_y)) = self.alive_bounding_box() else {
+            return String::new();
+        };
+        
+        let mut result = String::with_capacity((max_x - min_x + 2) * (max_y - min_y + 1));
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let symbol = match self.get_cell(x, y) {
+                    Some(CellState::Alive) => alive_char,
+                    _ => dead_char,
+                };
+                result.push(symbol);
+            }
+            result.push('\n');
+        }
+        
+        result
+    }
+    
+    /// Tworzy planszę z siatki znaków (`#`, `O` i `*` - żywe, pozostałe - martwe)
+    /// 
+    /// Wymiary planszy wynikają z liczby wierszy i najdłuższego wiersza - krótsze
+    /// wiersze są uzupełniane martwymi komórkami. Linie komentarza zaczynające się
+    /// od "# " lub "#!" są pomijane.
+    /// 
+    /// Wymiary są sprawdzane przed utworzeniem planszy: siatka mniejsza niż
+    /// `MIN_BOARD_DIMENSION` jest uzupełniana martwymi komórkami z prawej i od dołu,
+    /// a większa niż `MAX_BOARD_DIMENSION` jest odrzucana.
+    pub fn from_ascii(text: &str) -> Result<Board, AsciiError> {
+        let rows: Vec<&str> = text
+            .lines()
+            .filter(|line| !line.starts_with("# ") && !line.starts_with("#!"))
+            .collect();
+        let width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+        let height = rows.len();
+        
+        if width == 0 {
+            return Err(AsciiError::Empty);
+        }
+        if width > MAX_BOARD_DIMENSION || height > MAX_BOARD_DIMENSION {
+            return Err(AsciiError::TooLarge { width, height });
+        }
+        
+        let mut board = Board::new(width.max(MIN_BOARD_DIMENSION), height.max(MIN_BOARD_DIMENSION));
+        for (y, row) in rows.iter().enumerate() {
+            for (x, symbol) in row.chars().enumerate() {
+                if ALIVE_CHARS.contains(&symbol) {
+                    board.set_cell(x, y, CellState::Alive);
+                }
+            }
+        }
+        
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::rules::GameConfig;
+    
+    /// Tworzy planszę z podanymi żywymi komórkami
+    fn board_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Board {
+        let mut board = Board::new(width, height);
+        for &(x, y) in cells {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    #[test]
+    fn vertical_blinker_is_written_as_three_rows() {
+        let blinker = board_with(3, 3, &[(1, 0), (1, 1), (1, 2)]);
+        assert_eq!(blinker.to_ascii('#', '.'), "#\n#\n#\n");
+        
+        // Zapisywany jest tylko prostokąt żywych komórek
+        let blinker = blinker.next_generation(&GameConfig::default());
+        assert_eq!(blinker.to_ascii('O', '.'), "OOO\n");
+    }
+    
+    #[test]
+    fn dead_cells_inside_the_live_area_use_the_dead_char() {
+        let board = board_with(5, 5, &[(1, 1), (3, 2)]);
+        assert_eq!(board.to_ascii('#', '-'), "#--\n--#\n");
+        assert_eq!(Board::new(3, 3).to_ascii('#', '.'), "");
+    }
+    
+    #[test]
+    fn glider_round_trips_through_ascii() {
+        let board = board_with(10, 8, &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        let text = board.to_ascii('#', '.');
+        
+        let parsed = Board::from_ascii(&text).unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (3, 3));
+        assert_eq!(parsed.iter_alive_cells().collect::<Vec<_>>(), board.iter_alive_cells().collect::<Vec<_>>());
+        assert_eq!(parsed.to_ascii('#', '.'), text);
+    }
+    
+    #[test]
+    fn small_grid_is_padded_to_minimum_dimension() {
+        let board = Board::from_ascii("#").unwrap();
+        assert_eq!((board.width(), board.height()), (MIN_BOARD_DIMENSION, MIN_BOARD_DIMENSION));
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), vec![(0, 0)]);
+    }
+    
+    #[test]
+    fn short_rows_and_comments_are_handled() {
+        let board = Board::from_ascii("#! komentarz\nO...\n*\n..#").unwrap();
+        assert_eq!((board.width(), board.height()), (4, 3));
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), vec![(0, 0), (0, 1), (2, 2)]);
+    }
+    
+    #[test]
+    fn empty_text_is_rejected() {
+        assert_eq!(Board::from_ascii(""), Err(AsciiError::Empty));
+        assert_eq!(Board::from_ascii("# tylko komentarz\n"), Err(AsciiError::Empty));
+    }
+    
+    #[test]
+    fn oversized_grid_is_rejected() {
+        let too_wide = ".".repeat(MAX_BOARD_DIMENSION + 1);
+        assert_eq!(
+            Board::from_ascii(&too_wide),
+            Err(AsciiError::TooLarge { width: MAX_BOARD_DIMENSION + 1, height: 1 })
+        );
+        
+        let too_tall = ".\n".repeat(MAX_BOARD_DIMENSION + 1);
+        assert_eq!(
+            Board::from_ascii(&too_tall),
+            Err(AsciiError::TooLarge { width: 1, height: MAX_BOARD_DIMENSION + 1 })
+        );
+    }
+}
@@ -0,0 +1,269 @@
+/// Moduł wyboru sposobu obliczania następnej generacji
+/// 
+/// Plansza zawsze przechowuje komórki w gęstym wektorze, ale przy niskiej
+/// gęstości żywych komórek następna generacja jest liczona na rzadkiej
+/// reprezentacji (zbiorze żywych komórek) - praca zależy wtedy od liczby
+/// żywych komórek, a nie od rozmiaru planszy.
+
+use std::collections::{HashMap, HashSet};
+use super::structure::{Board, CellState};
+use crate::config::BoundaryMode;
+use crate::config::rules::GameConfig;
+
+/// Gęstość żywych komórek, poniżej której używane są obliczenia rzadkie
+/// 
+/// Koszt obliczeń rzadkich rośnie z liczbą żywych komórek, a gęstych - z rozmiarem
+/// planszy. Punkt, w którym oba sposoby są równie szybkie, można sprawdzić
+/// benchmarkiem `cargo bench --bench generation` dla różnych gęstości.
+pub const SPARSE_DENSITY_THRESHOLD: f32 = 0.04;
+
+/// Sposób obliczania następnej generacji
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardBackend {
+    /// Sprawdzenie każdej komórki planszy
+    Dense,
+    /// Zliczanie sąsiadów tylko wokół żywych komórek
+    Sparse,
+}
+
+impl BoardBackend {
+    /// Wybiera sposób obliczeń na podstawie gęstości planszy i reguł
+    /// 
+    /// Reguły z narodzinami przy 0 sąsiadach (B0) wymagają sprawdzenia każdej
+    /// komórki, więc zawsze używają obliczeń gęstych.
+    pub fn select(board: &Board, config: &GameConfig) -> Self {
+        if config.should_birth(0) || board.total_cells() == 0 {
+            return BoardBackend::Dense;
+        }
+        
+        let density = board.count_alive_cells() as f32 / board.total_cells() as f32;
+        if density < SPARSE_DENSITY_THRESHOLD {
+            BoardBackend::Sparse
+        } else {
+            BoardBackend::Dense
+        }
+    }
+}
+
+/// Rzadka reprezentacja planszy - zbiór współrzędnych żywych komórek
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseBoard {
+    width: usize,
+    height: usize,
+    alive: HashSet<(i32, i32)>,
+}
+
+impl SparseBoard {
+    /// Tworzy rzadką reprezentację z gęstej planszy
+    pub fn from_board(board: &Board) -> Self {
+        Self {
+            width: board.width(),
+            height: board.height(),
+            alive: board
+                .iter_alive_cells()
+                .map(|(x, y)| (x as i32, y as i32))
+                .collect(),
+        }
+    }
+    
+    /// Zamienia rzadką reprezentację z powrotem na gęstą planszę
+    pub fn to_board(&self) -> Board {
+        let mut board = Board::new(self.width, self.height);
+        for &(x, y) in &self.alive {
+            board.set_cell(x as usize, y as usize, CellState::Alive);
+        }
+        board
+    }
+    
+    /// Oblicza następną generację, zliczając sąsiadów tylko wokół żywych komórek
+    /// 
+    /// Daje identyczny wynik jak obliczenia gęste (przy regułach bez B0).
+    pub fn next_generation(&self, config: &GameConfig) -> SparseBoard {
+        let width = self.width as i32;
+        let height = self.height as i32;
+        
+        // Każda żywa komórka dodaje 1 do licznika każdej komórki, dla której jest sąsiadem -
+        // komórka (x - dx, y - dy) widzi komórkę (x, y) pod przesunięciem (dx, dy),
+        // co ma znaczenie dla niesymetrycznych masek sąsiedztwa
+        let offsets = config.neighborhood.offsets();
+        let mut neighbor_counts: HashMap<(i32, i32), usize> = HashMap::with_capacity(self.alive.len() * offsets.len());
+        for &(x, y) in &self.alive {
+            for &(dx, dy) in offsets {
+                let mut neighbor = (x - dx, y - dy);
+                if config.boundary_mode == BoundaryMode::Toroidal {
+                    neighbor = (neighbor.0.rem_euclid(width), neighbor.1.rem_euclid(height));
+                } else if neighbor.0 < 0 || neighbor.1 < 0 || neighbor.0 >= width || neighbor.1 >= height {
+                    // Komórki poza planszą nie istnieją
+                    continue;
+                }
+                
+                *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        
+        let mut next_alive = HashSet::with_capacity(self.alive.len());
+        
+        // Narodziny i przeżycie komórek mających co najmniej jednego sąsiada
+        for (&cell, &count) in &neighbor_counts {
+            let survives = self.alive.contains(&cell) && config.should_survive(count);
+            let born = !self.alive.contains(&cell) && config.should_birth(count);
+            if survives || born {
+                next_alive.insert(cell);
+            }
+        }
+        
+        // Żywe komórki bez sąsiadów nie pojawiają się w liczniku
+        if config.should_survive(0) {
+            for &cell in &self.alive {
+                if !neighbor_counts.contains_key(&cell) {
+                    next_alive.insert(cell);
+                }
+            }
+        }
+        
+        SparseBoard {
+            width: self.width,
+            height: self.height,
+            alive: next_alive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+    use crate::config::{NeighborMask, NeighborhoodType};
+    use crate::logic::prediction::predict_next_state;
+    
+    /// Tworzy planszę z losowymi żywymi komórkami o podanej gęstości
+    fn random_board(width: usize, height: usize, density: f64, seed: u64) -> Board {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut board = Board::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                if rng.gen_bool(density) {
+                    board.set_cell(x, y, CellState::Alive);
+                }
+            }
+        }
+        board
+    }
+    
+    /// Tworzy konfigurację z podanym sąsiedztwem, krawędziami i regułami
+    fn config_with(neighborhood: NeighborhoodType, boundary_mode: BoundaryMode, birth: &[usize], survival: &[usize]) -> GameConfig {
+        GameConfig {
+            neighborhood,
+            boundary_mode,
+            birth_neighbors: birth.iter().copied().collect::<HashSet<_>>(),
+            survival_neighbors: survival.iter().copied().collect::<HashSet<_>>(),
+            ..GameConfig::default()
+        }
+    }
+    
+    /// Sąsiedztwa do porównań - w tym niesymetryczne maski
+    fn all_neighborhoods() -> Vec<NeighborhoodType> {
+        let right_only = NeighborMask::from_grid([
+            [false, false, false],
+            [false, false, true],
+            [false, false, false],
+        ]);
+        let corner = NeighborMask::from_grid([
+            [true, true, false],
+            [true, false, false],
+            [false, false, true],
+        ]);
+        vec![
+            NeighborhoodType::Moore,
+            NeighborhoodType::VonNeumann,
+            NeighborhoodType::MooreRange2,
+            NeighborhoodType::Custom(right_only),
+            NeighborhoodType::Custom(corner),
+        ]
+    }
+    
+    #[test]
+    fn sparse_matches_dense_for_every_neighborhood_and_boundary() {
+        for neighborhood in all_neighborhoods() {
+            for boundary_mode in [BoundaryMode::Bounded, BoundaryMode::Toroidal] {
+                let config = config_with(neighborhood, boundary_mode, &[1, 3, 6], &[1, 2, 3]);
+                for seed in 0..4 {
+                    let board = random_board(23, 17, 0.15, seed);
+                    let dense = board.next_generation_sequential(&config, &mut Vec::new());
+                    let sparse = SparseBoard::from_board(&board).next_generation(&config).to_board();
+                    assert_eq!(sparse, dense, "{:?}, {:?}, seed {}", neighborhood, boundary_mode, seed);
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn sparse_uses_the_same_direction_as_dense_for_asymmetric_mask() {
+        let right_only = NeighborMask::from_grid([
+            [false, false, false],
+            [false, false, true],
+            [false, false, false],
+        ]);
+        let config = config_with(NeighborhoodType::Custom(right_only), BoundaryMode::Bounded, &[1], &[1]);
+        let mut board = Board::new(21, 21);
+        board.set_cell(10, 10, CellState::Alive);
+        
+        let sparse = SparseBoard::from_board(&board).next_generation(&config).to_board();
+        
+        // Komórka (9, 10) ma komórkę (10, 10) jako sąsiada po prawej
+        assert_eq!(sparse.iter_alive_cells().collect::<Vec<_>>(), vec![(9, 10)]);
+        assert_eq!(sparse, board.next_generation_sequential(&config, &mut Vec::new()));
+    }
+    
+    #[test]
+    fn select_prefers_sparse_only_for_low_density_without_b0() {
+        let config = GameConfig::default();
+        assert_eq!(BoardBackend::select(&random_board(50, 50, 0.01, 1), &config), BoardBackend::Sparse);
+        assert_eq!(BoardBackend::select(&random_board(50, 50, 0.3, 1), &config), BoardBackend::Dense);
+        
+        let b0_config = config_with(NeighborhoodType::Moore, BoundaryMode::Bounded, &[0, 3], &[2, 3]);
+        assert_eq!(BoardBackend::select(&random_board(50, 50, 0.01, 1), &b0_config), BoardBackend::Dense);
+    }
+    
+    /// Maska licząca tylko cztery komórki po przekątnej
+    fn diagonal_only() -> NeighborMask {
+        NeighborMask::from_grid([
+            [true, false, true],
+            [false, false, false],
+            [true, false, true],
+        ])
+    }
+    
+    #[test]
+    fn diagonal_mask_counts_only_diagonal_neighbors() {
+        let config = config_with(NeighborhoodType::Custom(diagonal_only()), BoundaryMode::Bounded, &[1], &[2]);
+        
+        let mut cross = Board::new(9, 9);
+        for (x, y) in [(3, 3), (5, 3), (3, 5), (5, 5)] {
+            cross.set_cell(x, y, CellState::Alive);
+        }
+        assert_eq!(cross.count_alive_neighbors(4, 4, &config), 4);
+        
+        let mut plus = Board::new(9, 9);
+        for (x, y) in [(4, 3), (3, 4), (5, 4), (4, 5)] {
+            plus.set_cell(x, y, CellState::Alive);
+        }
+        assert_eq!(plus.count_alive_neighbors(4, 4, &config), 0);
+    }
+    
+    #[test]
+    fn prediction_matches_next_generation_for_diagonal_mask() {
+        for boundary_mode in [BoundaryMode::Bounded, BoundaryMode::Toroidal] {
+            let config = config_with(NeighborhoodType::Custom(diagonal_only()), boundary_mode, &[1, 3], &[1, 2]);
+            for seed in 0..4 {
+                let board = random_board(19, 13, 0.2, seed);
+                let next = board.next_generation(&config);
+                
+                let mut predicted = predict_next_state(&board, &config).next_alive_cells;
+                predicted.sort_unstable_by_key(|&(x, y)| (y, x));
+                assert_eq!(predicted, next.iter_alive_cells().collect::<Vec<_>>(), "{:?}, seed {}", boundary_mode, seed);
+            }
+        }
+    }
+}
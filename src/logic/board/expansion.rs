@@ -1,5 +1,6 @@
 use super::structure::{Board, CellState};
-use crate::config::get_config;
+use crate::assets::{Pattern, Position};
+use crate::config::rules::GameConfig;
 
 /// Moduł odpowiedzialny za dynamiczne rozszerzanie planszy
 /// 
@@ -7,32 +8,15 @@ use crate::config::get_config;
 /// pustych (martwych) komórek dookoła istniejącej struktury.
 
 
+/// Przesunięcie (x, y), o które rozszerzenie przesunęło zawartość planszy
+/// 
+/// Dane powiązane z komórkami (np. nakładki) trzeba przesunąć o tę samą wartość.
+pub type ExpansionOffset = (usize, usize);
+
 impl Board {
-    pub fn expand(&self) -> Board {
-        // Obliczamy nowe wymiary - dodajemy po jednej komórce z każdej strony
-        let new_width = self.width() + 2;
-        let new_height = self.height() + 2;
-        
-        // Tworzymy nową planszę wypełnioną martwymi komórkami
-        let mut expanded_board = Board::new(new_width, new_height);
-        
-        // Przepisujemy wszystkie komórki ze starej planszy do nowej
-        // z offsetem (1, 1) aby wyśrodkować wzór
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                // Pobieramy stan komórki ze starej planszy
-                if let Some(cell_state) = self.get_cell(x, y) {
-                    // Przepisujemy komórkę do nowej pozycji z offsetem
-                    let new_x = x + 1; // Offset o 1 w poziomie
-                    let new_y = y + 1; // Offset o 1 w pionie
-                    
-                    // Ustawiamy komórkę w nowej planszy
-                    expanded_board.set_cell(new_x, new_y, cell_state);
-                }
-            }
-        }
-        
-        expanded_board
+    /// Rozszerza planszę o jedną warstwę pustych komórek dookoła
+    pub fn expand(&self) -> (Board, ExpansionOffset) {
+        self.expand_centered(self.width() + 2, self.height() + 2)
     }
 
     /// Rozszerza planszę o określoną liczbę warstw
@@ -40,34 +24,40 @@ impl Board {
     /// Wykonuje rozszerzenie planszy o podaną liczbę warstw komórek
     /// dookoła istniejącej struktury. Każda warstwa dodaje 2 do szerokości
     /// i 2 do wysokości planszy.
-    pub fn expand_by_layers(&self, layers: usize) -> Option<Board> {
+    pub fn expand_by_layers(&self, layers: usize) -> Option<(Board, ExpansionOffset)> {
         if layers == 0 {
             return None;
         }
         
-        // Obliczamy nowe wymiary
-        let new_width = self.width() + (2 * layers);
-        let new_height = self.height() + (2 * layers);
-        
-        // Tworzymy nową planszę
-        let mut expanded_board = Board::new(new_width, new_height);
-        
-        // Obliczamy offset do wyśrodkowania wzoru
-        let offset_x = layers;
-        let offset_y = layers;
+        Some(self.expand_centered(self.width() + 2 * layers, self.height() + 2 * layers))
+    }
+
+    /// Przesunięcie wyśrodkowujące planszę w większej planszy o podanych wymiarach
+    /// 
+    /// Wspólne dla wszystkich sposobów rozszerzania - przy nieparzystej różnicy
+    /// wymiarów dodatkowa kolumna (wiersz) trafia na prawą (dolną) krawędź.
+    pub fn expansion_offset(&self, target_width: usize, target_height: usize) -> ExpansionOffset {
+        (
+            target_width.saturating_sub(self.width()) / 2,
+            target_height.saturating_sub(self.height()) / 2,
+        )
+    }
+
+    /// Tworzy planszę o podanych (nie mniejszych) wymiarach z wyśrodkowaną zawartością
+    /// 
+    /// Zwraca nową planszę i przesunięcie, o które przeniesiono komórki (wraz z wiekiem).
+    fn expand_centered(&self, target_width: usize, target_height: usize) -> (Board, ExpansionOffset) {
+        let mut expanded_board = Board::new(target_width.max(self.width()), target_height.max(self.height()));
+        let (offset_x, offset_y) = self.expansion_offset(target_width, target_height);
         
-        // Przepisujemy wszystkie komórki ze starej planszy
+        // Przepisujemy wszystkie komórki ze starej planszy do nowej pozycji z offsetem
         for y in 0..self.height() {
             for x in 0..self.width() {
-                if let Some(cell_state) = self.get_cell(x, y) {
-                    let new_x = x + offset_x;
-                    let new_y = y + offset_y;
-                    expanded_board.set_cell(new_x, new_y, cell_state);
-                }
+                expanded_board.copy_cell_from(self, x, y, x + offset_x, y + offset_y);
             }
         }
         
-        Some(expanded_board)
+        (expanded_board, (offset_x, offset_y))
     }
 
     /// Automatycznie rozszerza planszę jeśli żywe komórki są zbyt blisko krawędzi
@@ -76,18 +66,24 @@ impl Board {
     /// Jeśli tak, automatycznie rozszerza planszę aby zapewnić odpowiedni margines.
     /// Respektuje maksymalny rozmiar planszy zdefiniowany w konfiguracji.
     /// UWAGA: Funkcja działa tylko w trybie Dynamic - w trybie Static zawsze zwraca None.
-    pub fn auto_expand_if_needed(&self, margin: usize) -> Option<Board> {
-        let config = get_config();
-        
+    /// Plansza toroidalna również nigdy nie jest rozszerzana, bo nie ma krawędzi.
+    /// Zwraca rozszerzoną planszę wraz z przesunięciem jej zawartości.
+    pub fn auto_expand_if_needed(&self, margin: usize, config: &GameConfig) -> Option<(Board, ExpansionOffset)> {
         // W trybie Static NIGDY nie rozszerzamy planszy
         if !config.can_expand_in_current_mode() {
             return None;
         }
         
+        // Plansza zawijająca się na krawędziach jest koncepcyjnie nieskończona
+        if config.is_toroidal() {
+            return None;
+        }
+        
         let mut needs_expansion = false;
         
         // Sprawdzamy czy plansza może być rozszerzona (nie osiągnęła maksymalnego rozmiaru)
-        if !config.can_expand(self.width(), self.height(), config.expansion_layers) {
+        // - przy dużej liczbie warstw dodawana jest tylko część, która się mieści
+        if self.width() >= config.max_board_size && self.height() >= config.max_board_size {
             // Plansza osiągnęła maksymalny rozmiar - nie rozszerzamy
             return None;
         }
@@ -134,156 +130,134 @@ impl Board {
     /// 
     /// Pomocnicza funkcja dla auto_expand_if_needed, która tworzy planszę
     /// o dokładnie określonych wymiarach, nie większych niż maksymalne.
-    fn expand_with_limits(&self, target_width: usize, target_height: usize) -> Option<Board> {
+    fn expand_with_limits(&self, target_width: usize, target_height: usize) -> Option<(Board, ExpansionOffset)> {
         if target_width <= self.width() && target_height <= self.height() {
             return None;
         }
         
-        // Tworzymy nową planszę o docelowych wymiarach
-        let mut expanded_board = Board::new(target_width, target_height);
-        
-        // Obliczamy offset do wyśrodkowania wzoru
-        let offset_x = (target_width.saturating_sub(self.width())) / 2;
-        let offset_y = (target_height.saturating_sub(self.height())) / 2;
-        
-        // Przepisujemy wszystkie komórki ze starej planszy
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                if let Some(cell_state) = self.get_cell(x, y) {
-                    let new_x = x + offset_x;
-                    let new_y = y + offset_y;
-                    
-                    // Sprawdzamy czy nowa pozycja mieści się w docelowej planszy
-                    if new_x < target_width && new_y < target_height {
-                        expanded_board.set_cell(new_x, new_y, cell_state);
-                    }
-                }
-            }
-        }
-        
-        Some(expanded_board)
+        Some(self.expand_centered(target_width, target_height))
+    }
+
+    /// Sprawdza czy cały obszar wzoru umieszczonego środkiem w `center` leży na planszy
+    pub fn can_fit_pattern_at(&self, center: Position, pattern: &Pattern) -> bool {
+        self.pattern_overflow(center, pattern) == 0
+    }
+
+    /// Zwraca, o ile komórek obszar wzoru wystaje najdalej poza planszę (0 - mieści się)
+    fn pattern_overflow(&self, center: Position, pattern: &Pattern) -> usize {
+        let left = center.x - pattern.center_offset.0;
+        let top = center.y - pattern.center_offset.1;
+        let right = left + pattern.size.0 as i32 - self.width() as i32;
+        let bottom = top + pattern.size.1 as i32 - self.height() as i32;
+        
+        [-left, -top, right, bottom].into_iter().max().unwrap_or(0).max(0) as usize
     }
 
-    /// Optymalizuje rozmiar planszy poprzez iteracyjne usuwanie pustych pierścieni krawędzi
+    /// Przesuwa środek wzoru tak, aby jego obszar leżał na planszy
     /// 
-    /// Algorytm działa następująco:
-    /// 1. Sprawdza czy można usunąć cały zewnętrzny pierścień (zachowując margines od żywych komórek)
-    /// 2. Jeśli tak, usuwa jeden kompletny pierścień i sprawdza ponownie
-    /// 3. Powtarza proces aż nie można już więcej usunąć
-    /// 4. Zachowuje dokładnie `margin` pustych komórek od najbliższych żywych komórek
-    /// 5. ZAWSZE zwraca kwadratową planszę
-    pub fn optimize_size(&self, margin: usize) -> Option<Board> {
-        // Plansza musi być kwadratem - bierzemy mniejszy wymiar jako bazę
-        let current_size = self.width().min(self.height());
-        
-        if current_size <= 2 * margin + 1 {
-            // Plansza jest już minimalna - nie można jej zmniejszyć
-            return None;
-        }
-        
-        // Rozpoczynamy z kwadratową wersją aktualnej planszy
-        let mut current_board = self.resize_to_square(current_size);
-        let mut was_optimized = false;
-        
-        loop {
-            // Sprawdzamy czy można usunąć cały zewnętrzny pierścień
-            if current_board.can_remove_outer_ring(margin) {
-                // Usuwamy jeden kompletny pierścień z wszystkich stron
-                current_board = current_board.remove_outer_ring();
-                was_optimized = true;
-                
-                // Sprawdzamy czy plansza nie stała się zbyt mała
-                if current_board.width() <= 2 * margin + 1 {
-                    break;
-                }
-            } else {
-                // Nie można usunąć więcej pierścieni
-                break;
-            }
-        }
+    /// Wzór większy od planszy jest wyrównywany do jej lewej (górnej) krawędzi.
+    pub fn clamp_pattern_center(&self, center: Position, pattern: &Pattern) -> Position {
+        let clamp_axis = |center: i32, offset: i32, size: u32, board_size: usize| {
+            let max_origin = (board_size as i32 - size as i32).max(0);
+            (center - offset).clamp(0, max_origin) + offset
+        };
         
-        // Zwracamy zoptymalizowaną planszę tylko jeśli rzeczywiście ją zmniejszyliśmy
-        if was_optimized {
-            Some(current_board)
-        } else {
-            None
-        }
+        Position::new(
+            clamp_axis(center.x, pattern.center_offset.0, pattern.size.0, self.width()),
+            clamp_axis(center.y, pattern.center_offset.1, pattern.size.1, self.height()),
+        )
     }
-    
-    /// Sprawdza czy można usunąć cały zewnętrzny pierścień zachowując margines
+
+    /// Rozszerza planszę tak, aby wzór umieszczony środkiem w `center` zmieścił się cały
+    /// 
+    /// Liczba dodanych warstw jest ograniczona maksymalnym rozmiarem planszy z konfiguracji,
+    /// więc wzór może nadal wystawać poza planszę. Zwraca None, gdy rozszerzenie
+    /// nie jest potrzebne lub nie jest możliwe.
+    pub fn expand_to_fit_pattern(&self, center: Position, pattern: &Pattern, config: &GameConfig) -> Option<(Board, ExpansionOffset)> {
+        let larger_dimension = self.width().max(self.height());
+        let layer_limit = config.max_board_size.saturating_sub(larger_dimension) / 2;
+        
+        self.expand_by_layers(self.pattern_overflow(center, pattern).min(layer_limit))
+    }
+
+    /// Czyści planszę i umieszcza wzór tak, aby prostokąt jego żywych komórek leżał na środku
     /// 
-    /// Zewnętrzny pierścień to wszystkie komórki na krawędzi planszy:
-    /// - Cały pierwszy wiersz (y = 0)
-    /// - Cały ostatni wiersz (y = height - 1) 
-    /// - Cała pierwsza kolumna (x = 0, bez narożników już policzone w wierszach)
-    /// - Cała ostatnia kolumna (x = width - 1, bez narożników już policzone w wierszach)
-    fn can_remove_outer_ring(&self, margin: usize) -> bool {
-        let size = self.width(); // Plansza jest kwadratem
-        
-        if size <= 2 * margin + 1 {
-            return false;
+    /// W trybie Dynamic plansza mniejsza od wzoru jest najpierw powiększana (z marginesem
+    /// rozszerzania z każdej strony, w granicach maksymalnego rozmiaru). Zwraca false,
+    /// gdy część komórek wzoru nie zmieściła się na planszy.
+    pub fn place_centered(&mut self, pattern: &Pattern, config: &GameConfig) -> bool {
+        self.clear();
+        
+        let Some(min_x) = pattern.cells.iter().map(|pos| pos.x).min() else {
+            return true;
+        };
+        let min_y = pattern.cells.iter().map(|pos| pos.y).min().unwrap_or(0);
+        let max_x = pattern.cells.iter().map(|pos| pos.x).max().unwrap_or(0);
+        let max_y = pattern.cells.iter().map(|pos| pos.y).max().unwrap_or(0);
+        let pattern_width = (max_x - min_x + 1) as usize;
+        let pattern_height = (max_y - min_y + 1) as usize;
+        
+        if config.can_expand_in_current_mode() && (pattern_width > self.width() || pattern_height > self.height()) {
+            let target_size = (pattern_width.max(pattern_height) + 2 * config.expansion_margin)
+                .min(config.max_board_size.max(self.width()).max(self.height()));
+            *self = Board::new(target_size.max(self.width()), target_size.max(self.height()));
         }
         
-        // Sprawdzamy czy w zewnętrznym pierścieniu i następnych `margin` warstwach są żywe komórki
-        // Jeśli znajdziemy żywą komórkę w obszarze który zostałby usunięty lub zbyt blisko krawędzi, 
-        // nie możemy usunąć pierścienia
-        
-        for layer in 0..=margin {
-            if layer >= size / 2 {
-                break; // Nie ma więcej warstw do sprawdzenia
-            }
-            
-            // Sprawdzamy warstwę `layer` od krawędzi
-            // Górny i dolny wiersz warstwy
-            for x in layer..(size - layer) {
-                if let Some(CellState::Alive) = self.get_cell(x, layer) {
-                    return false;
-                }
-                if let Some(CellState::Alive) = self.get_cell(x, size - 1 - layer) {
-                    return false;
-                }
-            }
-            
-            // Lewa i prawa kolumna warstwy (bez narożników już sprawdzonych)
-            for y in (layer + 1)..(size - 1 - layer) {
-                if let Some(CellState::Alive) = self.get_cell(layer, y) {
-                    return false;
-                }
-                if let Some(CellState::Alive) = self.get_cell(size - 1 - layer, y) {
-                    return false;
-                }
-            }
+        // Wzór większy od planszy jest obcinany równo z obu stron
+        let origin_x = (self.width() as i32 - pattern_width as i32) / 2 - min_x;
+        let origin_y = (self.height() as i32 - pattern_height as i32) / 2 - min_y;
+        let mut all_placed = true;
+        for pos in &pattern.cells {
+            let (x, y) = (pos.x + origin_x, pos.y + origin_y);
+            all_placed &= x >= 0 && y >= 0 && self.set_cell(x as usize, y as usize, CellState::Alive);
         }
         
-        true
+        all_placed
     }
-    
-    /// Usuwa cały zewnętrzny pierścień z planszy
+
+    /// Optymalizuje rozmiar planszy poprzez obcinanie pustych krawędzi
     /// 
-    /// Tworzy nową planszę o rozmiarze (size - 2) x (size - 2) i kopiuje
-    /// wszystkie komórki z wewnętrznego obszaru, pomijając zewnętrzny pierścień.
-    fn remove_outer_ring(&self) -> Board {
-        let old_size = self.width(); // Plansza jest kwadratem
-        
-        if old_size <= 2 {
-            // Nie można usunąć pierścienia z planszy 2x2 lub mniejszej
-            return self.clone();
-        }
+    /// Algorytm działa następująco:
+    /// 1. Wyznacza prostokąt otaczający żywe komórki
+    /// 2. Każdą krawędź przycina niezależnie, zachowując `margin` pustych komórek
+    ///    od najbliższych żywych komórek (krawędzie bliższe niż `margin` zostają)
+    /// 3. Pustą planszę zmniejsza w każdym wymiarze do co najwyżej 2 * margin + 1
+    /// 
+    /// Szerokość i wysokość są przycinane osobno, więc wynik może być prostokątem
+    /// (np. poziomy rząd komórek na szerokiej planszy daje niską, szeroką planszę).
+    /// Zwraca None, gdy żadnej krawędzi nie można przyciąć.
+    pub fn optimize_size(&self, margin: usize) -> Option<Board> {
+        let (left, top, right, bottom) = match self.alive_bounding_box() {
+            Some((min_x, min_y, max_x, max_y)) => (
+                min_x.saturating_sub(margin),
+                min_y.saturating_sub(margin),
+                (self.width() - 1 - max_x).saturating_sub(margin),
+                (self.height() - 1 - max_y).saturating_sub(margin),
+            ),
+            None => {
+                // Pusta plansza - zostawiamy wyśrodkowany fragment o boku 2 * margin + 1
+                let minimal_size = 2 * margin + 1;
+                let extra_x = self.width().saturating_sub(minimal_size);
+                let extra_y = self.height().saturating_sub(minimal_size);
+                (extra_x / 2, extra_y / 2, extra_x - extra_x / 2, extra_y - extra_y / 2)
+            }
+        };
         
-        let new_size = old_size - 2;
-        let mut new_board = Board::new(new_size, new_size);
+        // Zwracamy zoptymalizowaną planszę tylko jeśli rzeczywiście ją zmniejszyliśmy
+        if left + top + right + bottom == 0 {
+            return None;
+        }
         
-        // Kopiujemy wewnętrzny obszar (pomijamy zewnętrzny pierścień)
-        for y in 1..(old_size - 1) {
-            for x in 1..(old_size - 1) {
-                if let Some(cell_state) = self.get_cell(x, y) {
-                    // Przesuwamy współrzędne o -1 w obu osiach
-                    new_board.set_cell(x - 1, y - 1, cell_state);
-                }
+        let new_width = self.width() - left - right;
+        let new_height = self.height() - top - bottom;
+        let mut new_board = Board::new(new_width, new_height);
+        for y in 0..new_height {
+            for x in 0..new_width {
+                new_board.copy_cell_from(self, x + left, y + top, x, y);
             }
         }
         
-        new_board
+        Some(new_board)
     }
 
     /// Zmienia rozmiar planszy do określonych wymiarów
@@ -324,13 +298,11 @@ impl Board {
         // Kopiujemy komórki
         for y in start_y..end_y {
             for x in start_x..end_x {
-                if let Some(cell_state) = self.get_cell(x, y) {
-                    let new_x = (x - start_x) + offset_x;
-                    let new_y = (y - start_y) + offset_y;
-                    
-                    if new_x < new_width && new_y < new_height {
-                        new_board.set_cell(new_x, new_y, cell_state);
-                    }
+                let new_x = (x - start_x) + offset_x;
+                let new_y = (y - start_y) + offset_y;
+                
+                if new_x < new_width && new_y < new_height {
+                    new_board.copy_cell_from(self, x, y, new_x, new_y);
                 }
             }
         }
@@ -342,4 +314,147 @@ impl Board {
     pub fn resize_to_square(&self, size: usize) -> Board {
         self.resize_to(size, size)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::glider_gun::create_glider_gun;
+    use crate::config::rules::BoardSizeMode;
+    
+    #[test]
+    fn placing_glider_gun_expands_small_board_and_centers_it() {
+        let config = GameConfig::default();
+        let gun = create_glider_gun();
+        assert_eq!(gun.size, (36, 9));
+        
+        let mut board = Board::new(21, 21);
+        assert!(board.place_centered(&gun, &config));
+        
+        let expected_size = 36 + 2 * config.expansion_margin;
+        assert_eq!((board.width(), board.height()), (expected_size, expected_size));
+        assert_eq!(board.count_alive_cells(), gun.cells.len());
+        
+        let (min_x, min_y, max_x, max_y) = board.alive_bounding_box().unwrap();
+        assert_eq!((max_x - min_x + 1, max_y - min_y + 1), (36, 9));
+        assert_eq!((min_x, min_y), ((expected_size - 36) / 2, (expected_size - 9) / 2));
+    }
+    
+    #[test]
+    fn glider_gun_is_clipped_on_static_board() {
+        let mut config = GameConfig::default();
+        config.set_board_size_mode(BoardSizeMode::Static);
+        
+        let mut board = Board::new(21, 21);
+        assert!(!board.place_centered(&create_glider_gun(), &config));
+        assert_eq!((board.width(), board.height()), (21, 21));
+    }
+    
+    #[test]
+    fn optimize_size_trims_each_side_to_the_margin() {
+        // Szeroka plansza z zawartością przy lewej (krótszej) krawędzi
+        let mut board = Board::new(41, 11);
+        for x in 5..=7 {
+            board.set_cell(x, 6, CellState::Alive);
+        }
+        
+        // Lewa: 5 - 3 = 2, prawa: 40 - 7 - 3 = 30, górna: 6 - 3 = 3, dolna: 10 - 6 - 3 = 1
+        let optimized = board.optimize_size(3).unwrap();
+        assert_eq!((optimized.width(), optimized.height()), (41 - 2 - 30, 11 - 3 - 1));
+        assert_eq!(optimized.iter_alive_cells().collect::<Vec<_>>(), vec![(3, 3), (4, 3), (5, 3)]);
+    }
+    
+    #[test]
+    fn optimize_size_keeps_sides_closer_than_the_margin() {
+        let mut board = Board::new(41, 11);
+        board.set_cell(1, 9, CellState::Alive);
+        
+        // Lewa i dolna krawędź są bliżej niż margines, więc zostają
+        let optimized = board.optimize_size(3).unwrap();
+        assert_eq!((optimized.width(), optimized.height()), (5, 5));
+        assert_eq!(optimized.iter_alive_cells().collect::<Vec<_>>(), vec![(1, 3)]);
+        
+        // Plansza, której żadnej krawędzi nie można przyciąć, zostaje bez zmian
+        assert_eq!(optimized.optimize_size(3), None);
+    }
+    
+    #[test]
+    fn optimize_size_keeps_content_wider_than_the_shorter_side() {
+        for (width, height, transposed) in [(41, 11, false), (11, 41, true)] {
+            // Rząd 26 komórek - dłuższy niż krótszy bok planszy
+            let mut board = Board::new(width, height);
+            for i in 5..=30 {
+                let (x, y) = if transposed { (2, i) } else { (i, 2) };
+                board.set_cell(x, y, CellState::Alive);
+            }
+            
+            let optimized = board.optimize_size(2).unwrap();
+            let expected_size = if transposed { (5, 30) } else { (30, 5) };
+            assert_eq!((optimized.width(), optimized.height()), expected_size);
+            assert_eq!(optimized.count_alive_cells(), 26);
+            
+            let (min_x, min_y, max_x, max_y) = optimized.alive_bounding_box().unwrap();
+            let expected_box = if transposed { (2, 2, 2, 27) } else { (2, 2, 27, 2) };
+            assert_eq!((min_x, min_y, max_x, max_y), expected_box);
+        }
+    }
+    
+    #[test]
+    fn optimize_size_shrinks_empty_board_around_its_center() {
+        let optimized = Board::new(21, 15).optimize_size(3).unwrap();
+        assert_eq!((optimized.width(), optimized.height()), (7, 7));
+        assert_eq!(Board::new(7, 7).optimize_size(3), None);
+    }
+    
+    /// Niekwadratowa plansza z komórkami przy lewej krawędzi
+    fn board_near_edge() -> Board {
+        let mut board = Board::new(9, 5);
+        for &(x, y) in &[(0, 2), (1, 2), (4, 0), (8, 4)] {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    /// Sprawdza, że każda żywa komórka `old` leży w `expanded` pod `old + offset`
+    fn assert_shifted_by(old: &Board, expanded: &Board, (offset_x, offset_y): ExpansionOffset) {
+        assert_eq!(expanded.count_alive_cells(), old.count_alive_cells());
+        for (x, y) in old.iter_alive_cells() {
+            assert_eq!(expanded.get_cell(x + offset_x, y + offset_y), Some(CellState::Alive));
+        }
+    }
+    
+    #[test]
+    fn all_expansions_report_the_same_offset() {
+        let board = board_near_edge();
+        let config = GameConfig::default();
+        assert_eq!(config.expansion_layers, 1);
+        
+        let (expanded, expand_offset) = board.expand();
+        let (layered, layers_offset) = board.expand_by_layers(1).unwrap();
+        let (auto, auto_offset) = board.auto_expand_if_needed(config.expansion_margin, &config).unwrap();
+        
+        assert_eq!(expand_offset, (1, 1));
+        assert_eq!(layers_offset, expand_offset);
+        assert_eq!(auto_offset, expand_offset);
+        assert_eq!(expand_offset, board.expansion_offset(expanded.width(), expanded.height()));
+        
+        for (expanded, offset) in [(&expanded, expand_offset), (&layered, layers_offset), (&auto, auto_offset)] {
+            assert_eq!((expanded.width(), expanded.height()), (11, 7));
+            assert_shifted_by(&board, expanded, offset);
+        }
+    }
+    
+    #[test]
+    fn multi_layer_expansions_report_the_same_offset() {
+        let board = board_near_edge();
+        let mut config = GameConfig::default();
+        config.set_expansion_layers(3);
+        
+        let (layered, layers_offset) = board.expand_by_layers(3).unwrap();
+        let (auto, auto_offset) = board.auto_expand_if_needed(config.expansion_margin, &config).unwrap();
+        
+        assert_eq!(layers_offset, (3, 3));
+        assert_eq!(auto_offset, layers_offset);
+        assert_eq!(layered, auto);
+        assert_shifted_by(&board, &layered, layers_offset);
+    }
+}
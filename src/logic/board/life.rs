@@ -0,0 +1,52 @@
+/// Moduł eksportu planszy do formatów Life 1.05 i Life 1.06 (.lif)
+/// 
+/// Life 1.06 zapisuje współrzędne każdej żywej komórki, a Life 1.05 - bloki
+/// wierszy `.` (martwa) i `*` (żywa) poprzedzone położeniem bloku `#P x y`.
+
+use std::fmt::Write;
+use super::structure::{Board, CellState};
+
+impl Board {
+    /// Eksportuje żywe komórki planszy w formacie Life 1.06
+    /// 
+    /// Współrzędne są liczone względem lewego górnego rogu obszaru żywych komórek.
+    /// Pusta plansza daje sam nagłówek.
+    pub fn to_life106(&self) -> String {
+        let mut result = String::from("#Life 1.06\n");
+        let Some((min_x, min_y, _, _)) = self.alive_bounding_box() else {
+            return result;
+        };
+        
+        for (x, y) in self.iter_alive_cells() {
+            let _ = writeln!(result, "{} {}", x - min_x, y - min_y);
+        }
+        
+        result
+    }
+    
+    /// Eksportuje żywy obszar planszy w formacie Life 1.05 (jeden blok w (0, 0))
+    /// 
+    /// Martwe komórki na końcu wiersza są pomijane, a pusty wiersz zapisywany jest
+    /// jako `.`, aby nie zniknął przy wczytywaniu. Pusta plansza daje sam nagłówek.
+    pub fn to_life105(&self) -> String {
+        let mut result = String::from("#Life 1.05\n");
+        let Some((min_x, min_y, max_x, max_y)) = self.alive_bounding_box() else {
+            return result;
+        };
+        
+        result.push_str("#P 0 0\n");
+        for y in min_y..=max_y {
+            let row: String = (min_x..=max_x)
+                .map(|x| match self.get_cell(x, y) {
+                    Some(CellState::Alive) => '*',
+                    _ => '.',
+                })
+                .collect();
+            let row = row.trim_end_matches('.');
+            result.push_str(if row.is_empty() { "." } else { row });
+            result.push('\n');
+        }
+        
+        result
+    }
+}
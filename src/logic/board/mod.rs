@@ -5,13 +5,24 @@
 /// - Logikę mapowania współrzędnych 2D na indeksy 1D
 /// - Funkcje dynamicznego rozszerzania planszy
 /// - Narzędzia do optymalizacji rozmiaru planszy
+/// - Eksport planszy do formatu RLE, plaintext i Life 1.05/1.06
+/// - Zapis planszy jako siatki znaków do schowka
+/// - Wybór gęstych lub rzadkich obliczeń następnej generacji
+/// - Wykrywanie okresu oscylatorów i statków
 
 // Eksportujemy główne komponenty modułu
 pub mod structure;
 pub mod expansion;
+pub mod rle;
+pub mod plaintext;
+pub mod life;
+pub mod ascii;
+pub mod backend;
+pub mod analysis;
 
 // Re-eksportujemy najważniejsze typy dla łatwiejszego dostępu
 pub use structure::{Board, CellState};
+pub use expansion::ExpansionOffset;
 
 // Opcjonalnie można dodać aliasy dla często używanych typów
 pub type Position = (usize, usize);
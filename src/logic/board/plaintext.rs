@@ -0,0 +1,32 @@
+use super::structure::{Board, CellState};
+
+/// Moduł eksportu planszy do formatu plaintext (.cells)
+/// 
+/// Format plaintext zapisuje każdy wiersz wzoru jako ciąg znaków `.` (martwa)
+/// i `O` (żywa) - jest czytelny dla człowieka i prosty do edycji ręcznej.
+
+impl Board {
+    /// Eksportuje żywy obszar planszy jako tekst w formacie plaintext
+    /// 
+    /// Zapisywany jest tylko prostokąt obejmujący żywe komórki.
+    /// Pusta plansza daje pusty tekst.
+    pub fn to_plaintext(&self) -> String {
+        let Some((min_x, min_y, max_x, max_y)) = self.alive_bounding_box() else {
+            return String::new();
+        };
+        
+        let mut result = String::with_capacity((max_x - min_x + 2) * (max_y - min_y + 1));
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let symbol = match self.get_cell(x, y) {
+                    Some(CellState::Alive) => 'O',
+                    _ => '.',
+                };
+                result.push(symbol);
+            }
+            result.push('\n');
+        }
+        
+        result
+    }
+}
@@ -0,0 +1,138 @@
+use super::structure::{Board, CellState};
+use crate::config::rules::GameConfig;
+
+/// Moduł eksportu planszy do formatu RLE (Run Length Encoded)
+/// 
+/// Format RLE jest standardowym sposobem wymiany wzorów w społeczności
+/// Game of Life - zapisuje tylko obszar żywych komórek z kompresją powtórzeń.
+
+/// Maksymalna długość linii treści RLE (zgodnie z konwencją formatu)
+const RLE_LINE_LENGTH: usize = 70;
+
+impl Board {
+    /// Eksportuje żywy obszar planszy jako tekst RLE
+    /// 
+    /// Nagłówek zawiera rozmiar obszaru żywych komórek oraz reguły
+    /// z podanej konfiguracji gry. Pusta plansza daje poprawny nagłówek
+    /// `x = 0, y = 0` z samym terminatorem `!` - `Pattern::from_rle` odczytuje go
+    /// jako pusty wzór 0x0, którego umieszczenie pozostawia czystą planszę.
+    pub fn to_rle(&self, config: &GameConfig) -> String {
+        let rule = config.rule_string();
+        
+        let Some((min_x, min_y, max_x, max_y)) = self.alive_bounding_box() else {
+            return format!("x = 0, y = 0, rule = {}\n!\n", rule);
+        };
+        
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+        
+        // Kodujemy każdy wiersz jako ciąg tokenów (licznik + znak)
+        let mut tokens: Vec<String> = Vec::new();
+        let mut pending_rows = 0;
+        
+        for y in min_y..=max_y {
+            let mut row_tokens = Vec::new();
+            let mut x = min_x;
+            
+            while x <= max_x {
+                let state = self.get_cell(x, y).unwrap_or(CellState::Dead);
+                let run_start = x;
+                while x <= max_x && self.get_cell(x, y).unwrap_or(CellState::Dead) == state {
+                    x += 1;
+                }
+                
+                // Martwe komórki na końcu wiersza są pomijane
+                if state == CellState::Dead && x > max_x {
+                    break;
+                }
+                
+                let symbol = if state == CellState::Alive { 'o' } else { 'b' };
+                row_tokens.push(Self::rle_token(x - run_start, symbol));
+            }
+            
+            if row_tokens.is_empty() {
+                // Puste wiersze łączymy w jeden licznik '$'
+                pending_rows += 1;
+                continue;
+            }
+            
+            if y > min_y {
+                tokens.push(Self::rle_token(pending_rows + 1, '$'));
+            }
+            pending_rows = 0;
+            tokens.extend(row_tokens);
+        }
+        tokens.push("!".to_string());
+        
+        // Składamy tokeny w linie o ograniczonej długości
+        let mut result = format!("x = {}, y = {}, rule = {}\n", width, height, rule);
+        let mut line = String::new();
+        for token in tokens {
+            if line.len() + token.len() > RLE_LINE_LENGTH {
+                result.push_str(&line);
+                result.push('\n');
+                line.clear();
+            }
+            line.push_str(&token);
+        }
+        result.push_str(&line);
+        result.push('\n');
+        
+        result
+    }
+    
+    /// Tworzy pojedynczy token RLE - licznik jest pomijany dla pojedynczego wystąpienia
+    fn rle_token(count: usize, symbol: char) -> String {
+        if count == 1 {
+            symbol.to_string()
+        } else {
+            format!("{}{}", count, symbol)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::Pattern;
+    
+    /// Zapisuje planszę jako RLE, odczytuje wzór i umieszcza go na środku nowej planszy
+    fn round_trip(board: &Board, config: &GameConfig) -> (Pattern, Board) {
+        let pattern = Pattern::from_rle(&board.to_rle(config)).expect("zapis RLE powinien dać się odczytać");
+        let mut placed = Board::new(board.width(), board.height());
+        assert!(placed.place_centered(&pattern, config));
+        (pattern, placed)
+    }
+    
+    #[test]
+    fn pattern_round_trips_through_rle() {
+        let config = GameConfig::default();
+        let mut board = Board::new(120, 40);
+        // Szybowiec, blok oddzielony pustymi wierszami i wiersz dłuższy niż linia RLE
+        for (x, y) in [(3, 2), (4, 3), (2, 4), (3, 4), (4, 4), (10, 9), (11, 9), (10, 10), (11, 10)] {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        for x in (0..100).step_by(3) {
+            board.set_cell(x + 5, 20, CellState::Alive);
+        }
+        
+        let (pattern, placed) = round_trip(&board, &config);
+        
+        let (min_x, min_y, max_x, max_y) = board.alive_bounding_box().unwrap();
+        assert_eq!(pattern.size, ((max_x - min_x + 1) as u32, (max_y - min_y + 1) as u32));
+        assert_eq!(pattern.cells.len(), board.count_alive_cells());
+        assert_eq!(placed.to_ascii('#', '.'), board.to_ascii('#', '.'));
+    }
+    
+    #[test]
+    fn empty_board_round_trips_to_empty_pattern() {
+        let config = GameConfig::default();
+        let board = Board::new(9, 9);
+        assert_eq!(board.to_rle(&config), format!("x = 0, y = 0, rule = {}\n!\n", config.rule_string()));
+        
+        let (pattern, placed) = round_trip(&board, &config);
+        assert_eq!(pattern.size, (0, 0));
+        assert!(pattern.cells.is_empty());
+        assert_eq!(placed, board);
+    }
+}
@@ -17,24 +17,39 @@ impl Default for CellState {
 #[derive(Debug, Clone)]
 pub struct Board {
     cells: Vec<CellState>,
+    /// Liczba generacji, które przeżyła każda komórka (0 dla martwych i nowo narodzonych)
+    ages: Vec<u32>,
     width: usize,
     height: usize,
 }
 
+/// Plansze są równe, gdy mają te same wymiary i stany komórek - wiek komórek
+/// nie wpływa na porównanie (np. przy wykrywaniu oscylatorów)
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.cells == other.cells
+    }
+}
+
 impl Board {
+    /// Tworzy pustą planszę o podanych wymiarach
+    /// 
+    /// Panikuje, gdy liczba komórek nie mieści się w `usize`.
     pub fn new(width: usize, height: usize) -> Self {
-        let total_cells = width * height;
+        let total_cells = width.checked_mul(height)
+            .expect("board dimensions overflow the number of cells");
         Self {
             cells: vec![CellState::Dead; total_cells],
+            ages: vec![0; total_cells],
             width,
             height,
         }
     }
     
-    /// Tworzy nową planszę z wymiarami z konfiguracji
-    pub fn new_from_config() -> Self {
-        let config = crate::config::get_config();
-        Self::new(config.initial_board_size, config.initial_board_size)
+    /// Tworzy nową planszę z wymiarami z konfiguracji (zależnymi od trybu rozmiaru)
+    pub fn new_from_config(config: &crate::config::rules::GameConfig) -> Self {
+        let (width, height) = config.get_current_board_size();
+        Self::new(width, height)
     }
 
     /// Zwraca szerokość planszy
@@ -49,7 +64,17 @@ impl Board {
 
     /// Zwraca całkowitą liczbę komórek na planszy
     pub fn total_cells(&self) -> usize {
-        self.width * self.height
+        self.cells.len()
+    }
+
+    /// Zwraca wszystkie komórki (wiersz po wierszu)
+    pub(crate) fn cells(&self) -> &[CellState] {
+        &self.cells
+    }
+
+    /// Zwraca mutowalny dostęp do wszystkich komórek (wiersz po wierszu)
+    pub(crate) fn cells_mut(&mut self) -> &mut [CellState] {
+        &mut self.cells
     }
 
     /// Mapuje współrzędne 2D (x, y) na indeks 1D w tablicy
@@ -78,6 +103,10 @@ impl Board {
     /// Ustawia stan komórki na podanych współrzędnych
     pub fn set_cell(&mut self, x: usize, y: usize, state: CellState) -> bool {
         if let Some(index) = self.coords_to_index(x, y) {
+            // Zmiana stanu (narodziny lub śmierć) zeruje wiek komórki
+            if self.cells[index] != state {
+                self.ages[index] = 0;
+            }
             self.cells[index] = state;
             true
         } else {
@@ -85,6 +114,44 @@ impl Board {
         }
     }
 
+    /// Zwraca liczbę generacji, które przeżyła komórka na podanych współrzędnych
+    pub fn cell_age(&self, x: usize, y: usize) -> Option<u32> {
+        self.coords_to_index(x, y).map(|index| self.ages[index])
+    }
+
+    /// Kopiuje stan i wiek komórki (source_x, source_y) z innej planszy do (x, y)
+    /// 
+    /// Używane przy zmianie rozmiaru planszy, aby wiek podążał za przesuniętymi komórkami.
+    pub(crate) fn copy_cell_from(&mut self, source: &Board, source_x: usize, source_y: usize, x: usize, y: usize) -> bool {
+        match (source.coords_to_index(source_x, source_y), self.coords_to_index(x, y)) {
+            (Some(source_index), Some(index)) => {
+                self.cells[index] = source.cells[source_index];
+                self.ages[index] = source.ages[source_index];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Ustala wiek komórek tej planszy jako następnej generacji planszy `previous`
+    /// 
+    /// Komórki żywe w obu generacjach starzeją się o 1, pozostałe mają wiek 0.
+    /// Plansze muszą mieć te same wymiary - w przeciwnym razie wiek jest zerowany.
+    pub(crate) fn advance_ages_from(&mut self, previous: &Board) {
+        if self.width != previous.width || self.height != previous.height {
+            self.ages.fill(0);
+            return;
+        }
+        
+        for index in 0..self.cells.len() {
+            self.ages[index] = if self.cells[index] == CellState::Alive && previous.cells[index] == CellState::Alive {
+                previous.ages[index].saturating_add(1)
+            } else {
+                0
+            };
+        }
+    }
+
     /// Przełącza stan komórki na podanych współrzędnych
     /// Martwa komórka staje się żywa, żywa staje się martwa
     pub fn toggle_cell(&mut self, x: usize, y: usize) -> bool {
@@ -99,11 +166,95 @@ impl Board {
         }
     }
 
+    /// Wypełnia spójny obszar komórek o tym samym stanie (jak "wiadro z farbą")
+    /// 
+    /// Obszar obejmuje komórki połączone bokami (4 kierunki) z komórką (x, y)
+    /// i mające jej stan. Zwraca liczbę zmienionych komórek - 0 jeśli współrzędne
+    /// leżą poza planszą lub komórka ma już stan `new_state`.
+    pub fn flood_fill(&mut self, x: usize, y: usize, new_state: CellState) -> usize {
+        let target_state = match self.get_cell(x, y) {
+            Some(state) if state != new_state => state,
+            _ => return 0,
+        };
+        
+        // Stos zamiast rekurencji - duże obszary nie przepełnią stosu wywołań
+        let mut stack = vec![(x, y)];
+        let mut changed = 0;
+        
+        while let Some((cell_x, cell_y)) = stack.pop() {
+            if self.get_cell(cell_x, cell_y) != Some(target_state) {
+                continue;
+            }
+            
+            // Zmieniona komórka nie pasuje już do obszaru, więc nie zostanie odwiedzona ponownie
+            self.set_cell(cell_x, cell_y, new_state);
+            changed += 1;
+            
+            if cell_x > 0 {
+                stack.push((cell_x - 1, cell_y));
+            }
+            if cell_x + 1 < self.width {
+                stack.push((cell_x + 1, cell_y));
+            }
+            if cell_y > 0 {
+                stack.push((cell_x, cell_y - 1));
+            }
+            if cell_y + 1 < self.height {
+                stack.push((cell_x, cell_y + 1));
+            }
+        }
+        
+        changed
+    }
+
     /// Czyści całą planszę (ustawia wszystkie komórki jako martwe)
     pub fn clear(&mut self) {
         for cell in &mut self.cells {
             *cell = CellState::Dead;
         }
+        self.ages.fill(0);
+    }
+
+    /// Odwraca stan wszystkich komórek (żywe stają się martwe, a martwe żywe)
+    /// 
+    /// Nowo ożywione komórki mają wiek 0, jak komórki ustawione ręcznie.
+    pub fn invert(&mut self) {
+        for cell in &mut self.cells {
+            *cell = match cell {
+                CellState::Dead => CellState::Alive,
+                CellState::Alive => CellState::Dead,
+            };
+        }
+        self.ages.fill(0);
+    }
+
+    /// Przesuwa wszystkie komórki o wektor (dx, dy)
+    /// 
+    /// Komórki wychodzące poza planszę są usuwane, a przy `wrap` (plansza
+    /// toroidalna) pojawiają się po przeciwnej stronie. Wiek komórek jest zachowywany.
+    pub fn shift(&mut self, dx: i32, dy: i32, wrap: bool) {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let mut cells = vec![CellState::Dead; self.cells.len()];
+        let mut ages = vec![0; self.ages.len()];
+        
+        for (index, _) in self.cells.iter().enumerate().filter(|(_, cell)| **cell == CellState::Alive) {
+            let x = (index % self.width) as i64 + dx as i64;
+            let y = (index / self.width) as i64 + dy as i64;
+            let (x, y) = if wrap {
+                (x.rem_euclid(width), y.rem_euclid(height))
+            } else if (0..width).contains(&x) && (0..height).contains(&y) {
+                (x, y)
+            } else {
+                continue;
+            };
+            
+            let new_index = y as usize * self.width + x as usize;
+            cells[new_index] = CellState::Alive;
+            ages[new_index] = self.ages[index];
+        }
+        
+        self.cells = cells;
+        self.ages = ages;
     }
 
     /// Sprawdza czy współrzędne mieszczą się w granicach planszy
@@ -128,10 +279,146 @@ impl Board {
             .map(|(x, y, _)| (x, y))
     }
 
+    /// Zwraca współrzędne wszystkich komórek spełniających podany predykat
+    /// 
+    /// Predykat otrzymuje (x, y, state) dla każdej komórki - np. wyszukanie komórek,
+    /// które umrą w następnej generacji:
+    /// `board.find_cells(|x, y, _| will_cell_die(&board, x, y, &config))`
+    pub fn find_cells<F>(&self, predicate: F) -> Vec<(usize, usize)>
+    where
+        F: Fn(usize, usize, CellState) -> bool,
+    {
+        self.iter_cells()
+            .filter(|&(x, y, state)| predicate(x, y, state))
+            .map(|(x, y, _)| (x, y))
+            .collect()
+    }
+
+    /// Zwraca prostokąt obejmujący wszystkie żywe komórki
+    /// 
+    /// Wynik to (min_x, min_y, max_x, max_y) włącznie lub None dla pustej planszy.
+    pub fn alive_bounding_box(&self) -> Option<(usize, usize, usize, usize)> {
+        self.iter_alive_cells().fold(None, |bounds, (x, y)| match bounds {
+            None => Some((x, y, x, y)),
+            Some((min_x, min_y, max_x, max_y)) => {
+                Some((min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)))
+            }
+        })
+    }
+
     /// Zlicza liczbę żywych komórek na planszy
     pub fn count_alive_cells(&self) -> usize {
         self.cells.iter()
             .filter(|&&state| state == CellState::Alive)
             .count()
     }
-}
\ No newline at end of file
+
+    /// Zlicza narodziny i śmierci między tą planszą a jej następną generacją
+    /// 
+    /// Obie plansze muszą mieć te same wymiary (czyli następna generacja
+    /// przed ewentualnym rozszerzeniem lub przycięciem). Zwraca (narodziny, śmierci).
+    pub fn count_changes(&self, next: &Board) -> (usize, usize) {
+        debug_assert_eq!((self.width, self.height), (next.width, next.height));
+        
+        self.cells.iter()
+            .zip(&next.cells)
+            .fold((0, 0), |(births, deaths), (&before, &after)| match (before, after) {
+                (CellState::Dead, CellState::Alive) => (births + 1, deaths),
+                (CellState::Alive, CellState::Dead) => (births, deaths + 1),
+                _ => (births, deaths),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::rules::GameConfig;
+    
+    /// Tworzy planszę z podanymi żywymi komórkami
+    fn board_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Board {
+        let mut board = Board::new(width, height);
+        for &(x, y) in cells {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    /// Pierścień żywych komórek o rogach (1, 1) i (5, 5)
+    fn ring() -> Board {
+        let mut cells = Vec::new();
+        for i in 1..=5 {
+            cells.extend([(i, 1), (i, 5), (1, i), (5, i)]);
+        }
+        board_with(7, 7, &cells)
+    }
+    
+    #[test]
+    fn flood_fill_stays_inside_a_closed_ring() {
+        let mut board = ring();
+        assert_eq!(board.flood_fill(3, 3, CellState::Alive), 9);
+        assert_eq!(board.count_alive_cells(), 16 + 9);
+        assert_eq!(board.get_cell(0, 0), Some(CellState::Dead));
+    }
+    
+    #[test]
+    fn flood_fill_does_not_leak_through_diagonal_gaps() {
+        let mut board = board_with(5, 5, &[(1, 0), (0, 1)]);
+        assert_eq!(board.flood_fill(0, 0, CellState::Alive), 1);
+        
+        let mut board = ring();
+        assert_eq!(board.flood_fill(0, 0, CellState::Alive), 49 - 16 - 9);
+        assert_eq!(board.get_cell(3, 3), Some(CellState::Dead));
+    }
+    
+    #[test]
+    fn flood_fill_with_the_same_state_changes_nothing() {
+        let mut board = ring();
+        assert_eq!(board.flood_fill(3, 3, CellState::Dead), 0);
+        assert_eq!(board.flood_fill(10, 10, CellState::Alive), 0);
+        assert_eq!(board, ring());
+    }
+    
+    #[test]
+    fn invert_is_an_involution() {
+        let original = ring();
+        let mut board = original.clone();
+        
+        board.invert();
+        assert_eq!(board.count_alive_cells(), board.total_cells() - original.count_alive_cells());
+        assert_eq!(board.get_cell(3, 3), Some(CellState::Alive));
+        assert_eq!(board.get_cell(1, 1), Some(CellState::Dead));
+        
+        board.invert();
+        assert_eq!(board, original);
+    }
+    
+    #[test]
+    fn shift_without_wrap_drops_cells_leaving_the_board() {
+        let mut board = board_with(5, 5, &[(0, 0), (3, 2), (4, 4)]);
+        board.shift(1, -1, false);
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), vec![(4, 1)]);
+    }
+    
+    #[test]
+    fn shift_with_wrap_moves_cells_to_the_opposite_side() {
+        let mut board = board_with(5, 5, &[(0, 0), (3, 2), (4, 4)]);
+        board.shift(1, -1, true);
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), vec![(4, 1), (0, 3), (1, 4)]);
+        
+        board.shift(-1, 1, true);
+        assert_eq!(board, board_with(5, 5, &[(0, 0), (3, 2), (4, 4)]));
+    }
+    
+    #[test]
+    fn shift_keeps_cell_ages() {
+        let config = GameConfig::default();
+        let block = board_with(6, 6, &[(1, 1), (2, 1), (1, 2), (2, 2)]);
+        let mut board = block.next_generation(&config).next_generation(&config);
+        assert_eq!(board.cell_age(1, 1), Some(2));
+        
+        board.shift(2, 3, false);
+        assert_eq!(board.cell_age(3, 4), Some(2));
+        assert_eq!(board.cell_age(1, 1), Some(0));
+    }
+}
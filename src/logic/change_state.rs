@@ -1,9 +1,12 @@
 /// Moduł zarządzania zmianą stanu komórek
 /// 
 /// Zawiera logikę obsługi klikania i przeciągania po komórkach planszy.
-/// Implementuje zaawansowaną logikę przeciągania z zachowaniem pierwszej akcji.
+/// Implementuje zaawansowaną logikę przeciągania z zachowaniem pierwszej akcji,
+/// pędzel obejmujący kwadrat komórek oraz opcjonalne odbijanie zmian
+/// względem osi symetrii planszy.
 
 use crate::logic::board::{Board, CellState};
+use crate::logic::selection::{BoardClipboard, Selection};
 
 /// Typ akcji wykonanej na pierwszej komórce podczas przeciągania
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -14,6 +17,126 @@ pub enum DragAction {
     KillCell,
 }
 
+/// Sposób edycji komórek kliknięciem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EditMode {
+    /// Kliknięcie przełącza pojedynczą komórkę, przeciąganie rysuje
+    #[default]
+    Toggle,
+    /// Kliknięcie wypełnia spójny obszar komórek przeciwnym stanem
+    FloodFill,
+}
+
+/// Symetria edycji - zmiany są odbijane względem środka planszy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymmetryMode {
+    /// Bez odbijania
+    #[default]
+    None,
+    /// Odbicie względem osi poziomej (góra-dół)
+    Horizontal,
+    /// Odbicie względem osi pionowej (lewo-prawo)
+    Vertical,
+    /// Odbicie względem obu osi (symetria czterokrotna)
+    Both,
+}
+
+impl SymmetryMode {
+    /// Wszystkie dostępne rodzaje symetrii (kolejność jak w interfejsie)
+    pub const ALL: [SymmetryMode; 4] = [
+        SymmetryMode::None,
+        SymmetryMode::Horizontal,
+        SymmetryMode::Vertical,
+        SymmetryMode::Both,
+    ];
+    
+    /// Zwraca nazwę wyświetlaną w interfejsie
+    pub fn name(&self) -> &'static str {
+        match self {
+            SymmetryMode::None => "None",
+            SymmetryMode::Horizontal => "Horizontal",
+            SymmetryMode::Vertical => "Vertical",
+            SymmetryMode::Both => "Both (4-fold)",
+        }
+    }
+    
+    /// Zwraca komórkę (x, y) wraz z jej odbiciami na planszy o podanych wymiarach
+    /// 
+    /// Komórki leżące na osi symetrii nie są powtarzane.
+    pub fn mirrored_cells(&self, x: usize, y: usize, width: usize, height: usize) -> Vec<(usize, usize)> {
+        let mirror_x = width - 1 - x;
+        let mirror_y = height - 1 - y;
+        let candidates = match self {
+            SymmetryMode::None => vec![(x, y)],
+            SymmetryMode::Horizontal => vec![(x, y), (x, mirror_y)],
+            SymmetryMode::Vertical => vec![(x, y), (mirror_x, y)],
+            SymmetryMode::Both => vec![(x, y), (mirror_x, y), (x, mirror_y), (mirror_x, mirror_y)],
+        };
+        
+        let mut cells = Vec::with_capacity(candidates.len());
+        for cell in candidates {
+            if !cells.contains(&cell) {
+                cells.push(cell);
+            }
+        }
+        cells
+    }
+}
+
+/// Rozmiar pędzla - kwadrat komórek zmienianych kliknięciem lub przeciąganiem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BrushSize {
+    /// Pojedyncza komórka
+    #[default]
+    Single,
+    /// Kwadrat 3×3
+    Small,
+    /// Kwadrat 5×5
+    Large,
+}
+
+impl BrushSize {
+    /// Wszystkie dostępne rozmiary pędzla (kolejność jak w interfejsie)
+    pub const ALL: [BrushSize; 3] = [BrushSize::Single, BrushSize::Small, BrushSize::Large];
+    
+    /// Zwraca nazwę wyświetlaną w interfejsie
+    pub fn name(&self) -> &'static str {
+        match self {
+            BrushSize::Single => "1×1",
+            BrushSize::Small => "3×3",
+            BrushSize::Large => "5×5",
+        }
+    }
+    
+    /// Zwraca odległość brzegu pędzla od jego środka (w komórkach)
+    pub fn radius(&self) -> usize {
+        match self {
+            BrushSize::Single => 0,
+            BrushSize::Small => 1,
+            BrushSize::Large => 2,
+        }
+    }
+    
+    /// Zwraca granice pędzla o środku (x, y) przycięte do planszy: (min_x, min_y, max_x, max_y)
+    pub fn bounds(&self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize, usize, usize) {
+        let radius = self.radius();
+        (
+            x.saturating_sub(radius),
+            y.saturating_sub(radius),
+            (x + radius).min(width.saturating_sub(1)),
+            (y + radius).min(height.saturating_sub(1)),
+        )
+    }
+    
+    /// Zwraca komórki pędzla o środku (x, y) leżące na planszy o podanych wymiarach
+    pub fn cells(&self, x: usize, y: usize, width: usize, height: usize) -> Vec<(usize, usize)> {
+        let (min_x, min_y, max_x, max_y) = self.bounds(x, y, width, height);
+        (min_y..=max_y)
+            .flat_map(|cell_y| (min_x..=max_x).map(move |cell_x| (cell_x, cell_y)))
+            .collect()
+    }
+}
+
 /// Stan przeciągania myszy
 #[derive(Debug, Clone)]
 pub struct DragState {
@@ -83,12 +206,27 @@ impl DragState {
 pub struct CellStateManager {
     /// Stan przeciągania
     drag_state: DragState,
+    /// Aktualny sposób edycji komórek
+    edit_mode: EditMode,
+    /// Symetria, z którą odbijane są zmiany komórek
+    symmetry_mode: SymmetryMode,
+    /// Rozmiar pędzla używanego przy klikaniu i przeciąganiu
+    brush_size: BrushSize,
+    /// Komórki zmienione od ostatniego odczytu (dla przyrostowego przewidywania)
+    changed_cells: Vec<(usize, usize)>,
+    /// Czy od ostatniego odczytu zmieniono komórki bez ich zapisywania (np. wypełnianie)
+    changes_untracked: bool,
 }
 
 impl Default for CellStateManager {
     fn default() -> Self {
         Self {
             drag_state: DragState::new(),
+            edit_mode: EditMode::Toggle,
+            symmetry_mode: SymmetryMode::None,
+            brush_size: BrushSize::Single,
+            changed_cells: Vec::new(),
+            changes_untracked: false,
         }
     }
 }
@@ -99,11 +237,112 @@ impl CellStateManager {
         Self::default()
     }
 
+    /// Zwraca aktualny sposób edycji komórek
+    pub fn edit_mode(&self) -> EditMode {
+        self.edit_mode
+    }
+
+    /// Ustawia sposób edycji komórek (przerywa trwające przeciąganie)
+    pub fn set_edit_mode(&mut self, mode: EditMode) {
+        self.edit_mode = mode;
+        self.drag_state.end_drag();
+    }
+
+    /// Zwraca aktualną symetrię edycji
+    pub fn symmetry_mode(&self) -> SymmetryMode {
+        self.symmetry_mode
+    }
+
+    /// Ustawia symetrię edycji
+    pub fn set_symmetry_mode(&mut self, mode: SymmetryMode) {
+        self.symmetry_mode = mode;
+    }
+
+    /// Zwraca aktualny rozmiar pędzla
+    pub fn brush_size(&self) -> BrushSize {
+        self.brush_size
+    }
+
+    /// Ustawia rozmiar pędzla
+    pub fn set_brush_size(&mut self, size: BrushSize) {
+        self.brush_size = size;
+    }
+
+    /// Zwraca komórki zmieniane przy edycji komórki (x, y): pędzel wraz z jego odbiciami
+    fn brush_targets(&self, board: &Board, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let (width, height) = (board.width(), board.height());
+        self.brush_size
+            .cells(x, y, width, height)
+            .into_iter()
+            .flat_map(|(cell_x, cell_y)| self.symmetry_mode.mirrored_cells(cell_x, cell_y, width, height))
+            .collect()
+    }
+
+    /// Zwraca komórki zmienione od ostatniego wywołania i czyści ich listę
+    /// 
+    /// None oznacza, że część zmian nie była zapisywana (np. wypełnianie obszaru)
+    /// i przewidywanie trzeba policzyć od nowa dla całej planszy.
+    pub fn take_changed_cells(&mut self) -> Option<Vec<(usize, usize)>> {
+        let changed_cells = std::mem::take(&mut self.changed_cells);
+        if std::mem::take(&mut self.changes_untracked) {
+            None
+        } else {
+            Some(changed_cells)
+        }
+    }
+
     /// Obsługuje kliknięcie na komórkę (bez przeciągania)
     /// Zwraca true jeśli stan komórki został zmieniony
     pub fn handle_cell_click(&mut self, board: &mut Board, x: usize, y: usize) -> bool {
-        // Proste przełączenie stanu komórki
-        board.toggle_cell(x, y)
+        match self.edit_mode {
+            // Proste przełączenie stanu komórki (wraz z pędzlem i odbiciami)
+            EditMode::Toggle => self.toggle_with_symmetry(board, x, y),
+            EditMode::FloodFill => self.fill_region(board, x, y),
+        }
+    }
+
+    /// Przełącza stan komórki (x, y) i ustawia ten sam stan w pozostałych komórkach
+    /// pędzla oraz w ich odbiciach
+    /// Zwraca true jeśli stan którejkolwiek komórki został zmieniony
+    fn toggle_with_symmetry(&mut self, board: &mut Board, x: usize, y: usize) -> bool {
+        let new_state = match board.get_cell(x, y) {
+            Some(CellState::Dead) => CellState::Alive,
+            Some(CellState::Alive) => CellState::Dead,
+            None => return false,
+        };
+        
+        let mut changed = false;
+        for (cell_x, cell_y) in self.brush_targets(board, x, y) {
+            if board.get_cell(cell_x, cell_y) != Some(new_state) && board.set_cell(cell_x, cell_y, new_state) {
+                self.changed_cells.push((cell_x, cell_y));
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Wypełnia obszar zawierający komórkę (x, y) stanem przeciwnym do jej obecnego
+    /// 
+    /// Przy włączonej symetrii wypełniane są też obszary w odbitych komórkach,
+    /// o ile mają one ten sam stan co komórka (x, y).
+    /// Zwraca true jeśli stan którejkolwiek komórki został zmieniony
+    fn fill_region(&mut self, board: &mut Board, x: usize, y: usize) -> bool {
+        let (old_state, new_state) = match board.get_cell(x, y) {
+            Some(CellState::Dead) => (CellState::Dead, CellState::Alive),
+            Some(CellState::Alive) => (CellState::Alive, CellState::Dead),
+            None => return false,
+        };
+        
+        let mut filled = 0;
+        for (cell_x, cell_y) in self.symmetry_mode.mirrored_cells(x, y, board.width(), board.height()) {
+            // Odbity obszar mógł już zostać wypełniony razem z poprzednim
+            if board.get_cell(cell_x, cell_y) == Some(old_state) {
+                filled += board.flood_fill(cell_x, cell_y, new_state);
+            }
+        }
+        // Wypełnione komórki nie są zapisywane pojedynczo
+        self.changes_untracked |= filled > 0;
+        filled > 0
     }
 
     /// Rozpoczyna przeciąganie na danej komórce
@@ -120,18 +359,22 @@ impl CellStateManager {
             // Rozpoczynamy przeciąganie
             self.drag_state.start_drag(drag_action, (x, y));
 
-            // Wykonujemy pierwszą akcję (przełączenie stanu)
-            board.toggle_cell(x, y)
+            // Wykonujemy pierwszą akcję (przełączenie stanu lub wypełnienie obszaru)
+            self.handle_cell_click(board, x, y)
         } else {
             false
         }
     }
 
     /// Kontynuuje przeciąganie na danej komórce
-    /// Zwraca true jeśli stan komórki został zmieniony
+    /// 
+    /// Przy szybkim ruchu myszy kolejne komórki pod kursorem nie sąsiadują ze sobą,
+    /// więc akcja jest wykonywana na całej linii od poprzedniej komórki do podanej.
+    /// Zwraca true jeśli stan którejkolwiek komórki został zmieniony
     pub fn continue_drag(&mut self, board: &mut Board, x: usize, y: usize) -> bool {
         // Sprawdzamy czy przeciąganie jest aktywne
-        if !self.drag_state.is_dragging() {
+        // (wypełnianie działa tylko na komórce, od której zaczęto)
+        if !self.drag_state.is_dragging() || self.edit_mode == EditMode::FloodFill {
             return false;
         }
 
@@ -140,7 +383,8 @@ impl CellStateManager {
             return false;
         }
 
-        // Aktualizujemy ostatnią komórkę
+        // Aktualizujemy ostatnią komórkę (poprzednia wyznacza początek linii)
+        let line_start = self.drag_state.last_cell.unwrap_or((x, y));
         self.drag_state.update_last_cell((x, y));
 
         // Pobieramy typ akcji przeciągania
@@ -149,6 +393,22 @@ impl CellStateManager {
             None => return false,
         };
 
+        // Wykonujemy akcję zgodnie z logiką przeciągania (w całym pędzlu i odbitych komórkach)
+        let mut changed = false;
+        for (line_x, line_y) in line_cells(line_start, (x, y)) {
+            for (cell_x, cell_y) in self.brush_targets(board, line_x, line_y) {
+                if Self::apply_drag_action(board, drag_action, cell_x, cell_y) {
+                    self.changed_cells.push((cell_x, cell_y));
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Wykonuje akcję przeciągania na pojedynczej komórce
+    /// Zwraca true jeśli stan komórki został zmieniony
+    fn apply_drag_action(board: &mut Board, drag_action: DragAction, x: usize, y: usize) -> bool {
         // Pobieramy aktualny stan komórki
         let current_state = match board.get_cell(x, y) {
             Some(state) => state,
@@ -205,8 +465,148 @@ impl CellStateManager {
         }
     }
 
+    /// Wkleja skopiowany fragment planszy z lewym górnym rogiem w (x, y)
+    /// 
+    /// Fragment jest przesuwany tak, aby zmieścił się na planszy, a jego martwe
+    /// komórki nadpisują żywe. Zwraca true jeśli stan którejkolwiek komórki został zmieniony
+    pub fn paste_clipboard(&mut self, board: &mut Board, clipboard: &BoardClipboard, x: usize, y: usize) -> bool {
+        self.drag_state.end_drag();
+        
+        let (origin_x, origin_y) = clipboard.clamped_origin(board, x, y);
+        let mut changed = false;
+        for (dx, dy, state) in clipboard.iter_cells() {
+            let (cell_x, cell_y) = (origin_x + dx, origin_y + dy);
+            if board.get_cell(cell_x, cell_y).is_some_and(|current| current != state) {
+                changed |= board.set_cell(cell_x, cell_y, state);
+            }
+        }
+        
+        changed
+    }
+
+    /// Ustawia wszystkie komórki zaznaczenia jako martwe
+    /// Zwraca true jeśli stan którejkolwiek komórki został zmieniony
+    pub fn clear_selection(&mut self, board: &mut Board, selection: &Selection) -> bool {
+        self.drag_state.end_drag();
+        
+        let (min_x, min_y, max_x, max_y) = selection.bounds();
+        let mut changed = false;
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                if board.get_cell(x, y) == Some(CellState::Alive) {
+                    changed |= board.set_cell(x, y, CellState::Dead);
+                }
+            }
+        }
+        
+        changed
+    }
+
     /// Resetuje stan managera (przerywa przeciąganie)
     pub fn reset(&mut self) {
         self.drag_state.end_drag();
     }
-}
\ No newline at end of file
+}
+
+/// Zwraca komórki odcinka od `start` do `end` (włącznie) wyznaczone algorytmem Bresenhama
+/// 
+/// Kolejne komórki sąsiadują ze sobą bokiem lub narożnikiem, więc linia nie ma przerw.
+fn line_cells(start: (usize, usize), end: (usize, usize)) -> Vec<(usize, usize)> {
+    let (mut x, mut y) = (start.0 as i64, start.1 as i64);
+    let (end_x, end_y) = (end.0 as i64, end.1 as i64);
+    let dx = (end_x - x).abs();
+    let dy = -(end_y - y).abs();
+    let step_x = if x < end_x { 1 } else { -1 };
+    let step_y = if y < end_y { 1 } else { -1 };
+    let mut error = dx + dy;
+    
+    let mut cells = Vec::with_capacity(dx.max(-dy) as usize + 1);
+    loop {
+        cells.push((x as usize, y as usize));
+        if (x, y) == (end_x, end_y) {
+            break;
+        }
+        let doubled_error = 2 * error;
+        if doubled_error >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if doubled_error <= dx {
+            error += dx;
+            y += step_y;
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn vertical_symmetry_toggles_the_mirrored_cell() {
+        let mut board = Board::new(9, 9);
+        let mut manager = CellStateManager::new();
+        manager.set_symmetry_mode(SymmetryMode::Vertical);
+        
+        assert!(manager.handle_cell_click(&mut board, 2, 3));
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), vec![(2, 3), (6, 3)]);
+        
+        // Ponowne kliknięcie wyłącza obie komórki
+        assert!(manager.handle_cell_click(&mut board, 2, 3));
+        assert_eq!(board.count_alive_cells(), 0);
+    }
+    
+    #[test]
+    fn cells_on_the_symmetry_axis_are_not_repeated() {
+        assert_eq!(SymmetryMode::Vertical.mirrored_cells(4, 3, 9, 9), vec![(4, 3)]);
+        assert_eq!(SymmetryMode::Both.mirrored_cells(1, 2, 9, 9), vec![(1, 2), (7, 2), (1, 6), (7, 6)]);
+    }
+    
+    #[test]
+    fn drag_between_distant_cells_sets_the_cells_in_between() {
+        let mut board = Board::new(20, 20);
+        let mut manager = CellStateManager::new();
+        
+        manager.start_drag(&mut board, 2, 5);
+        assert!(manager.continue_drag(&mut board, 12, 5));
+        manager.end_drag();
+        
+        let expected: Vec<(usize, usize)> = (2..=12).map(|x| (x, 5)).collect();
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), expected);
+    }
+    
+    #[test]
+    fn diagonal_drag_leaves_no_gaps() {
+        let mut board = Board::new(20, 20);
+        let mut manager = CellStateManager::new();
+        
+        manager.start_drag(&mut board, 1, 1);
+        manager.continue_drag(&mut board, 9, 5);
+        manager.end_drag();
+        
+        // Każdy wiersz od 1 do 5 i każda kolumna od 1 do 9 zawiera narysowaną komórkę
+        let cells: Vec<(usize, usize)> = board.iter_alive_cells().collect();
+        assert!((1..=9).all(|x| cells.iter().any(|&(cell_x, _)| cell_x == x)));
+        assert!((1..=5).all(|y| cells.iter().any(|&(_, cell_y)| cell_y == y)));
+        assert!(cells.contains(&(9, 5)));
+    }
+    
+    #[test]
+    fn small_brush_paints_nine_cells_around_the_click() {
+        let mut board = Board::new(9, 9);
+        let mut manager = CellStateManager::new();
+        manager.set_brush_size(BrushSize::Small);
+        
+        assert!(manager.handle_cell_click(&mut board, 4, 4));
+        let expected: Vec<(usize, usize)> = (3..=5).flat_map(|y| (3..=5).map(move |x| (x, y))).collect();
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), expected);
+    }
+    
+    #[test]
+    fn brush_is_clipped_at_the_board_edge() {
+        assert_eq!(BrushSize::Large.cells(0, 8, 9, 9).len(), 3 * 3);
+        assert_eq!(BrushSize::Small.bounds(8, 0, 9, 9), (7, 0, 8, 1));
+        assert_eq!(BrushSize::Single.cells(2, 2, 9, 9), vec![(2, 2)]);
+    }
+}
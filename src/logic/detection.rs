@@ -0,0 +1,135 @@
+/// Moduł wykrywania ustalonych stanów symulacji
+/// 
+/// Rozpoznaje trzy sytuacje, w których symulacja przestaje się rozwijać:
+/// wymarcie (pusta plansza), martwą naturę (plansza się nie zmienia)
+/// oraz oscylację (plansza powtarza stan sprzed kilku generacji).
+
+use std::collections::VecDeque;
+use super::board::Board;
+use crate::config::rules::GameConfig;
+
+/// Wykryty stan ustalony symulacji
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettledState {
+    /// Wszystkie komórki wymarły w podanej generacji
+    Extinct { generation: u64 },
+    /// Plansza przestała się zmieniać w podanej generacji
+    StillLife { generation: u64 },
+    /// Plansza powtarza się co `period` generacji
+    Oscillator { period: usize, generation: u64 },
+}
+
+impl SettledState {
+    /// Zwraca opis stanu do wyświetlenia w interfejsie
+    pub fn description(&self) -> String {
+        match self {
+            SettledState::Extinct { generation } => format!("Extinct at gen {}", generation),
+            SettledState::StillLife { generation } => format!("Still life since gen {}", generation),
+            SettledState::Oscillator { period, .. } => format!("Oscillator period {}", period),
+        }
+    }
+}
+
+/// Detektor stanów ustalonych oparty na krótkiej historii plansz
+#[derive(Debug, Clone, Default)]
+pub struct StateDetector {
+    /// Ostatnie plansze - najnowsza na końcu
+    recent: VecDeque<Board>,
+    /// Pierwszy wykryty stan ustalony (zachowywany do wyczyszczenia detektora)
+    settled: Option<SettledState>,
+}
+
+impl StateDetector {
+    /// Tworzy nowy, pusty detektor
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Analizuje nową generację planszy i zwraca wykryty stan ustalony
+    /// 
+    /// Raz wykryty stan jest zwracany przy kolejnych wywołaniach,
+    /// dzięki czemu np. generacja wymarcia nie zmienia się w dalszych krokach.
+    /// Oscylatory są wykrywane do okresu `detection_window` z konfiguracji.
+    pub fn observe(&mut self, board: &Board, generation: u64, config: &GameConfig) -> Option<SettledState> {
+        if self.settled.is_some() {
+            return self.settled;
+        }
+        
+        if board.is_empty() {
+            self.settled = Some(SettledState::Extinct { generation });
+        } else if let Some(position) = self.recent.iter().rev().position(|previous| previous == board) {
+            // Odległość od najnowszego zapisanego stanu to okres powtarzania
+            let period = position + 1;
+            self.settled = Some(if period == 1 {
+                SettledState::StillLife { generation: generation - 1 }
+            } else {
+                SettledState::Oscillator { period, generation }
+            });
+        }
+        
+        let window = config.detection_window;
+        while self.recent.len() >= window.max(1) {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(board.clone());
+        
+        self.settled
+    }
+    
+    /// Czyści historię detektora (np. po edycji planszy)
+    pub fn clear(&mut self) {
+        self.recent.clear();
+        self.settled = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::board::CellState;
+    
+    /// Tworzy planszę z podanymi żywymi komórkami
+    fn board_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Board {
+        let mut board = Board::new(width, height);
+        for &(x, y) in cells {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    /// Przekazuje detektorowi `generations` kolejnych generacji (od zerowej) i zwraca ostatni wynik
+    fn observe_generations(board: Board, generations: u64, config: &GameConfig) -> Option<SettledState> {
+        let mut detector = StateDetector::new();
+        let mut board = board;
+        let mut settled = None;
+        for generation in 0..generations {
+            settled = detector.observe(&board, generation, config);
+            board = board.next_generation(config);
+        }
+        settled
+    }
+    
+    #[test]
+    fn blinker_is_detected_as_period_two_oscillator() {
+        let config = GameConfig::default();
+        let blinker = board_with(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+        assert_eq!(
+            observe_generations(blinker, 5, &config),
+            Some(SettledState::Oscillator { period: 2, generation: 2 })
+        );
+    }
+    
+    #[test]
+    fn block_is_detected_as_still_life() {
+        let config = GameConfig::default();
+        let block = board_with(4, 4, &[(1, 1), (2, 1), (1, 2), (2, 2)]);
+        assert_eq!(observe_generations(block, 3, &config), Some(SettledState::StillLife { generation: 0 }));
+    }
+    
+    #[test]
+    fn dying_pattern_is_detected_as_extinct() {
+        let config = GameConfig::default();
+        let pair = board_with(4, 4, &[(1, 1), (2, 1)]);
+        assert_eq!(observe_generations(pair, 3, &config), Some(SettledState::Extinct { generation: 1 }));
+    }
+}
@@ -0,0 +1,52 @@
+/// Moduł historii generacji
+/// 
+/// Przechowuje ograniczoną liczbę poprzednich stanów planszy,
+/// co pozwala cofać symulację krok po kroku.
+
+use std::collections::VecDeque;
+use super::board::Board;
+use crate::config::rules::GameConfig;
+
+/// Bufor cykliczny poprzednich generacji planszy
+#[derive(Debug, Clone, Default)]
+pub struct BoardHistory {
+    /// Zapisane plansze - najnowsza na końcu
+    boards: VecDeque<Board>,
+}
+
+impl BoardHistory {
+    /// Tworzy pustą historię
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Zapisuje stan planszy przed wykonaniem kroku
+    /// 
+    /// Gdy bufor osiągnie limit z konfiguracji, najstarszy stan jest usuwany.
+    pub fn push(&mut self, board: &Board, config: &GameConfig) {
+        let limit = config.history_limit;
+        if limit == 0 {
+            return;
+        }
+        
+        while self.boards.len() >= limit {
+            self.boards.pop_front();
+        }
+        self.boards.push_back(board.clone());
+    }
+    
+    /// Zdejmuje ostatnio zapisany stan planszy
+    pub fn pop(&mut self) -> Option<Board> {
+        self.boards.pop_back()
+    }
+    
+    /// Usuwa całą historię (np. po ręcznej edycji planszy)
+    pub fn clear(&mut self) {
+        self.boards.clear();
+    }
+    
+    /// Sprawdza czy historia jest pusta
+    pub fn is_empty(&self) -> bool {
+        self.boards.is_empty()
+    }
+}
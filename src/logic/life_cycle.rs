@@ -3,94 +3,307 @@
 /// Ten moduł zawiera logikę określającą czy komórka przeżyje, umrze, czy się narodzi
 /// w następnej generacji, bazując na konfiguracji zdefiniowanej w module config.
 
+use std::hash::{DefaultHasher, Hasher};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rayon::prelude::*;
 use super::board::{Board, CellState};
-use crate::config::get_config;
+use super::board::backend::{BoardBackend, SparseBoard};
+use crate::config::BoundaryMode;
+use crate::config::rules::GameConfig;
+
+/// Liczba komórek, powyżej której gęste obliczenia są rozdzielane między wątki
+/// 
+/// Dla mniejszych plansz koszt uruchomienia zadań rayon przewyższa zysk.
+pub const PARALLEL_CELL_THRESHOLD: usize = 4096;
+
+/// Tablice reguł przeżycia i narodzin dla każdej możliwej liczby sąsiadów
+/// 
+/// Pozwalają sprawdzić regułę bez przeszukiwania list z konfiguracji dla każdej komórki.
+struct RuleLookup {
+    birth: Vec<bool>,
+    survival: Vec<bool>,
+}
+
+impl RuleLookup {
+    fn new(config: &GameConfig) -> Self {
+        let max_neighbors = config.neighborhood.max_neighbors();
+        Self {
+            birth: (0..=max_neighbors).map(|n| config.should_birth(n)).collect(),
+            survival: (0..=max_neighbors).map(|n| config.should_survive(n)).collect(),
+        }
+    }
+    
+    /// Określa stan komórki w następnej generacji na podstawie liczby żywych sąsiadów
+    fn next_state(&self, current_state: CellState, alive_neighbors: u8) -> CellState {
+        let rule = match current_state {
+            CellState::Alive => &self.survival,
+            CellState::Dead => &self.birth,
+        };
+        if rule[alive_neighbors as usize] {
+            CellState::Alive
+        } else {
+            CellState::Dead
+        }
+    }
+}
 
 impl Board {
-    /// Oblicza następną generację planszy zgodnie z regułami gry
+    /// Oblicza następną generację planszy zgodnie z regułami z podanej konfiguracji
     /// 
-    /// Dla każdej komórki sprawdza liczbę żywych sąsiadów i na podstawie
-    /// konfiguracji określa jej stan w następnej generacji.
-    pub fn next_generation(&self) -> Board {
-        let config = get_config();
-        let mut next_board = Board::new(self.width(), self.height());
+    /// Dla rzadko zapełnionych plansz obliczenia wykonywane są tylko wokół
+    /// żywych komórek (patrz `BoardBackend`) - wynik jest w obu przypadkach identyczny.
+    /// Bufor liczby sąsiadów jest alokowany przy każdym wywołaniu - przy wielu
+    /// kolejnych krokach lepiej użyć `next_generation_with_buffer`.
+    pub fn next_generation(&self, config: &GameConfig) -> Board {
+        self.next_generation_with_buffer(config, &mut Vec::new())
+    }
+    
+    /// Oblicza następną generację, używając bufora liczby sąsiadów przekazanego przez wywołującego
+    /// 
+    /// Zawartość bufora jest nadpisywana, a jego pojemność zostaje zachowana,
+    /// więc kolejne kroki symulacji nie alokują go od nowa.
+    pub fn next_generation_with_buffer(&self, config: &GameConfig, neighbor_counts: &mut Vec<u8>) -> Board {
+        let mut next_board = match BoardBackend::select(self, config) {
+            BoardBackend::Dense => self.next_generation_dense(config, neighbor_counts),
+            BoardBackend::Sparse => SparseBoard::from_board(self).next_generation(config).to_board(),
+        };
         
-        // Iterujemy przez wszystkie komórki planszy
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                let current_state = self.get_cell(x, y).unwrap_or(CellState::Dead);
-                let alive_neighbors = self.count_alive_neighbors(x, y);
-                
-                // Określamy nowy stan komórki na podstawie reguł
-                let new_state = match current_state {
-                    CellState::Alive => {
-                        // Żywa komórka: sprawdzamy czy przeżyje
-                        if config.should_survive(alive_neighbors) {
-                            CellState::Alive
-                        } else {
-                            CellState::Dead
-                        }
-                    },
-                    CellState::Dead => {
-                        // Martwa komórka: sprawdzamy czy się narodzi
-                        if config.should_birth(alive_neighbors) {
-                            CellState::Alive
-                        } else {
-                            CellState::Dead
-                        }
-                    }
-                };
-                
-                next_board.set_cell(x, y, new_state);
+        if config.is_stochastic() {
+            self.apply_stochastic_rules(&mut next_board, config);
+        }
+        
+        // Komórki, które przeżyły, są o generację starsze
+        next_board.advance_ages_from(self);
+        next_board
+    }
+    
+    /// Losowo cofa narodziny i przeżycia wyznaczone przez reguły deterministyczne
+    /// 
+    /// Komórka, która według reguł się rodzi, pozostaje żywa z prawdopodobieństwem
+    /// `birth_probability`, a komórka, która przeżywa - z prawdopodobieństwem
+    /// `survival_probability`. Przy ustawionym ziarnie generator zależy od ziarna
+    /// i stanu planszy, więc ten sam przebieg da się powtórzyć.
+    fn apply_stochastic_rules(&self, next_board: &mut Board, config: &GameConfig) {
+        let mut rng = match config.stochastic_seed {
+            Some(seed) => {
+                let mut hasher = DefaultHasher::new();
+                hasher.write_u64(seed);
+                for &state in self.cells() {
+                    hasher.write_u8(state as u8);
+                }
+                StdRng::seed_from_u64(hasher.finish())
+            }
+            None => StdRng::from_entropy(),
+        };
+        
+        for (cell, &current_state) in next_board.cells_mut().iter_mut().zip(self.cells()) {
+            if *cell == CellState::Dead {
+                continue;
+            }
+            
+            let probability = match current_state {
+                CellState::Alive => config.survival_probability,
+                CellState::Dead => config.birth_probability,
+            };
+            if rng.r#gen::<f32>() >= probability {
+                *cell = CellState::Dead;
             }
         }
+    }
+    
+    /// Oblicza następną generację, sprawdzając każdą komórkę planszy
+    /// 
+    /// Duże plansze są liczone równolegle, przy mniejszych wystarcza jeden wątek.
+    fn next_generation_dense(&self, config: &GameConfig, neighbor_counts: &mut Vec<u8>) -> Board {
+        if self.total_cells() > PARALLEL_CELL_THRESHOLD {
+            self.next_generation_parallel(config, neighbor_counts)
+        } else {
+            self.next_generation_sequential(config, neighbor_counts)
+        }
+    }
+    
+    /// Oblicza następną generację w jednym wątku
+    pub fn next_generation_sequential(&self, config: &GameConfig, neighbor_counts: &mut Vec<u8>) -> Board {
+        self.count_all_neighbors(config, neighbor_counts);
+        let rules = RuleLookup::new(config);
+        let mut next_board = Board::new(self.width(), self.height());
+        
+        for ((cell, &state), &alive_neighbors) in next_board.cells_mut().iter_mut().zip(self.cells()).zip(neighbor_counts.iter()) {
+            *cell = rules.next_state(state, alive_neighbors);
+        }
         
         next_board
     }
     
+    /// Oblicza następną generację, rozdzielając wiersze planszy między wątki
+    /// 
+    /// Liczby sąsiadów są zbierane w jednym wątku, a następnie każdy wątek
+    /// zapisuje swoje wiersze do osobnego fragmentu nowej planszy - wynik jest
+    /// identyczny jak w `next_generation_sequential`.
+    pub fn next_generation_parallel(&self, config: &GameConfig, neighbor_counts: &mut Vec<u8>) -> Board {
+        let width = self.width();
+        let mut next_board = Board::new(width, self.height());
+        if width == 0 {
+            return next_board;
+        }
+        
+        self.count_all_neighbors(config, neighbor_counts);
+        let rules = RuleLookup::new(config);
+        next_board
+            .cells_mut()
+            .par_chunks_mut(width)
+            .zip(self.cells().par_chunks(width))
+            .zip(neighbor_counts.par_chunks(width))
+            .for_each(|((row, current_row), count_row)| {
+                for ((cell, &state), &alive_neighbors) in row.iter_mut().zip(current_row).zip(count_row) {
+                    *cell = rules.next_state(state, alive_neighbors);
+                }
+            });
+        
+        next_board
+    }
+    
+    /// Liczy żywych sąsiadów wszystkich komórek naraz do bufora `counts`
+    /// 
+    /// Zamiast sprawdzać sąsiedztwo każdej komórki, każda żywa komórka dodaje 1
+    /// komórkom, dla których jest sąsiadem - koszt zależy od liczby żywych komórek,
+    /// a wynik jest taki sam jak z `count_alive_neighbors` dla każdej komórki.
+    /// 
+    /// Komórki wewnętrzne (całe sąsiedztwo na planszy) dodają od razu po
+    /// przesunięciach indeksów, bez sprawdzania granic - tylko komórki przy
+    /// krawędziach przechodzą przez `count_edge_cell_neighbors`.
+    fn count_all_neighbors(&self, config: &GameConfig, counts: &mut Vec<u8>) {
+        counts.clear();
+        counts.resize(self.total_cells(), 0);
+        let (width, height) = (self.width(), self.height());
+        let radius = config.neighborhood.radius();
+        
+        // Komórka o indeksie (index - dy * width - dx) ma komórkę index jako sąsiada o przesunięciu (dx, dy)
+        let index_offsets: Vec<isize> = config.neighborhood.offsets()
+            .iter()
+            .map(|&(dx, dy)| -(dy as isize * width as isize + dx as isize))
+            .collect();
+        
+        for (index, _) in self.cells().iter().enumerate().filter(|(_, state)| **state == CellState::Alive) {
+            let x = index % width;
+            let y = index / width;
+            
+            let is_interior = x >= radius && x + radius < width && y >= radius && y + radius < height;
+            if is_interior {
+                for &offset in &index_offsets {
+                    counts[index.wrapping_add_signed(offset)] += 1;
+                }
+            } else {
+                self.count_edge_cell_neighbors(counts, x, y, config);
+            }
+        }
+    }
+    
+    /// Dodaje żywą komórkę (x, y) leżącą przy krawędzi do liczników jej sąsiadów
+    /// 
+    /// W trybie Toroidal sąsiedzi zza krawędzi leżą po drugiej stronie planszy,
+    /// w pozostałych trybach są pomijani.
+    fn count_edge_cell_neighbors(&self, counts: &mut [u8], x: usize, y: usize, config: &GameConfig) {
+        let (width, height) = (self.width() as i32, self.height() as i32);
+        
+        for &(dx, dy) in config.neighborhood.offsets() {
+            let mut neighbor_x = x as i32 - dx;
+            let mut neighbor_y = y as i32 - dy;
+            
+            if config.boundary_mode == BoundaryMode::Toroidal {
+                neighbor_x = neighbor_x.rem_euclid(width);
+                neighbor_y = neighbor_y.rem_euclid(height);
+            } else if !(0..width).contains(&neighbor_x) || !(0..height).contains(&neighbor_y) {
+                continue;
+            }
+            
+            counts[(neighbor_y * width + neighbor_x) as usize] += 1;
+        }
+    }
+    
     /// Liczy liczbę żywych sąsiadów dla danej komórki
     /// 
-    /// Sprawdza wszystkie 8 sąsiadujących komórek (w tym po przekątnej).
-    /// Komórki poza granicami planszy są traktowane jako martwe.
-    pub fn count_alive_neighbors(&self, x: usize, y: usize) -> usize {
+    /// Sprawdza wszystkie komórki sąsiedztwa z konfiguracji (np. 8 komórek wokół dla Moore'a).
+    /// W trybie Bounded komórki poza granicami planszy są traktowane jako martwe,
+    /// a w trybie Toroidal współrzędne sąsiadów zawijają się na przeciwną krawędź.
+    pub fn count_alive_neighbors(&self, x: usize, y: usize, config: &GameConfig) -> usize {
         let mut count = 0;
         
-        // Sprawdzamy wszystkie 8 kierunków wokół komórki
-        for dy in -1..=1i32 {
-            for dx in -1..=1i32 {
-                // Pomijamy samą komórkę (środek)
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-                
-                // Obliczamy współrzędne sąsiada
-                let neighbor_x = x as i32 + dx;
-                let neighbor_y = y as i32 + dy;
+        // Sprawdzamy wszystkie kierunki sąsiedztwa wokół komórki
+        for &(dx, dy) in config.neighborhood.offsets() {
+            // Obliczamy współrzędne sąsiada
+            let mut neighbor_x = x as i32 + dx;
+            let mut neighbor_y = y as i32 + dy;
+            
+            // Na planszy toroidalnej sąsiad zza krawędzi leży po drugiej stronie
+            if config.boundary_mode == BoundaryMode::Toroidal {
+                neighbor_x = neighbor_x.rem_euclid(self.width() as i32);
+                neighbor_y = neighbor_y.rem_euclid(self.height() as i32);
+            }
+            
+            // Sprawdzamy czy sąsiad jest w granicach planszy
+            if neighbor_x >= 0 && neighbor_y >= 0 {
+                let neighbor_x = neighbor_x as usize;
+                let neighbor_y = neighbor_y as usize;
                 
-                // Sprawdzamy czy sąsiad jest w granicach planszy
-                if neighbor_x >= 0 && neighbor_y >= 0 {
-                    let neighbor_x = neighbor_x as usize;
-                    let neighbor_y = neighbor_y as usize;
-                    
-                    if neighbor_x < self.width() && neighbor_y < self.height() {
-                        // Sąsiad jest w granicach planszy
-                        if let Some(CellState::Alive) = self.get_cell(neighbor_x, neighbor_y) {
-                            count += 1;
-                        }
+                if neighbor_x < self.width() && neighbor_y < self.height() {
+                    // Sąsiad jest w granicach planszy
+                    if let Some(CellState::Alive) = self.get_cell(neighbor_x, neighbor_y) {
+                        count += 1;
                     }
-                    // Jeśli sąsiad jest poza granicami planszy, traktujemy go jako martwego
-                    // (nie zwiększamy count)
                 }
-                // Jeśli współrzędne są ujemne, sąsiad jest poza planszą (martwy)
+                // Jeśli sąsiad jest poza granicami planszy, traktujemy go jako martwego
+                // (nie zwiększamy count)
             }
+            // Jeśli współrzędne są ujemne, sąsiad jest poza planszą (martwy)
         }
         
         count
     }
     
+    /// Liczy komórki, które w następnej generacji narodziłyby się tuż za krawędzią planszy
+    /// 
+    /// Na planszy z krawędziami takie komórki nie powstają, więc wzory docierające
+    /// do krawędzi (np. szybowce) tracą część komórek. Sprawdzane są tylko pozycje
+    /// poza planszą, które mają co najmniej jednego żywego sąsiada na planszy.
+    /// Plansza toroidalna nie ma krawędzi - zwraca wtedy 0.
+    pub fn count_clipped_births(&self, config: &GameConfig) -> usize {
+        if config.boundary_mode == BoundaryMode::Toroidal {
+            return 0;
+        }
+        
+        let radius = config.neighborhood.radius() as i32;
+        let (width, height) = (self.width() as i32, self.height() as i32);
+        // Wiersze przecinające planszę mają pozycje poza nią tylko po bokach
+        let side_columns: Vec<i32> = (-radius..0).chain(width..width + radius).collect();
+        let all_columns: Vec<i32> = (-radius..width + radius).collect();
+        
+        let mut clipped = 0;
+        for y in -radius..height + radius {
+            let columns = if (0..height).contains(&y) { &side_columns } else { &all_columns };
+            for &x in columns {
+                let alive_neighbors = config.neighborhood.offsets()
+                    .iter()
+                    .map(|&(dx, dy)| (x + dx, y + dy))
+                    .filter(|&(neighbor_x, neighbor_y)| {
+                        (0..width).contains(&neighbor_x)
+                            && (0..height).contains(&neighbor_y)
+                            && self.get_cell(neighbor_x as usize, neighbor_y as usize) == Some(CellState::Alive)
+                    })
+                    .count();
+                if alive_neighbors > 0 && config.should_birth(alive_neighbors) {
+                    clipped += 1;
+                }
+            }
+        }
+        
+        clipped
+    }
+    
     /// Sprawdza czy plansza jest stabilna (nie zmieni się w następnej generacji)
-    pub fn is_stable(&self) -> bool {
-        let next = self.next_generation();
+    pub fn is_stable(&self, config: &GameConfig) -> bool {
+        let next = self.next_generation(config);
         
         // Porównujemy każdą komórkę
         for y in 0..self.height() {
@@ -114,3 +327,189 @@ impl Board {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NeighborhoodType;
+    
+    /// Tworzy planszę z losowymi żywymi komórkami o podanej gęstości
+    fn random_board(width: usize, height: usize, density: f64, seed: u64) -> Board {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut board = Board::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                if rng.gen_bool(density) {
+                    board.set_cell(x, y, CellState::Alive);
+                }
+            }
+        }
+        board
+    }
+    
+    /// Tworzy konfigurację reguł B13/S23 z podanym sąsiedztwem i krawędziami
+    fn config_with(neighborhood: NeighborhoodType, boundary_mode: BoundaryMode) -> GameConfig {
+        let mut config = GameConfig::default();
+        config.set_neighborhood(neighborhood);
+        config.set_boundary_mode(boundary_mode);
+        config.set_rule_from_string("B13/S23").unwrap();
+        config
+    }
+    
+    /// Wszystkie połączenia sąsiedztwa i trybu krawędzi sprawdzane w testach
+    fn all_configs() -> Vec<GameConfig> {
+        let mut configs = Vec::new();
+        for neighborhood in [NeighborhoodType::Moore, NeighborhoodType::VonNeumann, NeighborhoodType::MooreRange2] {
+            for boundary_mode in [BoundaryMode::Bounded, BoundaryMode::Toroidal] {
+                configs.push(config_with(neighborhood, boundary_mode));
+            }
+        }
+        configs
+    }
+    
+    /// Następna generacja liczona wprost z `count_alive_neighbors` dla każdej komórki
+    fn reference_next_generation(board: &Board, config: &GameConfig) -> Board {
+        let mut next = Board::new(board.width(), board.height());
+        for y in 0..board.height() {
+            for x in 0..board.width() {
+                let neighbors = board.count_alive_neighbors(x, y, config);
+                let alive = match board.get_cell(x, y) {
+                    Some(CellState::Alive) => config.should_survive(neighbors),
+                    _ => config.should_birth(neighbors),
+                };
+                if alive {
+                    next.set_cell(x, y, CellState::Alive);
+                }
+            }
+        }
+        next
+    }
+    
+    #[test]
+    fn every_backend_matches_per_cell_reference() {
+        // Jeden bufor dla wszystkich plansz - jego poprzednia zawartość nie może wpływać na wynik
+        let mut neighbor_counts = Vec::new();
+        for config in all_configs() {
+            for (width, height, seed) in [(23, 17, 1), (70, 70, 2), (5, 9, 3)] {
+                let board = random_board(width, height, 0.3, seed);
+                let expected = reference_next_generation(&board, &config);
+                let context = format!("{:?} {:?} {}x{}", config.neighborhood, config.boundary_mode, width, height);
+                
+                assert_eq!(board.next_generation_sequential(&config, &mut neighbor_counts), expected, "sequential, {}", context);
+                assert_eq!(board.next_generation_parallel(&config, &mut neighbor_counts), expected, "parallel, {}", context);
+                let sparse = SparseBoard::from_board(&board).next_generation(&config).to_board();
+                assert_eq!(sparse, expected, "sparse, {}", context);
+                assert_eq!(board.next_generation_with_buffer(&config, &mut neighbor_counts), expected, "selected, {}", context);
+            }
+        }
+    }
+    
+    #[test]
+    fn parallel_matches_sequential_above_threshold() {
+        for config in all_configs() {
+            for density in [0.05, 0.35, 0.7] {
+                let board = random_board(101, 83, density, 7);
+                assert!(board.total_cells() > PARALLEL_CELL_THRESHOLD);
+                
+                let sequential = board.next_generation_sequential(&config, &mut Vec::new());
+                let parallel = board.next_generation_parallel(&config, &mut Vec::new());
+                assert_eq!(parallel, sequential, "{:?} {:?}, density {}", config.neighborhood, config.boundary_mode, density);
+            }
+        }
+    }
+    
+    #[test]
+    fn interior_and_edge_counting_match_per_cell_count() {
+        // Plansze, na których wszystkie komórki leżą przy krawędzi (np. 3x3 przy promieniu 2),
+        // oraz plansze z wnętrzem, w tym o wymiarach dokładnie na granicy wnętrza
+        let sizes = [(1, 1), (3, 3), (4, 7), (5, 5), (6, 5), (19, 11)];
+        let mut counts = Vec::new();
+        for config in all_configs() {
+            for (seed, &(width, height)) in sizes.iter().enumerate() {
+                // Pełna plansza sprawdza, czy żaden sąsiad nie jest liczony podwójnie
+                let boards = [random_board(width, height, 0.4, seed as u64), random_board(width, height, 1.0, 0)];
+                for board in boards {
+                    board.count_all_neighbors(&config, &mut counts);
+                    for y in 0..height {
+                        for x in 0..width {
+                            assert_eq!(
+                                counts[y * width + x] as usize,
+                                board.count_alive_neighbors(x, y, &config),
+                                "({}, {}) on {}x{}, {:?} {:?}",
+                                x, y, width, height, config.neighborhood, config.boundary_mode
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn glider_crossing_right_edge_reappears_on_left_when_toroidal() {
+        let mut config = GameConfig::default();
+        config.set_boundary_mode(BoundaryMode::Toroidal);
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let (size, start) = (8, (5, 2));
+        
+        let mut board = Board::new(size, size);
+        for (x, y) in glider {
+            board.set_cell(start.0 + x, start.1 + y, CellState::Alive);
+        }
+        
+        // Co 4 generacje szybowiec przesuwa się o komórkę w prawo i w dół
+        for _ in 0..8 {
+            board = board.next_generation(&config);
+        }
+        
+        let mut expected: Vec<(usize, usize)> = glider
+            .iter()
+            .map(|&(x, y)| ((start.0 + x + 2) % size, (start.1 + y + 2) % size))
+            .collect();
+        expected.sort_unstable_by_key(|&(x, y)| (y, x));
+        assert_eq!(board.iter_alive_cells().collect::<Vec<_>>(), expected);
+        assert!(board.iter_alive_cells().any(|(x, _)| x < 2), "szybowiec powinien pojawić się przy lewej krawędzi");
+    }
+    
+    /// Sąsiedztwa z liczbą sąsiadów środka pełnej planszy 5x5 i regułą rodzącą tylko przy tej liczbie
+    const KNOWN_NEIGHBORHOODS: [(NeighborhoodType, usize, &str); 3] = [
+        (NeighborhoodType::Moore, 8, "B8/S"),
+        (NeighborhoodType::VonNeumann, 4, "B4/S"),
+        (NeighborhoodType::MooreRange2, 24, "B24,/S"),
+    ];
+    
+    #[test]
+    fn centre_of_full_board_sees_every_neighbor() {
+        let board = random_board(5, 5, 1.0, 0);
+        let mut counts = Vec::new();
+        for (neighborhood, expected, _) in KNOWN_NEIGHBORHOODS {
+            for boundary_mode in [BoundaryMode::Bounded, BoundaryMode::Toroidal] {
+                let config = config_with(neighborhood, boundary_mode);
+                assert_eq!(board.count_alive_neighbors(2, 2, &config), expected, "{:?} {:?}", neighborhood, boundary_mode);
+                
+                board.count_all_neighbors(&config, &mut counts);
+                assert_eq!(counts[2 * 5 + 2] as usize, expected, "{:?} {:?}", neighborhood, boundary_mode);
+            }
+        }
+    }
+    
+    #[test]
+    fn cell_surrounded_by_full_neighborhood_is_born() {
+        // Pełna plansza 5x5 z martwym środkiem - przy pustym S wszystkie żywe komórki umierają
+        let mut board = random_board(5, 5, 1.0, 0);
+        board.set_cell(2, 2, CellState::Dead);
+        
+        for (neighborhood, count, rule) in KNOWN_NEIGHBORHOODS {
+            let mut config = GameConfig::default();
+            config.set_neighborhood(neighborhood);
+            config.set_rule_from_string(rule).unwrap();
+            
+            let next = board.next_generation(&config);
+            assert_eq!(next.iter_alive_cells().collect::<Vec<_>>(), vec![(2, 2)], "{:?}", neighborhood);
+            
+            // O jednego sąsiada za mało - środek się nie rodzi
+            config.set_rule_from_string(&format!("B{},/S", count - 1)).unwrap();
+            assert!(board.next_generation(&config).is_empty(), "{:?}", neighborhood);
+        }
+    }
+}
@@ -4,6 +4,13 @@
 /// - board: zarządzanie planszą i stanem komórek
 /// - life_cycle: implementacja reguł gry Conway'a
 /// - change_state: zarządzanie zmianą stanu komórek (klikanie i przeciąganie)
+/// - tabs: niezależne zakładki plansz
+/// - history: historia generacji do cofania symulacji
+/// - detection: wykrywanie wymarcia, martwej natury i oscylatorów
+/// - selection: zaznaczanie, kopiowanie i wklejanie fragmentów planszy
+/// - recording: nagrywanie i odtwarzanie przebiegu symulacji
+/// - raster: rysowanie planszy do obrazu RGBA bez kontekstu graficznego
+/// - statistics: statystyki kolejnych generacji i ich eksport do CSV
 
 pub mod board;
 pub mod life_cycle;
@@ -11,6 +18,13 @@ pub mod change_state;
 pub mod prediction;
 pub mod reset;
 pub mod randomizer;
+pub mod tabs;
+pub mod history;
+pub mod detection;
+pub mod selection;
+pub mod recording;
+pub mod raster;
+pub mod statistics;
 
 // Re-eksportujemy najważniejsze typy z modułu board (gdy będą potrzebne)
 // pub use board::{Board, CellState};
\ No newline at end of file
@@ -3,11 +3,13 @@
 /// Zawiera funkcje do obliczania, które komórki będą żywe w następnej generacji
 /// oraz identyfikuje komórki, które się narodzą (przejdą z martwych na żywe).
 
+use std::collections::HashSet;
 use super::board::{Board, CellState};
-use crate::config::get_config;
+use crate::config::BoundaryMode;
+use crate::config::rules::GameConfig;
 
 /// Struktura przechowująca informacje o przewidywanym następnym stanie
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct PredictionResult {
     /// Współrzędne komórek, które będą żywe w następnej generacji
     pub next_alive_cells: Vec<(usize, usize)>,
@@ -15,16 +17,14 @@ pub struct PredictionResult {
     pub birth_cells: Vec<(usize, usize)>,
     /// Współrzędne komórek, które umrą (obecnie żywe, w następnej generacji martwe)
     pub death_cells: Vec<(usize, usize)>,
+    /// Współrzędne komórek, które przeżyją (żywe teraz i w następnej generacji)
+    pub survival_cells: Vec<(usize, usize)>,
 }
 
 impl PredictionResult {
     /// Tworzy nowy pusty wynik przewidywania
     pub fn new() -> Self {
-        Self {
-            next_alive_cells: Vec::new(),
-            birth_cells: Vec::new(),
-            death_cells: Vec::new(),
-        }
+        Self::default()
     }
     
     /// Sprawdza czy komórka o podanych współrzędnych się narodzi
@@ -37,71 +37,129 @@ impl PredictionResult {
         self.death_cells.contains(&(x, y))
     }
     
+    /// Sprawdza czy komórka o podanych współrzędnych przeżyje (żywa teraz i w następnej generacji)
+    pub fn will_survive(&self, x: usize, y: usize) -> bool {
+        self.survival_cells.contains(&(x, y))
+    }
+    
     /// Sprawdza czy komórka o podanych współrzędnych będzie żywa w następnej generacji
     pub fn will_be_alive(&self, x: usize, y: usize) -> bool {
         self.next_alive_cells.contains(&(x, y))
     }
+    
+    /// Oblicza przyszły stan komórki (x, y) i dopisuje ją do odpowiednich list
+    fn record_cell(&mut self, board: &Board, x: usize, y: usize, config: &GameConfig) {
+        let current_state = board.get_cell(x, y).unwrap_or(CellState::Dead);
+        let alive_neighbors = board.count_alive_neighbors(x, y, config);
+        
+        // Określamy nowy stan komórki na podstawie reguł
+        let will_be_alive = match current_state {
+            CellState::Alive => {
+                // Żywa komórka: sprawdzamy czy przeżyje
+                config.should_survive(alive_neighbors)
+            },
+            CellState::Dead => {
+                // Martwa komórka: sprawdzamy czy się narodzi
+                config.should_birth(alive_neighbors)
+            }
+        };
+        
+        // Zapisujemy wyniki
+        if will_be_alive {
+            self.next_alive_cells.push((x, y));
+            
+            // Jeśli komórka obecnie jest martwa, ale będzie żywa - to się narodzi,
+            // a jeśli jest żywa - przeżyje
+            match current_state {
+                CellState::Dead => self.birth_cells.push((x, y)),
+                CellState::Alive => self.survival_cells.push((x, y)),
+            }
+        } else {
+            // Jeśli komórka obecnie jest żywa, ale będzie martwa - to umrze
+            if current_state == CellState::Alive {
+                self.death_cells.push((x, y));
+            }
+        }
+    }
 }
 
 /// Przewiduje następny stan planszy i zwraca informacje o zmianach
-pub fn predict_next_state(board: &Board) -> PredictionResult {
-    let config = get_config();
+pub fn predict_next_state(board: &Board, config: &GameConfig) -> PredictionResult {
     let mut result = PredictionResult::new();
     
     // Iterujemy przez wszystkie komórki planszy
     for y in 0..board.height() {
         for x in 0..board.width() {
-            let current_state = board.get_cell(x, y).unwrap_or(CellState::Dead);
-            let alive_neighbors = board.count_alive_neighbors(x, y);
-            
-            // Określamy nowy stan komórki na podstawie reguł
-            let will_be_alive = match current_state {
-                CellState::Alive => {
-                    // Żywa komórka: sprawdzamy czy przeżyje
-                    config.should_survive(alive_neighbors)
-                },
-                CellState::Dead => {
-                    // Martwa komórka: sprawdzamy czy się narodzi
-                    config.should_birth(alive_neighbors)
-                }
-            };
-            
-            // Zapisujemy wyniki
-            if will_be_alive {
-                result.next_alive_cells.push((x, y));
+            result.record_cell(board, x, y, config);
+        }
+    }
+    
+    result
+}
+
+impl Board {
+    /// Aktualizuje wcześniejsze przewidywanie po zmianie podanych komórek
+    /// 
+    /// Przyszły stan może się zmienić tylko w zmienionych komórkach i w komórkach,
+    /// które mają je w sąsiedztwie, więc tylko one są liczone ponownie. Przewidywanie
+    /// `prev` musi dotyczyć tej samej planszy (tego samego rozmiaru) przed zmianą.
+    pub fn recompute_prediction_around(&self, changed: &[(usize, usize)], config: &GameConfig, prev: &mut PredictionResult) {
+        let (width, height) = (self.width() as i32, self.height() as i32);
+        
+        // Komórka (x, y) jest sąsiadem komórki (x - dx, y - dy)
+        let mut affected = HashSet::new();
+        for &(x, y) in changed {
+            affected.insert((x, y));
+            for &(dx, dy) in config.neighborhood.offsets() {
+                let mut cell_x = x as i32 - dx;
+                let mut cell_y = y as i32 - dy;
                 
-                // Jeśli komórka obecnie jest martwa, ale będzie żywa - to się narodzi
-                if current_state == CellState::Dead {
-                    result.birth_cells.push((x, y));
+                if config.boundary_mode == BoundaryMode::Toroidal {
+                    cell_x = cell_x.rem_euclid(width);
+                    cell_y = cell_y.rem_euclid(height);
                 }
-            } else {
-                // Jeśli komórka obecnie jest żywa, ale będzie martwa - to umrze
-                if current_state == CellState::Alive {
-                    result.death_cells.push((x, y));
+                
+                if (0..width).contains(&cell_x) && (0..height).contains(&cell_y) {
+                    affected.insert((cell_x as usize, cell_y as usize));
                 }
             }
         }
+        
+        // Usuwamy stare wyniki dla komórek, które liczymy ponownie
+        prev.next_alive_cells.retain(|cell| !affected.contains(cell));
+        prev.birth_cells.retain(|cell| !affected.contains(cell));
+        prev.death_cells.retain(|cell| !affected.contains(cell));
+        prev.survival_cells.retain(|cell| !affected.contains(cell));
+        
+        for (x, y) in affected {
+            prev.record_cell(self, x, y, config);
+        }
     }
-    
-    result
 }
 
 /// Przewiduje tylko komórki, które się narodzą w następnej generacji
 /// (obecnie martwe, w następnej generacji żywe)
-pub fn predict_birth_cells(board: &Board) -> Vec<(usize, usize)> {
-    let prediction = predict_next_state(board);
+pub fn predict_birth_cells(board: &Board, config: &GameConfig) -> Vec<(usize, usize)> {
+    let prediction = predict_next_state(board, config);
     prediction.birth_cells
 }
 
 /// Przewiduje tylko komórki, które umrą w następnej generacji
 /// (obecnie żywe, w następnej generacji martwe)
-pub fn predict_death_cells(board: &Board) -> Vec<(usize, usize)> {
-    let prediction = predict_next_state(board);
+pub fn predict_death_cells(board: &Board, config: &GameConfig) -> Vec<(usize, usize)> {
+    let prediction = predict_next_state(board, config);
     prediction.death_cells
 }
 
+/// Przewiduje tylko komórki, które przeżyją do następnej generacji
+/// (obecnie żywe, w następnej generacji również żywe)
+pub fn predict_survival_cells(board: &Board, config: &GameConfig) -> Vec<(usize, usize)> {
+    let prediction = predict_next_state(board, config);
+    prediction.survival_cells
+}
+
 /// Sprawdza czy dana komórka się narodzi w następnej generacji
-pub fn will_cell_be_born(board: &Board, x: usize, y: usize) -> bool {
+pub fn will_cell_be_born(board: &Board, x: usize, y: usize, config: &GameConfig) -> bool {
     let current_state = board.get_cell(x, y).unwrap_or(CellState::Dead);
     
     // Komórka może się narodzić tylko jeśli obecnie jest martwa
@@ -109,14 +167,13 @@ pub fn will_cell_be_born(board: &Board, x: usize, y: usize) -> bool {
         return false;
     }
     
-    let config = get_config();
-    let alive_neighbors = board.count_alive_neighbors(x, y);
+    let alive_neighbors = board.count_alive_neighbors(x, y, config);
     
     config.should_birth(alive_neighbors)
 }
 
 /// Sprawdza czy dana komórka umrze w następnej generacji
-pub fn will_cell_die(board: &Board, x: usize, y: usize) -> bool {
+pub fn will_cell_die(board: &Board, x: usize, y: usize, config: &GameConfig) -> bool {
     let current_state = board.get_cell(x, y).unwrap_or(CellState::Dead);
     
     // Komórka może umrzeć tylko jeśli obecnie jest żywa
@@ -124,8 +181,58 @@ pub fn will_cell_die(board: &Board, x: usize, y: usize) -> bool {
         return false;
     }
     
-    let config = get_config();
-    let alive_neighbors = board.count_alive_neighbors(x, y);
+    let alive_neighbors = board.count_alive_neighbors(x, y, config);
     
     !config.should_survive(alive_neighbors)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Tworzy planszę z podanymi żywymi komórkami
+    fn board_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Board {
+        let mut board = Board::new(width, height);
+        for &(x, y) in cells {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    fn sorted(mut cells: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        cells.sort_unstable();
+        cells
+    }
+    
+    #[test]
+    fn every_cell_of_a_block_survives() {
+        let block_cells = [(2, 2), (3, 2), (2, 3), (3, 3)];
+        let board = board_with(6, 6, &block_cells);
+        let config = GameConfig::default();
+        
+        let prediction = predict_next_state(&board, &config);
+        assert_eq!(sorted(prediction.survival_cells.clone()), sorted(block_cells.to_vec()));
+        assert!(prediction.birth_cells.is_empty());
+        assert!(prediction.death_cells.is_empty());
+        assert!(block_cells.iter().all(|&(x, y)| prediction.will_survive(x, y)));
+        
+        assert_eq!(sorted(predict_survival_cells(&board, &config)), sorted(block_cells.to_vec()));
+    }
+    
+    #[test]
+    fn only_the_centre_of_a_blinker_survives() {
+        // Poziomy blinker w środku planszy 5x5
+        let board = board_with(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+        let config = GameConfig::default();
+        
+        let prediction = predict_next_state(&board, &config);
+        assert_eq!(prediction.survival_cells, vec![(2, 2)]);
+        assert!(prediction.will_survive(2, 2));
+        assert!(!prediction.will_survive(1, 2));
+        assert!(!prediction.will_survive(3, 2));
+        
+        assert_eq!(sorted(prediction.death_cells), vec![(1, 2), (3, 2)]);
+        assert_eq!(sorted(prediction.birth_cells), vec![(2, 1), (2, 3)]);
+        assert_eq!(predict_survival_cells(&board, &config), vec![(2, 2)]);
+    }
+}
@@ -3,30 +3,42 @@
 /// Zawiera funkcje do tworzenia losowej planszy z uwzględnieniem
 /// prawdopodobieństwa bazowego i bonusów za sąsiadów.
 
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use super::board::{Board, CellState};
-use crate::config::{get_config, RandomizerConfig};
+use crate::config::RandomizerConfig;
+use crate::config::rules::GameConfig;
 
 /// Generuje losową planszę na podstawie aktualnego rozmiaru i konfiguracji randomizera
 /// 
 /// Algorytm działa w następujący sposób:
 /// 1. Tworzy pustą planszę o rozmiarze aktualnej planszy
-/// 2. Dla każdej komórki oblicza prawdopodobieństwo życia:
+///    (w trybie addytywnym kopię aktualnej planszy)
+/// 2. Dla każdej martwej komórki w wybranym kształcie oblicza prawdopodobieństwo życia:
 ///    - Bazowe prawdopodobieństwo z konfiguracji
-///    - Plus bonus za każdego żywego sąsiada (już wygenerowanego)
+///    - Plus bonus za każdego żywego sąsiada (już wygenerowanego lub istniejącego)
 /// 3. Losuje czy komórka będzie żywa na podstawie obliczonego prawdopodobieństwa
-pub fn generate_random_board(current_board: &Board) -> Board {
-    let config = get_config();
+pub fn generate_random_board(current_board: &Board, config: &GameConfig) -> Board {
     let randomizer_config = &config.randomizer_config;
     
     let width = current_board.width();
     let height = current_board.height();
-    let mut new_board = Board::new(width, height);
-    let mut rng = rand::thread_rng();
+    let mut new_board = starting_board(current_board, randomizer_config);
+    let mut rng = create_rng(randomizer_config);
     
     // Iterujemy przez każdą komórkę planszy
     for y in 0..height {
         for x in 0..width {
+            // Komórki poza wybranym kształtem pozostają bez zmian
+            if !randomizer_config.shape.contains(x, y, width, height) {
+                continue;
+            }
+            
+            // Istniejące żywe komórki (tryb addytywny) nie są losowane ponownie
+            if new_board.get_cell(x, y) == Some(CellState::Alive) {
+                continue;
+            }
+            
             let probability = calculate_cell_probability(
                 &new_board, 
                 x, 
@@ -45,6 +57,49 @@ pub fn generate_random_board(current_board: &Board) -> Board {
     new_board
 }
 
+/// Rozmiar próbnej planszy używanej do oszacowania gęstości
+const DENSITY_SAMPLE_SIZE: usize = 64;
+
+/// Ziarno próbnej planszy - oszacowanie nie zmienia się bez zmiany parametrów
+const DENSITY_SAMPLE_SEED: u64 = 0x5EED;
+
+/// Szacuje jaki ułamek komórek będzie żywy po losowaniu z podaną konfiguracją
+/// 
+/// Bonus za sąsiadów zależy od już wylosowanych komórek, więc gęstości nie da się
+/// łatwo wyliczyć wzorem - zamiast tego losujemy próbną planszę o stałym ziarnie.
+pub fn estimate_density(config: &GameConfig) -> f32 {
+    let mut sample_config = config.clone();
+    sample_config.set_randomizer_seed(Some(DENSITY_SAMPLE_SEED));
+    sample_config.set_randomizer_additive(false);
+    
+    let sample_board = Board::new(DENSITY_SAMPLE_SIZE, DENSITY_SAMPLE_SIZE);
+    let sample = generate_random_board(&sample_board, &sample_config);
+    sample.count_alive_cells() as f32 / sample.total_cells() as f32
+}
+
+/// Zwraca planszę, na której losowane są żywe komórki
+/// 
+/// W trybie addytywnym jest to kopia aktualnej planszy, w przeciwnym razie
+/// pusta plansza o tym samym rozmiarze.
+fn starting_board(current_board: &Board, config: &RandomizerConfig) -> Board {
+    if config.additive {
+        current_board.clone()
+    } else {
+        Board::new(current_board.width(), current_board.height())
+    }
+}
+
+/// Tworzy generator liczb losowych zgodnie z konfiguracją
+/// 
+/// Przy ustawionym ziarnie generator jest deterministyczny,
+/// w przeciwnym razie ziarno pochodzi z entropii systemu.
+fn create_rng(config: &RandomizerConfig) -> StdRng {
+    match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
 /// Oblicza prawdopodobieństwo że komórka będzie żywa
 fn calculate_cell_probability(
     board: &Board, 
@@ -62,7 +117,7 @@ fn calculate_cell_probability(
     let total_probability = base_probability + (alive_neighbors as f32 * neighbor_bonus);
     
     // Ograniczamy do przedziału 0.0 - 1.0
-    total_probability.min(1.0).max(0.0)
+    total_probability.clamp(0.0, 1.0)
 }
 
 /// Zlicza liczbę żywych sąsiadów dla danej komórki
@@ -101,19 +156,27 @@ fn count_alive_neighbors(board: &Board, x: usize, y: usize) -> usize {
 
 /// Generuje całkowicie losową planszę bez uwzględnienia sąsiadów
 /// 
-/// Każda komórka ma takie samo prawdopodobieństwo życia (bazowe prawdopodobieństwo).
-pub fn generate_simple_random_board(current_board: &Board) -> Board {
-    let config = get_config();
+/// Każda komórka w wybranym kształcie ma takie samo prawdopodobieństwo życia
+/// (bazowe prawdopodobieństwo). W trybie addytywnym losowane są tylko martwe komórki.
+pub fn generate_simple_random_board(current_board: &Board, config: &GameConfig) -> Board {
     let base_probability = config.randomizer_config.base_probability;
     
     let width = current_board.width();
     let height = current_board.height();
-    let mut new_board = Board::new(width, height);
-    let mut rng = rand::thread_rng();
+    let mut new_board = starting_board(current_board, &config.randomizer_config);
+    let mut rng = create_rng(&config.randomizer_config);
     
     // Iterujemy przez każdą komórkę planszy
     for y in 0..height {
         for x in 0..width {
+            if !config.randomizer_config.shape.contains(x, y, width, height) {
+                continue;
+            }
+            
+            if new_board.get_cell(x, y) == Some(CellState::Alive) {
+                continue;
+            }
+            
             let random_value: f32 = rng.r#gen();
             if random_value < base_probability {
                 new_board.set_cell(x, y, CellState::Alive);
@@ -122,4 +185,104 @@ pub fn generate_simple_random_board(current_board: &Board) -> Board {
     }
     
     new_board
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RandomFillShape;
+    
+    /// Konfiguracja losowania z podanym ziarnem
+    fn seeded_config(seed: u64) -> GameConfig {
+        let mut config = GameConfig::default();
+        config.set_randomizer_base_probability(0.3);
+        config.set_randomizer_seed(Some(seed));
+        config
+    }
+    
+    #[test]
+    fn same_seed_gives_same_board() {
+        let empty = Board::new(40, 30);
+        let first = generate_random_board(&empty, &seeded_config(1234));
+        let second = generate_random_board(&empty, &seeded_config(1234));
+        
+        assert!(first.count_alive_cells() > 0);
+        assert_eq!(first, second);
+    }
+    
+    #[test]
+    fn different_seeds_give_different_boards() {
+        let empty = Board::new(40, 30);
+        assert_ne!(
+            generate_random_board(&empty, &seeded_config(1)),
+            generate_random_board(&empty, &seeded_config(2))
+        );
+    }
+    
+    /// Plansza z kilkoma narysowanymi komórkami
+    fn drawn_board() -> Board {
+        let mut board = Board::new(30, 20);
+        for &(x, y) in &[(1, 1), (2, 1), (15, 10), (28, 18), (0, 19)] {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    /// Konfiguracja losowania addytywnego bez bonusu za sąsiadów
+    fn additive_config(probability: f32) -> GameConfig {
+        let mut config = seeded_config(42);
+        config.set_randomizer_additive(true);
+        config.set_randomizer_base_probability(probability);
+        config.set_randomizer_neighbor_bonus(0.0);
+        config
+    }
+    
+    #[test]
+    fn additive_fill_with_zero_probability_keeps_the_board() {
+        let board = drawn_board();
+        let config = additive_config(0.0);
+        
+        assert_eq!(generate_random_board(&board, &config), board);
+        assert_eq!(generate_simple_random_board(&board, &config), board);
+    }
+    
+    #[test]
+    fn additive_fill_keeps_every_drawn_cell() {
+        let board = drawn_board();
+        let config = additive_config(0.3);
+        
+        for filled in [generate_random_board(&board, &config), generate_simple_random_board(&board, &config)] {
+            assert!(filled.count_alive_cells() > board.count_alive_cells());
+            for y in 0..board.height() {
+                for x in 0..board.width() {
+                    if board.get_cell(x, y) == Some(CellState::Alive) {
+                        assert_eq!(filled.get_cell(x, y), Some(CellState::Alive), "cell ({}, {}) was removed", x, y);
+                    }
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn disk_fill_stays_inside_the_radius() {
+        let (width, height) = (40, 24);
+        let mut config = seeded_config(7);
+        config.set_randomizer_shape(RandomFillShape::Disk);
+        config.set_randomizer_base_probability(1.0);
+        
+        let board = generate_random_board(&Board::new(width, height), &config);
+        assert!(board.count_alive_cells() > 0);
+        
+        let radius = width.min(height) as f32 / 2.0;
+        let (center_x, center_y) = ((width as f32 - 1.0) / 2.0, (height as f32 - 1.0) / 2.0);
+        for y in 0..height {
+            for x in 0..width {
+                let (dx, dy) = (x as f32 - center_x, y as f32 - center_y);
+                let inside = dx * dx + dy * dy <= radius * radius;
+                let alive = board.get_cell(x, y) == Some(CellState::Alive);
+                assert!(inside || !alive, "cell ({}, {}) lies outside the disk", x, y);
+                assert!(!inside || alive, "cell ({}, {}) inside the disk was not filled", x, y);
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+/// Moduł rasteryzacji planszy do obrazu RGBA
+/// 
+/// Wyznacza położenie komórek na ekranie i rysuje planszę do bufora pikseli
+/// bez udziału egui, więc wynik można porównywać piksel po pikselu bez
+/// kontekstu graficznego. Renderer okna korzysta z tej samej geometrii komórek.
+
+use super::board::Board;
+
+/// Kolor RGBA (bez premultiplikacji przez kanał alfa)
+pub type Rgba = [u8; 4];
+
+/// Prostokąt komórki we współrzędnych ekranu (w pikselach)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellRect {
+    /// Lewy górny róg komórki
+    pub min: (f32, f32),
+    /// Długość boku komórki
+    pub size: f32,
+}
+
+impl CellRect {
+    /// Zwraca prawy dolny róg komórki
+    pub fn max(&self) -> (f32, f32) {
+        (self.min.0 + self.size, self.min.1 + self.size)
+    }
+}
+
+/// Oblicza prostokąt komórki (x, y) planszy, której lewy górny róg leży w `origin`
+pub fn cell_rect(origin: (f32, f32), cell_size: f32, x: usize, y: usize) -> CellRect {
+    CellRect {
+        min: (origin.0 + x as f32 * cell_size, origin.1 + y as f32 * cell_size),
+        size: cell_size,
+    }
+}
+
+/// Obraz RGBA przechowywany wiersz po wierszu
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgba>,
+}
+
+impl RgbaImage {
+    /// Tworzy obraz wypełniony jednym kolorem
+    pub fn new(width: usize, height: usize, fill: Rgba) -> Self {
+        Self { width, height, pixels: vec![fill; width * height] }
+    }
+    
+    /// Zwraca szerokość obrazu w pikselach
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    
+    /// Zwraca wysokość obrazu w pikselach
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    
+    /// Zwraca kolor piksela (None poza obrazem)
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Rgba> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+    
+    /// Zwraca piksele jako bajty RGBA wiersz po wierszu (np. do zapisu w formacie PNG)
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+    
+    /// Liczy piksele, których dowolny kanał różni się od drugiego obrazu o więcej niż `tolerance`
+    /// 
+    /// Obrazy o różnych wymiarach różnią się wszystkimi pikselami.
+    pub fn count_different_pixels(&self, other: &RgbaImage, tolerance: u8) -> usize {
+        if self.width != other.width || self.height != other.height {
+            return self.pixels.len().max(other.pixels.len());
+        }
+        
+        self.pixels
+            .iter()
+            .zip(&other.pixels)
+            .filter(|(a, b)| a.iter().zip(b.iter()).any(|(a, b)| a.abs_diff(*b) > tolerance))
+            .count()
+    }
+    
+    /// Wypełnia prostokąt komórki kolorem
+    /// 
+    /// Piksel należy do komórki, gdy leży w niej jego środek - sąsiednie komórki
+    /// nie nachodzą na siebie także przy ułamkowym rozmiarze komórki.
+    pub fn fill_cell(&mut self, rect: CellRect, color: Rgba) {
+        let (max_x, max_y) = rect.max();
+        let to_range = |min: f32, max: f32, len: usize| {
+            let first = (min - 0.5).ceil().max(0.0) as usize;
+            let last = (max - 0.5).ceil().max(0.0) as usize;
+            first.min(len)..last.min(len)
+        };
+        
+        for y in to_range(rect.min.1, max_y, self.height) {
+            for x in to_range(rect.min.0, max_x, self.width) {
+                self.pixels[y * self.width + x] = color;
+            }
+        }
+    }
+}
+
+/// Rysuje planszę do obrazu RGBA
+/// 
+/// Martwe komórki tworzą tło, a żywe są rysowane jako kwadraty w kolorze
+/// zwróconym przez `alive_color` (np. zależnym od wieku komórki).
+pub fn rasterize_board<F>(board: &Board, cell_size: f32, dead_color: Rgba, alive_color: F) -> RgbaImage
+where
+    F: Fn(usize, usize) -> Rgba,
+{
+    let width = (board.width() as f32 * cell_size).ceil() as usize;
+    let height = (board.height() as f32 * cell_size).ceil() as usize;
+    let mut image = RgbaImage::new(width, height, dead_color);
+    
+    for (x, y) in board.iter_alive_cells() {
+        image.fill_cell(cell_rect((0.0, 0.0), cell_size, x, y), alive_color(x, y));
+    }
+    
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::board::CellState;
+    use std::path::PathBuf;
+    
+    /// Rozmiar komórki w obrazach wzorcowych
+    const CELL_SIZE: f32 = 4.0;
+    
+    const DEAD: Rgba = [255, 255, 255, 255];
+    const ALIVE: Rgba = [0, 0, 0, 255];
+    
+    /// Liczba pikseli, które mogą się różnić od obrazu wzorcowego (np. przez inne zaokrąglenie krawędzi)
+    const MAX_DIFFERENT_PIXELS: usize = 4;
+    
+    /// Dopuszczalna różnica kanału koloru piksela
+    const CHANNEL_TOLERANCE: u8 = 2;
+    
+    /// Tworzy planszę z podanymi żywymi komórkami
+    fn board_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Board {
+        let mut board = Board::new(width, height);
+        for &(x, y) in cells {
+            board.set_cell(x, y, CellState::Alive);
+        }
+        board
+    }
+    
+    /// Porównuje obraz planszy z obrazem wzorcowym z `tests/golden`
+    /// 
+    /// Przy ustawionej zmiennej środowiskowej `UPDATE_GOLDEN` obraz wzorcowy
+    /// jest zapisywany na nowo zamiast porównywania.
+    fn assert_matches_golden(board: &Board, name: &str) {
+        let image = rasterize_board(board, CELL_SIZE, DEAD, |_, _| ALIVE);
+        let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "golden", &format!("{}.png", name)].iter().collect();
+        
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            image::save_buffer(&path, &image.as_bytes(), image.width() as u32, image.height() as u32, image::ExtendedColorType::Rgba8)
+                .expect("nie udało się zapisać obrazu wzorcowego");
+            return;
+        }
+        
+        let golden = image::open(&path)
+            .unwrap_or_else(|e| panic!("nie udało się wczytać {}: {}", path.display(), e))
+            .to_rgba8();
+        let golden = RgbaImage {
+            width: golden.width() as usize,
+            height: golden.height() as usize,
+            pixels: golden.pixels().map(|pixel| pixel.0).collect(),
+        };
+        
+        let different = image.count_different_pixels(&golden, CHANNEL_TOLERANCE);
+        assert!(different <= MAX_DIFFERENT_PIXELS, "{}: {} pikseli różni się od obrazu wzorcowego", name, different);
+    }
+    
+    #[test]
+    fn glider_matches_golden_image() {
+        assert_matches_golden(&board_with(5, 5, &[(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]), "glider");
+    }
+    
+    #[test]
+    fn blinker_matches_golden_image() {
+        assert_matches_golden(&board_with(5, 5, &[(1, 2), (2, 2), (3, 2)]), "blinker");
+    }
+    
+    #[test]
+    fn empty_board_matches_golden_image() {
+        assert_matches_golden(&board_with(5, 5, &[]), "empty");
+    }
+    
+    #[test]
+    fn fractional_cells_do_not_overlap() {
+        let board = board_with(3, 1, &[(0, 0), (2, 0)]);
+        let image = rasterize_board(&board, 2.5, DEAD, |_, _| ALIVE);
+        
+        // Środki pikseli 0-1 leżą w komórce 0, 2-4 w komórce 1, a 5-6 w komórce 2;
+        // środek piksela 7 leży na prawej krawędzi planszy
+        let row: Vec<Rgba> = (0..image.width()).map(|x| image.pixel(x, 0).unwrap()).collect();
+        assert_eq!(row, vec![ALIVE, ALIVE, DEAD, DEAD, DEAD, ALIVE, ALIVE, DEAD]);
+    }
+}
@@ -0,0 +1,259 @@
+/// Moduł nagrywania przebiegu symulacji
+/// 
+/// Gra w życie nie jest odwracalna, więc aby odtworzyć przebieg symulacji
+/// (np. na potrzeby prezentacji lub zgłoszeń błędów), kolejne generacje są
+/// zapisywane jako klatki RLE. Nagranie można zapisać do pliku, wczytać
+/// i odtworzyć klatka po klatce bez ponownego liczenia generacji.
+
+use std::fmt;
+use super::board::{Board, CellState};
+use crate::assets::Pattern;
+use crate::config::rules::{GameConfig, MAX_BOARD_DIMENSION};
+
+/// Rozmiar nagrania, powyżej którego wyświetlane jest ostrzeżenie
+pub const RECORDING_WARNING_BYTES: usize = 16 * 1024 * 1024;
+
+/// Największy rozmiar nagrania - po jego osiągnięciu kolejne klatki nie są dopisywane
+pub const MAX_RECORDING_BYTES: usize = 64 * 1024 * 1024;
+
+/// Pierwsza linia pliku nagrania
+const RECORDING_HEADER: &str = "#Game of Life recording";
+
+/// Przedrostek linii rozpoczynającej klatkę: "#F szerokość wysokość x y"
+const FRAME_PREFIX: &str = "#F ";
+
+/// Błąd wczytywania nagrania
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordingError {
+    /// Plik nie zawiera żadnej klatki
+    NoFrames,
+    /// Niepoprawna linia nagłówka klatki
+    InvalidFrameHeader { frame: usize },
+    /// Niepoprawna treść RLE klatki
+    InvalidFrame { frame: usize, reason: String },
+}
+
+impl fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordingError::NoFrames => write!(f, "the recording contains no frames"),
+            RecordingError::InvalidFrameHeader { frame } => {
+                write!(f, "frame {} has an invalid header (expected \"#F width height x y\")", frame)
+            }
+            RecordingError::InvalidFrame { frame, reason } => write!(f, "frame {}: {}", frame, reason),
+        }
+    }
+}
+
+impl std::error::Error for RecordingError {}
+
+/// Nagranie kolejnych generacji planszy
+/// 
+/// Każda klatka przechowuje wymiary planszy, położenie obszaru żywych komórek
+/// i jego zapis RLE, więc odtworzona plansza jest identyczna z nagraną.
+#[derive(Debug, Clone, Default)]
+pub struct Recording {
+    /// Zakodowane klatki (linia "#F ..." i treść RLE)
+    frames: Vec<String>,
+    /// Łączny rozmiar zakodowanych klatek w bajtach
+    size_bytes: usize,
+    /// Czy pominięto klatki po osiągnięciu limitu rozmiaru
+    truncated: bool,
+}
+
+impl Recording {
+    /// Tworzy puste nagranie
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Dopisuje planszę jako kolejną klatkę
+    /// 
+    /// Zwraca false (bez dopisywania), gdy klatka przekroczyłaby `MAX_RECORDING_BYTES`.
+    pub fn push(&mut self, board: &Board, config: &GameConfig) -> bool {
+        let (origin_x, origin_y, _, _) = board.alive_bounding_box().unwrap_or((0, 0, 0, 0));
+        let frame = format!(
+            "{}{} {} {} {}\n{}",
+            FRAME_PREFIX,
+            board.width(),
+            board.height(),
+            origin_x,
+            origin_y,
+            board.to_rle(config)
+        );
+        
+        if self.size_bytes + frame.len() > MAX_RECORDING_BYTES {
+            self.truncated = true;
+            return false;
+        }
+        
+        self.size_bytes += frame.len();
+        self.frames.push(frame);
+        true
+    }
+    
+    /// Zwraca liczbę klatek
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    
+    /// Sprawdza czy nagranie nie zawiera klatek
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+    
+    /// Zwraca rozmiar nagrania w bajtach
+    pub fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+    
+    /// Sprawdza czy nagranie przekroczyło rozmiar, przy którym wyświetlane jest ostrzeżenie
+    pub fn is_large(&self) -> bool {
+        self.size_bytes >= RECORDING_WARNING_BYTES
+    }
+    
+    /// Sprawdza czy część klatek pominięto z powodu limitu rozmiaru
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+    
+    /// Zapisuje nagranie jako tekst (klatki RLE jedna po drugiej)
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}, {} frames\n", RECORDING_HEADER, self.frames.len());
+        for frame in &self.frames {
+            text.push_str(frame);
+        }
+        text
+    }
+    
+    /// Wczytuje nagranie z tekstu zapisanego przez `to_text`
+    /// 
+    /// Wszystkie klatki są sprawdzane od razu, aby błąd pliku był zgłaszany
+    /// przy wczytywaniu, a nie w trakcie odtwarzania.
+    pub fn from_text(text: &str) -> Result<Recording, RecordingError> {
+        let mut recording = Recording::new();
+        let mut current_frame: Option<String> = None;
+        
+        for line in text.lines() {
+            if line.starts_with(FRAME_PREFIX) {
+                if let Some(frame) = current_frame.take() {
+                    recording.add_loaded_frame(frame)?;
+                }
+                current_frame = Some(String::new());
+            }
+            
+            // Linie przed pierwszą klatką (np. nagłówek pliku) są pomijane
+            if let Some(frame) = current_frame.as_mut() {
+                frame.push_str(line);
+                frame.push('\n');
+            }
+        }
+        if let Some(frame) = current_frame {
+            recording.add_loaded_frame(frame)?;
+        }
+        
+        if recording.is_empty() {
+            return Err(RecordingError::NoFrames);
+        }
+        Ok(recording)
+    }
+    
+    /// Sprawdza wczytaną klatkę i dopisuje ją do nagrania
+    fn add_loaded_frame(&mut self, frame: String) -> Result<(), RecordingError> {
+        Self::decode_frame(&frame, self.frames.len())?;
+        self.size_bytes += frame.len();
+        self.frames.push(frame);
+        Ok(())
+    }
+    
+    /// Odtwarza planszę z klatki o podanym indeksie
+    pub fn frame(&self, index: usize) -> Option<Board> {
+        self.frames
+            .get(index)
+            .and_then(|frame| Self::decode_frame(frame, index).ok())
+    }
+    
+    /// Dekoduje zapis klatki do planszy
+    fn decode_frame(frame: &str, index: usize) -> Result<Board, RecordingError> {
+        let (header, rle) = frame.split_once('\n').unwrap_or((frame, ""));
+        let values: Vec<usize> = header[FRAME_PREFIX.len()..]
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| RecordingError::InvalidFrameHeader { frame: index })?;
+        let [width, height, origin_x, origin_y] = values[..] else {
+            return Err(RecordingError::InvalidFrameHeader { frame: index });
+        };
+        if width > MAX_BOARD_DIMENSION || height > MAX_BOARD_DIMENSION {
+            return Err(RecordingError::InvalidFrame {
+                frame: index,
+                reason: format!("board size {}x{} exceeds the maximum of {}", width, height, MAX_BOARD_DIMENSION),
+            });
+        }
+        
+        let pattern = Pattern::from_rle(rle)
+            .map_err(|error| RecordingError::InvalidFrame { frame: index, reason: error.to_string() })?;
+        
+        let mut board = Board::new(width, height);
+        for position in &pattern.cells {
+            let x = origin_x + position.x as usize;
+            let y = origin_y + position.y as usize;
+            if !board.set_cell(x, y, CellState::Alive) {
+                return Err(RecordingError::InvalidFrame {
+                    frame: index,
+                    reason: format!("cell ({}, {}) lies outside the {}x{} board", x, y, width, height),
+                });
+            }
+        }
+        
+        Ok(board)
+    }
+}
+
+/// Odtwarzanie wczytanego nagrania zamiast obliczania kolejnych generacji
+#[derive(Debug, Clone)]
+pub struct Playback {
+    /// Odtwarzane nagranie
+    recording: Recording,
+    /// Indeks aktualnie wyświetlanej klatki
+    current_frame: usize,
+}
+
+impl Playback {
+    /// Rozpoczyna odtwarzanie od pierwszej klatki
+    pub fn new(recording: Recording) -> Self {
+        Self { recording, current_frame: 0 }
+    }
+    
+    /// Zwraca planszę aktualnej klatki
+    pub fn current_board(&self) -> Option<Board> {
+        self.recording.frame(self.current_frame)
+    }
+    
+    /// Przechodzi do następnej klatki i zwraca jej planszę (None na końcu nagrania)
+    pub fn next_board(&mut self) -> Option<Board> {
+        let board = self.recording.frame(self.current_frame + 1)?;
+        self.current_frame += 1;
+        Some(board)
+    }
+    
+    /// Cofa odtwarzanie o jedną klatkę (plansza pochodzi z historii generacji)
+    pub fn step_back(&mut self) {
+        self.current_frame = self.current_frame.saturating_sub(1);
+    }
+    
+    /// Wraca do pierwszej klatki
+    pub fn rewind(&mut self) {
+        self.current_frame = 0;
+    }
+    
+    /// Zwraca indeks aktualnej klatki
+    pub fn current_frame(&self) -> usize {
+        self.current_frame
+    }
+    
+    /// Zwraca liczbę klatek nagrania
+    pub fn frame_count(&self) -> usize {
+        self.recording.len()
+    }
+}
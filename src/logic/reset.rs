@@ -5,9 +5,10 @@
 /// 2. Drugi reset - całkowicie pusta plansza
 
 use super::board::Board;
-use crate::config::{get_config, BoardSizeMode};
+use crate::config::rules::GameConfig;
 
 /// Manager odpowiedzialny za logikę resetowania planszy
+#[derive(Debug, Clone)]
 pub struct ResetManager {
     /// Stan planszy przed pierwszym uruchomieniem (do resetowania)
     pre_start_board: Option<Board>,
@@ -43,21 +44,16 @@ impl ResetManager {
     /// Resetuje planszę zgodnie z dwuetapowym systemem
     /// 
     /// Zwraca nową planszę oraz informację czy aplikacja powinna być oznaczona jako "nie uruchomiona"
-    pub fn reset_board(&mut self, _current_board: &Board, ever_started: bool) -> (Board, bool) {
-        // ZAWSZE pobieramy najświeższe ustawienia z konfiguracji
-        // aby uwzględnić zmiany dokonane przez użytkownika w GUI
-        let config = get_config();
-        
+    /// Rozmiar nowej planszy wynika z przekazanej (aktualnej) konfiguracji,
+    /// aby uwzględnić zmiany dokonane przez użytkownika w GUI.
+    pub fn reset_board(&mut self, _current_board: &Board, ever_started: bool, config: &GameConfig) -> (Board, bool) {
         // Pobieramy docelowy rozmiar planszy z aktualnych ustawień Board Settings
-        let target_size = match config.board_size_mode {
-            BoardSizeMode::Dynamic => config.initial_board_size,
-            BoardSizeMode::Static => config.static_board_size,
-        };
+        let (target_width, target_height) = config.get_current_board_size();
         
         if !ever_started {
             // Aplikacja nie była jeszcze uruchomiona - tworzymy pustą planszę
             // o rozmiarze zgodnym z aktualnymi ustawieniami Board Settings
-            let new_board = Board::new(target_size, target_size);
+            let new_board = Board::new(target_width, target_height);
             self.last_reset_was_to_pre_start = false;
             (new_board, false) // Nie zmieniamy stanu ever_started
         } else {
@@ -66,13 +62,13 @@ impl ResetManager {
                 if !self.last_reset_was_to_pre_start {
                     // Pierwszy reset - wracamy do stanu przed uruchomieniem
                     // ale z rozmiarem dostosowanym do AKTUALNYCH ustawień Board Settings
-                    let resized_board = self.resize_board_to_target(pre_start_board, target_size);
+                    let resized_board = self.resize_board_to_target(pre_start_board, target_width, target_height);
                     self.last_reset_was_to_pre_start = true;
                     (resized_board, false) // Nie resetujemy ever_started
                 } else {
                     // Drugi reset - czyścimy planszę całkowicie (PUSTA PLANSZA)
                     // o rozmiarze zgodnym z AKTUALNYMI ustawieniami Board Settings
-                    let new_board = Board::new(target_size, target_size);
+                    let new_board = Board::new(target_width, target_height);
                     self.last_reset_was_to_pre_start = false;
                     
                     // Resetujemy stan managera
@@ -84,7 +80,7 @@ impl ResetManager {
             } else {
                 // Fallback - jeśli nie ma zapisanego stanu, tworzymy pustą planszę
                 // o rozmiarze zgodnym z AKTUALNYMI ustawieniami Board Settings
-                let new_board = Board::new(target_size, target_size);
+                let new_board = Board::new(target_width, target_height);
                 self.last_reset_was_to_pre_start = false;
                 self.pre_start_board = None;
                 self.was_ever_started = false;
@@ -99,6 +95,11 @@ impl ResetManager {
         self.pre_start_board.is_some()
     }
     
+    /// Zwraca zapisany stan przed uruchomieniem bez zmiany etapu resetu
+    pub fn peek_pre_start_state(&self) -> Option<&Board> {
+        self.pre_start_board.as_ref()
+    }
+    
     /// Czyści zapisany stan przed uruchomieniem
     pub fn clear_pre_start_state(&mut self) {
         self.pre_start_board = None;
@@ -137,7 +138,7 @@ impl ResetManager {
     /// Funkcja przepisuje stan planszy do nowej planszy o docelowym rozmiarze.
     /// Jeśli nowy rozmiar jest większy, wzór jest wyśrodkowany.
     /// Jeśli nowy rozmiar jest mniejszy, wzór jest obcinany z zachowaniem środka.
-    fn resize_board_to_target(&self, source_board: &Board, target_size: usize) -> Board {
-        source_board.resize_to_square(target_size)
+    fn resize_board_to_target(&self, source_board: &Board, target_width: usize, target_height: usize) -> Board {
+        source_board.resize_to(target_width, target_height)
     }
 }
@@ -0,0 +1,95 @@
+/// Moduł zaznaczania prostokątnego obszaru planszy
+/// 
+/// Zawiera prostokąt zaznaczenia oraz schowek z kopią zaznaczonych komórek,
+/// który można wkleić w innym miejscu planszy.
+
+use super::board::{Board, CellState};
+
+/// Prostokątne zaznaczenie na planszy wyznaczone przez dwa przeciwległe rogi (włącznie)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    /// Komórka, od której rozpoczęto zaznaczanie
+    pub anchor: (usize, usize),
+    /// Komórka, na której zakończono (lub aktualnie kończy się) zaznaczanie
+    pub end: (usize, usize),
+}
+
+impl Selection {
+    /// Tworzy zaznaczenie obejmujące pojedynczą komórkę
+    pub fn new(cell: (usize, usize)) -> Self {
+        Self { anchor: cell, end: cell }
+    }
+    
+    /// Przesuwa drugi róg zaznaczenia do podanej komórki
+    pub fn extend_to(&mut self, cell: (usize, usize)) {
+        self.end = cell;
+    }
+    
+    /// Zwraca granice zaznaczenia (min_x, min_y, max_x, max_y)
+    pub fn bounds(&self) -> (usize, usize, usize, usize) {
+        (
+            self.anchor.0.min(self.end.0),
+            self.anchor.1.min(self.end.1),
+            self.anchor.0.max(self.end.0),
+            self.anchor.1.max(self.end.1),
+        )
+    }
+}
+
+/// Skopiowany prostokątny fragment planszy (żywe i martwe komórki)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardClipboard {
+    width: usize,
+    height: usize,
+    /// Stany komórek wiersz po wierszu
+    cells: Vec<CellState>,
+}
+
+impl BoardClipboard {
+    /// Kopiuje komórki objęte zaznaczeniem
+    /// 
+    /// Komórki zaznaczenia leżące poza planszą są kopiowane jako martwe.
+    pub fn from_selection(board: &Board, selection: &Selection) -> Self {
+        let (min_x, min_y, max_x, max_y) = selection.bounds();
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+        
+        let mut cells = Vec::with_capacity(width * height);
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                cells.push(board.get_cell(x, y).unwrap_or(CellState::Dead));
+            }
+        }
+        
+        Self { width, height, cells }
+    }
+    
+    /// Zwraca szerokość skopiowanego fragmentu
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    
+    /// Zwraca wysokość skopiowanego fragmentu
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    
+    /// Iteruje po wszystkich komórkach fragmentu (x, y, stan) we współrzędnych względnych
+    pub fn iter_cells(&self) -> impl Iterator<Item = (usize, usize, CellState)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, &state)| (index % self.width, index / self.width, state))
+    }
+    
+    /// Zwraca lewy górny róg wklejenia dla wskazanej komórki
+    /// 
+    /// Fragment jest przesuwany w lewo/w górę tak, aby w całości zmieścił się
+    /// na planszy (jeśli jest większy niż plansza, zaczyna się w rogu (0, 0)).
+    pub fn clamped_origin(&self, board: &Board, x: usize, y: usize) -> (usize, usize) {
+        (
+            x.min(board.width().saturating_sub(self.width)),
+            y.min(board.height().saturating_sub(self.height)),
+        )
+    }
+}
@@ -0,0 +1,88 @@
+/// Moduł zbierania statystyk kolejnych generacji
+/// 
+/// Dla każdej obliczonej generacji zapisywana jest liczba żywych komórek,
+/// narodzin i śmierci oraz gęstość zaludnienia. Zebrane dane można
+/// wyeksportować do pliku CSV i analizować w zewnętrznych programach.
+
+use std::fmt::Write;
+use super::board::Board;
+
+/// Nagłówek pliku CSV ze statystykami
+const CSV_HEADER: &str = "generation,population,births,deaths,density";
+
+/// Statystyki pojedynczej generacji
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GenerationStats {
+    /// Numer generacji
+    pub generation: u64,
+    /// Liczba żywych komórek
+    pub population: usize,
+    /// Liczba komórek, które narodziły się w tej generacji
+    pub births: usize,
+    /// Liczba komórek, które umarły w tej generacji
+    pub deaths: usize,
+    /// Odsetek żywych komórek planszy (0.0 - 1.0)
+    pub density: f64,
+}
+
+/// Statystyki kolejnych generacji od ostatniego resetu
+#[derive(Debug, Clone, Default)]
+pub struct StatisticsLog {
+    rows: Vec<GenerationStats>,
+}
+
+impl StatisticsLog {
+    /// Tworzy pusty dziennik statystyk
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Dopisuje statystyki generacji na podstawie jej planszy
+    pub fn record(&mut self, generation: u64, board: &Board, births: usize, deaths: usize) {
+        let population = board.count_alive_cells();
+        let density = if board.total_cells() == 0 {
+            0.0
+        } else {
+            population as f64 / board.total_cells() as f64
+        };
+        self.rows.push(GenerationStats { generation, population, births, deaths, density });
+    }
+    
+    /// Usuwa statystyki generacji późniejszych niż podana (np. po cofnięciu symulacji)
+    pub fn truncate_after(&mut self, generation: u64) {
+        self.rows.retain(|row| row.generation <= generation);
+    }
+    
+    /// Usuwa wszystkie zebrane statystyki
+    pub fn clear(&mut self) {
+        self.rows.clear();
+    }
+    
+    /// Zwraca liczbę zapisanych generacji
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+    
+    /// Sprawdza czy nie zapisano żadnej generacji
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+    
+    /// Zapisuje statystyki w formacie CSV (z nagłówkiem)
+    /// 
+    /// Liczby są formatowane przez `std::fmt`, więc separatorem dziesiętnym
+    /// zawsze jest kropka, niezależnie od ustawień regionalnych systemu.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::with_capacity((self.rows.len() + 1) * 32);
+        csv.push_str(CSV_HEADER);
+        csv.push('\n');
+        for row in &self.rows {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{:.6}",
+                row.generation, row.population, row.births, row.deaths, row.density
+            );
+        }
+        csv
+    }
+}
@@ -0,0 +1,57 @@
+/// Moduł zakładek plansz
+/// 
+/// Pozwala prowadzić kilka niezależnych eksperymentów jednocześnie.
+/// Każda zakładka przechowuje własną planszę, licznik generacji,
+/// konfigurację gry oraz stan dwuetapowego resetowania.
+
+use super::board::Board;
+use super::reset::ResetManager;
+use super::history::BoardHistory;
+use crate::config::rules::GameConfig;
+
+/// Pojedyncza zakładka z niezależną planszą
+#[derive(Debug, Clone)]
+pub struct BoardTab {
+    /// Nazwa wyświetlana na pasku zakładek
+    pub name: String,
+    /// Aktualna plansza zakładki
+    pub board: Board,
+    /// Początkowy stan planszy zakładki
+    pub initial_board: Board,
+    /// Liczba wykonanych generacji
+    pub generation_count: u64,
+    /// Konfiguracja gry obowiązująca w tej zakładce
+    pub config: GameConfig,
+    /// Stan resetowania zakładki
+    pub reset_manager: ResetManager,
+    /// Czy symulacja w tej zakładce była kiedykolwiek uruchomiona
+    pub ever_started: bool,
+    /// Poprzednie generacje zakładki
+    pub history: BoardHistory,
+}
+
+impl BoardTab {
+    /// Tworzy nową zakładkę z podaną planszą i konfiguracją
+    pub fn new(name: String, board: Board, config: GameConfig) -> Self {
+        Self {
+            name,
+            initial_board: board.clone(),
+            board,
+            generation_count: 0,
+            config,
+            reset_manager: ResetManager::new(),
+            ever_started: false,
+            history: BoardHistory::new(),
+        }
+    }
+}
+
+/// Sposób przełączania symulacji między zakładkami
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabStepMode {
+    /// Krok wykonuje tylko aktywna (widoczna) zakładka
+    #[default]
+    ActiveOnly,
+    /// Wszystkie zakładki wykonują krok jednocześnie
+    AllTogether,
+}
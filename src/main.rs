@@ -1,22 +1,92 @@
-mod config;
-mod logic;
 mod ui;
-mod assets;
 
-use config::{init_config, get_default_initial_state};
+use Game_of_life::{assets, config, logic};
+
+use config::get_initial_state;
+use config::initial_state::Pattern as InitialPattern;
+use config::rules::{GameConfig, MAX_BOARD_DIMENSION};
 use logic::board::{Board, CellState};
-use logic::change_state::CellStateManager;
+use logic::change_state::{BrushSize, CellStateManager, EditMode};
 use logic::prediction::{predict_next_state, PredictionResult};
 use logic::reset::ResetManager;
 use logic::randomizer;
-use ui::{GameRenderer, SidePanel, MouseInteraction};
-use ui::side_panel::{SimulationState, UserAction};
+use logic::tabs::{BoardTab, TabStepMode};
+use logic::history::BoardHistory;
+use logic::detection::StateDetector;
+use logic::selection::{BoardClipboard, Selection};
+use logic::statistics::StatisticsLog;
+use logic::recording::{Playback, Recording, MAX_RECORDING_BYTES, RECORDING_WARNING_BYTES};
+use assets::PatternFormat;
+use ui::{BoardTool, GameRenderer, SidePanel, MouseInteraction, TabBar};
+use ui::side_panel::{SimulationState, UserAction, BUSY_INDICATOR_STEPS, MAX_STEPS_PER_CLICK};
 
 use eframe::egui;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+/// Najkrótszy odstęp między sprawdzeniami stabilności planszy po jej edycji
+/// 
+/// Sprawdzenie wymaga obliczenia następnej generacji, więc przy rysowaniu
+/// nie jest wykonywane w każdej klatce.
+const STABILITY_CHECK_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Czas bez edycji planszy, po którym przewidywanie jest liczone od nowa dla całej planszy
+/// 
+/// Przy szybkich edycjach (np. wypełnianiu obszarów) pełne przeliczenie dużej
+/// planszy w każdej klatce spowalniałoby rysowanie.
+const PREDICTION_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Okres, z którego liczona jest faktycznie osiągnięta prędkość symulacji
+const SPEED_MEASUREMENT_WINDOW: Duration = Duration::from_secs(1);
+
+/// Klucz, pod którym w pamięci eframe zapisywany jest układ okna
+const WINDOW_LAYOUT_KEY: &str = "window_layout";
+
+/// Klucz, pod którym zapisywana jest nazwa wzoru startowego
+const STARTUP_PATTERN_KEY: &str = "startup_pattern";
+
+/// Klucz, pod którym zapisywana jest plansza do odzyskania po nieoczekiwanym zamknięciu
+const RECOVERY_KEY: &str = "recovery";
+
+/// Klucz, pod którym zapisywane jest włączenie automatycznego zapisu
+const AUTOSAVE_KEY: &str = "autosave";
+
+/// Odstęp między automatycznymi zapisami stanu aplikacji (w tym planszy do odzyskania)
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Minimalna szerokość panelu bocznego
+const MIN_SIDE_PANEL_WIDTH: f32 = 280.0;
+
+/// Minimalna szerokość obszaru planszy (ogranicza poszerzanie panelu bocznego)
+const MIN_BOARD_REGION_WIDTH: f32 = 200.0;
+
+/// Szerokość uchwytu rozdzielającego panel boczny i planszę
+const SPLITTER_WIDTH: f32 = 6.0;
+
+/// Układ okna zapamiętywany między uruchomieniami aplikacji
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct WindowLayout {
+    /// Rozmiar wnętrza okna (szerokość, wysokość)
+    window_size: (f32, f32),
+    /// Szerokość panelu bocznego ustawiona przez użytkownika (None - domyślna)
+    #[serde(default)]
+    side_panel_width: Option<f32>,
+}
+
+/// Plansza zapisywana automatycznie na wypadek nieoczekiwanego zamknięcia aplikacji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecoverySnapshot {
+    /// Plansza zapisana jako jednoklatkowe nagranie (wymiary, położenie i RLE)
+    board: String,
+    /// Pełna konfiguracja gry (reguła, tryb planszy, krawędzie, sąsiedztwo)
+    config: GameConfig,
+}
+
 /// Główna aplikacja gry w życie
 struct GameOfLifeApp {
+    /// Konfiguracja gry aktywnej zakładki (przekazywana do logiki i panelu ustawień)
+    config: GameConfig,
     /// Aktualna plansza gry
     board: Board,
     /// Początkowy stan planszy (do resetowania)
@@ -29,65 +99,200 @@ struct GameOfLifeApp {
     cell_state_manager: CellStateManager,
     /// Czas ostatniej aktualizacji
     last_update: Instant,
+    /// Czas ostatniego uruchomienia symulacji (do pomiaru faktycznej prędkości)
+    run_started: Instant,
+    /// Liczba generacji wykonanych od ostatniego uruchomienia symulacji
+    generations_since_start: u64,
+    /// Czasy generacji wykonanych w ostatniej sekundzie (najstarsze na początku)
+    generation_timestamps: VecDeque<Instant>,
+    /// Czy plansza nie zmieni się w następnej generacji (None - wymaga ponownego sprawdzenia)
+    board_stable: Option<bool>,
+    /// Czas ostatniego sprawdzenia stabilności planszy
+    last_stability_check: Instant,
     /// Przewidywanie następnego stanu (cache)
     current_prediction: Option<PredictionResult>,
+    /// Czas ostatniej edycji, po której przewidywanie trzeba policzyć od nowa
+    prediction_invalidated_at: Option<Instant>,
     /// Czy aplikacja była kiedykolwiek uruchomiona
     ever_started: bool,
     /// Manager odpowiedzialny za logikę resetowania
     reset_manager: ResetManager,
+    /// Poprzednie generacje aktywnej planszy (do cofania symulacji)
+    history: BoardHistory,
+    /// Detektor wymarcia, martwej natury i oscylatorów
+    state_detector: StateDetector,
+    /// Zakładki plansz (wpis aktywnej zakładki jest aktualizowany przy przełączaniu)
+    tabs: Vec<BoardTab>,
+    /// Indeks aktywnej zakładki
+    active_tab: usize,
+    /// Numer nadawany kolejnej nowej zakładce
+    next_tab_number: usize,
+    /// Czy krok symulacji obejmuje wszystkie zakładki
+    tab_step_mode: TabStepMode,
+    /// Pasek zakładek
+    tab_bar: TabBar,
+    /// Tekst oczekujący na skopiowanie do schowka w następnej klatce
+    pending_clipboard: Option<String>,
+    /// Liczba kroków "Step ×N" czekających, aż wskaźnik zajętości pojawi się na ekranie
+    pending_steps: Option<u32>,
+    /// Ostatni znany rozmiar wnętrza okna (zapisywany przy zamykaniu aplikacji)
+    window_size: (f32, f32),
+    /// Szerokość panelu bocznego ustawiona rozdzielaczem (None - plansza zajmuje pełną wysokość okna)
+    side_panel_width: Option<f32>,
+    /// Fragment planszy skopiowany z zaznaczenia (Ctrl+C / Ctrl+X)
+    clipboard: Option<BoardClipboard>,
+    /// Czy trwa przeciąganie zaznaczenia
+    is_selecting: bool,
+    /// Czy wyświetlane jest pytanie o potwierdzenie wyczyszczenia planszy resetem
+    reset_confirmation_open: bool,
+    /// Komórka wskazywana kursorem klawiatury (Shift + strzałki, Enter przełącza komórkę)
+    cursor: (usize, usize),
+    /// Czy kursor klawiatury był użyty - do tego czasu nie jest wyświetlany
+    cursor_active: bool,
+    /// Nagranie kolejnych generacji aktywnej planszy (zachowywane po zatrzymaniu nagrywania)
+    recording: Option<Recording>,
+    /// Czy kolejne generacje są dopisywane do nagrania
+    is_recording: bool,
+    /// Odtwarzane nagranie - generacje pochodzą z jego klatek zamiast z obliczeń
+    playback: Option<Playback>,
+    /// Statystyki kolejnych generacji od ostatniego resetu (eksport do CSV)
+    statistics: StatisticsLog,
+    /// Plansza z poprzedniej sesji czekająca na decyzję użytkownika o przywróceniu
+    pending_recovery: Option<RecoverySnapshot>,
+    /// Czy okno jest zamykane - zapis przy poprawnym zamknięciu usuwa planszę do odzyskania
+    closing: bool,
+    /// Bufor liczby sąsiadów używany ponownie przy obliczaniu kolejnych generacji
+    neighbor_counts: Vec<u8>,
 }
 
 impl Default for GameOfLifeApp {
     fn default() -> Self {
-        // Inicjalizujemy konfigurację
-        init_config();
-        
+        Self::new(GameConfig::default())
+    }
+}
+
+impl GameOfLifeApp {
+    /// Tworzy aplikację korzystającą z podanej konfiguracji
+    fn new(config: GameConfig) -> Self {
         // Tworzymy początkowy stan planszy
-        let initial_state = get_default_initial_state();
-        let initial_board = initial_state.create_board();
+        let initial_state = get_initial_state(&config);
+        let initial_board = initial_state.create_board(&config);
         let board = initial_board.clone();
         
         let mut side_panel = SidePanel::new();
-        side_panel.set_alive_cells_count(board.count_alive_cells());
+        side_panel.set_alive_cells_count(board.count_alive_cells(), board.total_cells());
+        
+        let first_tab = BoardTab::new("Board 1".to_string(), board.clone(), config.clone());
+        let window_size = config.ui_config.window_config.default_size;
         
         Self {
+            config,
             board,
             initial_board,
             renderer: GameRenderer::new(),
             side_panel,
             cell_state_manager: CellStateManager::new(),
             last_update: Instant::now(),
+            run_started: Instant::now(),
+            generations_since_start: 0,
+            generation_timestamps: VecDeque::new(),
+            board_stable: None,
+            last_stability_check: Instant::now(),
             current_prediction: None,
+            prediction_invalidated_at: None,
             ever_started: false,
             reset_manager: ResetManager::new(),
+            history: BoardHistory::new(),
+            state_detector: StateDetector::new(),
+            tabs: vec![first_tab],
+            active_tab: 0,
+            next_tab_number: 2,
+            tab_step_mode: TabStepMode::default(),
+            tab_bar: TabBar::new(),
+            pending_clipboard: None,
+            pending_steps: None,
+            window_size,
+            side_panel_width: None,
+            clipboard: None,
+            is_selecting: false,
+            reset_confirmation_open: false,
+            cursor: (0, 0),
+            cursor_active: false,
+            recording: None,
+            is_recording: false,
+            playback: None,
+            statistics: StatisticsLog::new(),
+            pending_recovery: None,
+            closing: false,
+            neighbor_counts: Vec::new(),
         }
     }
 }
 
 impl eframe::App for GameOfLifeApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Zapamiętujemy rozmiar okna, aby zapisać go przy zamykaniu aplikacji
+        if let Some(inner_rect) = ctx.input(|input| input.viewport().inner_rect) {
+            self.window_size = (inner_rect.width(), inner_rect.height());
+        }
+        if ctx.input(|input| input.viewport().close_requested()) {
+            self.closing = true;
+        }
+        
+        // Duża liczba kroków jest wykonywana dopiero po narysowaniu wskaźnika zajętości
+        if let Some(steps) = self.pending_steps {
+            if self.side_panel.busy_indicator_shown() {
+                self.pending_steps = None;
+                self.side_panel.set_pending_steps(None);
+                self.step_generations(steps);
+            }
+            ctx.request_repaint();
+        }
+        
         // Sprawdzamy czy należy wykonać następny krok symulacji
         if self.side_panel.simulation_state() == SimulationState::Running {
             let elapsed = self.last_update.elapsed();
             let target_duration = Duration::from_secs_f32(self.side_panel.time_between_generations());
             
-            if elapsed >= target_duration {
+            // Bez ograniczenia prędkości krok jest wykonywany w każdej klatce
+            // (zatrzymanie na stabilnej planszy nadal działa w next_generation)
+            if self.side_panel.uncapped_speed() || elapsed >= target_duration {
+                let generation_before = self.side_panel.generation_count();
                 self.next_generation();
                 self.last_update = Instant::now();
+                
+                if self.side_panel.generation_count() != generation_before {
+                    self.on_running_generation();
+                }
             }
+            self.update_actual_speed();
             
             // Żądamy ponownego renderowania dla płynnej animacji
             ctx.request_repaint();
         }
         
+        self.refresh_board_stability(ctx);
+        
+        // Skróty klawiszowe sterowania symulacją
+        self.handle_keyboard_shortcuts(ctx);
+        
+        // Pasek zakładek plansz
+        egui::TopBottomPanel::top("board_tabs").show(ctx, |ui| {
+            let tab_names: Vec<String> = self.tabs.iter().map(|tab| tab.name.clone()).collect();
+            let action = self.tab_bar.render(ui, &tab_names, self.active_tab, self.tab_step_mode);
+            self.handle_user_action(action);
+        });
+        
         // Główny layout aplikacji
         egui::CentralPanel::default().show(ctx, |ui| {
             // Pobieramy dostępny obszar
             let available_rect = ui.available_rect_before_wrap();
             
-            // Obliczamy rozmiar panelu bocznego (szerokość ekranu - wysokość ekranu)
-            let board_size = available_rect.height(); // Plansza jest kwadratem o boku równym wysokości
-            let side_panel_width = available_rect.width() - board_size;
+            // Szerokość panelu bocznego - domyślnie obszar planszy ma jej proporcje i pełną wysokość
+            let side_panel_width = self.clamped_side_panel_width(available_rect.width(), available_rect.height());
+            // Między panelem, rozdzielaczem i planszą są dwa odstępy układu poziomego
+            let spacing = 2.0 * ui.spacing().item_spacing.x;
+            let board_region_width = (available_rect.width() - side_panel_width - SPLITTER_WIDTH - spacing).max(0.0);
             
             ui.horizontal(|ui| {
                 // Panel boczny po lewej stronie
@@ -95,29 +300,63 @@ impl eframe::App for GameOfLifeApp {
                     egui::Vec2::new(side_panel_width, available_rect.height()),
                     egui::Layout::top_down(egui::Align::LEFT),
                     |ui| {
-                        let action = self.side_panel.render(ui);
+                        self.side_panel.set_can_step_back(!self.history.is_empty());
+                        self.side_panel.set_can_restore_start(self.ever_started && self.reset_manager.peek_pre_start_state().is_some());
+                        self.side_panel.set_next_reset_description(self.reset_manager.get_next_reset_description(self.ever_started));
+                        self.side_panel.set_board_tool(self.renderer.tool());
+                        self.side_panel.set_edit_mode(self.cell_state_manager.edit_mode());
+                        self.side_panel.set_symmetry_mode(self.cell_state_manager.symmetry_mode());
+                        self.side_panel.set_brush_size(self.cell_state_manager.brush_size());
+                        self.side_panel.set_board_size(self.board.width(), self.board.height());
+                        let action = self.side_panel.render(ui, &mut self.config);
                         self.handle_user_action(action);
                     }
                 );
                 
-                // Obszar renderowania planszy po prawej stronie
+                // Rozdzielacz - przeciąganie zmienia szerokość panelu bocznego
+                let (splitter_rect, splitter_response) = ui.allocate_exact_size(
+                    egui::Vec2::new(SPLITTER_WIDTH, available_rect.height()),
+                    egui::Sense::drag(),
+                );
+                if splitter_response.hovered() || splitter_response.dragged() {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeHorizontal);
+                }
+                if splitter_response.dragged() {
+                    self.side_panel_width = Some(side_panel_width + splitter_response.drag_delta().x);
+                }
+                let splitter_stroke = if splitter_response.hovered() || splitter_response.dragged() {
+                    ui.visuals().widgets.hovered.fg_stroke
+                } else {
+                    ui.visuals().widgets.noninteractive.bg_stroke
+                };
+                ui.painter().vline(splitter_rect.center().x, splitter_rect.y_range(), splitter_stroke);
+                
+                // Obszar renderowania planszy po prawej stronie (plansza jest w nim wyśrodkowana)
                 ui.allocate_ui_with_layout(
-                    egui::Vec2::new(board_size, available_rect.height()),
+                    egui::Vec2::new(board_region_width, available_rect.height()),
                     egui::Layout::top_down(egui::Align::LEFT),
                     |ui| {
                         let board_rect = ui.available_rect_before_wrap();
                         
                         // Aktualizujemy przewidywanie jeśli potrzeba
-                        self.update_prediction_if_needed();
+                        self.update_prediction_if_needed(ui.ctx());
                         
                         // Pobieramy wzór do podglądu jeśli jest wybrany
-                        let pattern_preview = if let Some(pattern_name) = self.side_panel.selected_pattern() {
-                            self.side_panel.get_pattern(pattern_name)
-                        } else {
-                            None
-                        };
+                        let pattern_preview = self.side_panel.placement_pattern();
                         
                         // Renderujemy planszę z podglądem
+                        self.renderer.set_smooth_zoom(self.side_panel.smooth_zoom());
+                        self.renderer.set_show_minimap(self.side_panel.show_minimap());
+                        self.renderer.set_age_colors(self.side_panel.age_colors());
+                        self.renderer.set_colors(&self.config.appearance, self.side_panel.styles());
+                        self.renderer.set_show_grid(self.side_panel.show_grid());
+                        let (grid_thickness, major_grid_interval) = self.side_panel.grid_style();
+                        self.renderer.set_grid_style(grid_thickness, major_grid_interval);
+                        self.renderer.set_wrap_pattern_preview(self.config.is_toroidal());
+                        self.renderer.set_clamp_pattern_preview(!self.config.is_toroidal() && !self.config.can_expand_in_current_mode());
+                        self.renderer.set_keyboard_cursor(self.visible_cursor());
+                        self.renderer.set_brush_size(self.active_brush_size());
+                        self.renderer.set_expansion_margin(self.expansion_margin_overlay());
                         let mouse_interaction = self.renderer.render_board_with_pattern_preview(
                             ui, 
                             &self.board, 
@@ -128,6 +367,10 @@ impl eframe::App for GameOfLifeApp {
                             pattern_preview
                         );
                         
+                        // Komórka pod kursorem jest wyświetlana w statystykach (w kolejnej klatce)
+                        let hovered = mouse_interaction.hovered_cell.zip(mouse_interaction.hovered_state);
+                        self.side_panel.set_hovered_cell(hovered);
+                        
                         // Obsługujemy interakcje myszy tylko gdy symulacja zatrzymana
                         if self.side_panel.simulation_state() == SimulationState::Stopped {
                             self.handle_mouse_interaction(mouse_interaction);
@@ -136,10 +379,116 @@ impl eframe::App for GameOfLifeApp {
                 );
             });
         });
+        
+        // Okno potwierdzenia resetu jest rysowane na wierzchu całego interfejsu
+        if self.reset_confirmation_open {
+            self.render_reset_confirmation(ctx);
+        }
+        if self.pending_recovery.is_some() {
+            self.render_recovery_prompt(ctx);
+        }
+        
+        // Przekazujemy do schowka tekst przygotowany przez akcje użytkownika
+        if let Some(text) = self.pending_clipboard.take() {
+            ctx.copy_text(text);
+        }
+    }
+    
+    /// Zapisuje układ okna, aby przywrócić go przy następnym uruchomieniu
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        let layout = WindowLayout {
+            window_size: self.window_size,
+            side_panel_width: self.side_panel_width,
+        };
+        eframe::set_value(storage, WINDOW_LAYOUT_KEY, &layout);
+        eframe::set_value(storage, STARTUP_PATTERN_KEY, &self.config.initial_pattern.name());
+        eframe::set_value(storage, AUTOSAVE_KEY, &self.config.ui_config.autosave);
+        
+        // Przy poprawnym zamknięciu okna plansza do odzyskania jest usuwana, więc po
+        // ponownym uruchomieniu pozostaje tylko po nieoczekiwanym zakończeniu aplikacji.
+        // Nierozstrzygnięta plansza z poprzedniej sesji nie jest nadpisywana.
+        let snapshot = if self.pending_recovery.is_some() {
+            self.pending_recovery.clone()
+        } else if self.closing || !self.config.ui_config.autosave {
+            None
+        } else if self.side_panel.simulation_state() != SimulationState::Stopped {
+            // Podczas symulacji zostaje ostatni zapis z zatrzymanej planszy
+            return;
+        } else {
+            self.recovery_snapshot()
+        };
+        eframe::set_value(storage, RECOVERY_KEY, &snapshot);
+    }
+    
+    /// Zapis wykonywany jest okresowo przez eframe (zapis plików odbywa się w tle)
+    fn auto_save_interval(&self) -> Duration {
+        AUTOSAVE_INTERVAL
     }
 }
 
 impl GameOfLifeApp {
+    /// Zwraca szerokość panelu bocznego ograniczoną do rozsądnego zakresu dla podanego obszaru
+    /// 
+    /// Domyślna szerokość wynika z proporcji planszy docelowej (z ustawień), a nie
+    /// aktualnej - plansza rosnąca w trybie Dynamic nie przesuwa wtedy panelu.
+    fn clamped_side_panel_width(&self, available_width: f32, available_height: f32) -> f32 {
+        let (board_width, board_height) = self.config.get_current_board_size();
+        let board_region_width = available_height * board_width as f32 / board_height as f32;
+        let preferred_width = self.side_panel_width.unwrap_or(available_width - board_region_width - SPLITTER_WIDTH);
+        let max_width = (available_width - SPLITTER_WIDTH - MIN_BOARD_REGION_WIDTH).max(MIN_SIDE_PANEL_WIDTH);
+        preferred_width.clamp(MIN_SIDE_PANEL_WIDTH, max_width)
+    }
+    
+    /// Wczytuje układ okna zapisany przy poprzednim uruchomieniu
+    fn load_window_layout(storage: Option<&dyn eframe::Storage>) -> Option<WindowLayout> {
+        storage.and_then(|storage| eframe::get_value(storage, WINDOW_LAYOUT_KEY))
+    }
+    
+    /// Zwraca pędzel, którego obrys jest rysowany pod kursorem
+    /// 
+    /// Pędzel działa tylko przy edycji zatrzymanej planszy trybem przełączania komórek.
+    fn active_brush_size(&self) -> BrushSize {
+        let editing = self.side_panel.simulation_state() == SimulationState::Stopped
+            && self.cell_state_manager.edit_mode() == EditMode::Toggle;
+        if editing {
+            self.cell_state_manager.brush_size()
+        } else {
+            BrushSize::Single
+        }
+    }
+    
+    /// Wczytuje ustawienie automatycznego zapisu z poprzedniego uruchomienia
+    fn load_autosave(storage: Option<&dyn eframe::Storage>) -> Option<bool> {
+        storage.and_then(|storage| eframe::get_value(storage, AUTOSAVE_KEY))
+    }
+    
+    /// Wczytuje planszę pozostawioną przez nieoczekiwanie zakończoną sesję
+    fn load_recovery(storage: Option<&dyn eframe::Storage>) -> Option<RecoverySnapshot> {
+        storage.and_then(|storage| eframe::get_value::<Option<RecoverySnapshot>>(storage, RECOVERY_KEY))
+            .flatten()
+    }
+    
+    /// Tworzy zapis aktualnej planszy i konfiguracji do odzyskania (None dla pustej planszy)
+    fn recovery_snapshot(&self) -> Option<RecoverySnapshot> {
+        if self.board.count_alive_cells() == 0 {
+            return None;
+        }
+        
+        let mut recording = Recording::new();
+        recording.push(&self.board, &self.config);
+        Some(RecoverySnapshot {
+            board: recording.to_text(),
+            config: self.config.clone(),
+        })
+    }
+    
+    /// Wczytuje wzór startowy wybrany przy poprzednim uruchomieniu
+    fn load_startup_pattern(storage: Option<&dyn eframe::Storage>) -> Option<InitialPattern> {
+        storage
+            .and_then(|storage| eframe::get_value::<String>(storage, STARTUP_PATTERN_KEY))
+            .and_then(|name| InitialPattern::from_name(&name))
+    }
+    
     /// Obsługuje akcje użytkownika z panelu bocznego
     fn handle_user_action(&mut self, action: UserAction) {
         match action {
@@ -151,43 +500,82 @@ impl GameOfLifeApp {
                 
                 self.side_panel.set_simulation_state(SimulationState::Running);
                 self.last_update = Instant::now();
+                self.run_started = self.last_update;
+                self.generations_since_start = 0;
+                self.generation_timestamps.clear();
+                self.side_panel.reset_boundary_loss_notice();
+                self.side_panel.set_actual_speed(None);
                 self.ever_started = true;
             }
             UserAction::Stop => {
                 self.side_panel.set_simulation_state(SimulationState::Stopped);
             }
             UserAction::Reset => {
-                self.reset_to_initial_state();
+                // Reset do pustej planszy usunąłby żywe komórki - najpierw pytamy użytkownika
+                if self.config.confirm_destructive_reset
+                    && self.reset_manager.next_reset_is_empty(self.ever_started)
+                    && !self.board.is_empty() {
+                    self.reset_confirmation_open = true;
+                } else {
+                    self.reset_to_initial_state();
+                }
+            }
+            UserAction::RestoreStartState => {
+                if self.ever_started && let Some(board) = self.reset_manager.peek_pre_start_state().cloned() {
+                    self.side_panel.set_simulation_state(SimulationState::Stopped);
+                    self.cell_state_manager.reset();
+                    self.start_from_board(board);
+                }
             }
             UserAction::Step => {
                 if self.side_panel.simulation_state() == SimulationState::Stopped {
                     self.next_generation();
                 }
             }
+            UserAction::StepMany(steps) => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped && self.pending_steps.is_none() {
+                    let steps = steps.min(MAX_STEPS_PER_CLICK);
+                    if steps >= BUSY_INDICATOR_STEPS {
+                        self.pending_steps = Some(steps);
+                        self.side_panel.set_pending_steps(Some(steps));
+                    } else {
+                        self.step_generations(steps);
+                    }
+                }
+            }
+            UserAction::StepBack => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    self.previous_generation();
+                }
+            }
             UserAction::EditCell(x, y) => {
                 // Edycja komórki jest dozwolona tylko gdy symulacja jest zatrzymana
                 if self.side_panel.simulation_state() == SimulationState::Stopped {
                     if self.cell_state_manager.handle_cell_click(&mut self.board, x, y) {
                         // Aktualizujemy liczbę żywych komórek po zmianie
-                        self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
-                        // Invalidujemy cache przewidywania po zmianie
-                        self.current_prediction = None;
+                        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+                        // Aktualizujemy przewidywanie wokół zmienionych komórek
+                        self.update_prediction_after_edit();
+                        // Historia nie odpowiada już edytowanej planszy
+                        self.clear_board_tracking();
                     }
                 }
             }
             UserAction::RulesChanged => {
                 // Zasady gry zostały zmienione - invalidujemy cache przewidywania
                 self.current_prediction = None;
+                self.board_stable = None;
             }
             UserAction::BoardSettingsChanged => {
                 // Ustawienia planszy zostały zmienione - invalidujemy cache przewidywania
                 // Nie zmieniamy rozmiaru planszy automatycznie - to powinno się dziać tylko
                 // przez explicit BoardSizeChanged lub Reset
                 self.current_prediction = None;
+                self.board_stable = None;
             }
-            UserAction::BoardSizeChanged(new_size) => {
+            UserAction::BoardSizeChanged(new_width, new_height) => {
                 // Zmieniono rozmiar planszy - musimy zmienić rozmiar aktualnej planszy
-                self.resize_board_to(new_size);
+                self.resize_board_to(new_width, new_height);
             }
             UserAction::RandomFill => {
                 // Generuj losową planszę - tylko gdy symulacja jest zatrzymana
@@ -206,33 +594,335 @@ impl GameOfLifeApp {
             UserAction::PlacePattern(pattern_name, x, y) => {
                 // Umieść wzór na planszy
                 if self.side_panel.simulation_state() == SimulationState::Stopped {
-                    self.place_pattern_on_board(&pattern_name, x, y);
+                    if let Some(pattern) = self.side_panel.get_pattern(&pattern_name).cloned() {
+                        self.place_pattern_on_board(&pattern, x, y);
+                    }
+                }
+            }
+            UserAction::GoToCell(x, y) => {
+                // Przenosimy widok na podaną komórkę, o ile leży na planszy
+                if self.renderer.center_on_cell(&self.board, x, y) {
+                    self.side_panel.set_goto_error(None);
+                } else {
+                    self.side_panel.set_goto_error(Some(format!(
+                        "({}, {}) is outside the {}×{} board",
+                        x, y, self.board.width(), self.board.height()
+                    )));
+                }
+            }
+            UserAction::ResetView => {
+                self.renderer.reset_view();
+            }
+            UserAction::SwitchTab(index) => {
+                self.switch_tab(index);
+            }
+            UserAction::NewTab => {
+                self.open_new_tab();
+            }
+            UserAction::CloseTab(index) => {
+                self.close_tab(index);
+            }
+            UserAction::DuplicateTab => {
+                self.duplicate_active_tab();
+            }
+            UserAction::SetTabStepMode(mode) => {
+                self.tab_step_mode = mode;
+            }
+            UserAction::SaveAsPattern(name) => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    match assets::Pattern::from_board(name, &self.board) {
+                        Some(pattern) => self.side_panel.add_pattern(pattern),
+                        None => self.side_panel.set_save_pattern_error("The board has no alive cells".to_string()),
+                    }
+                }
+            }
+            UserAction::CopyAsRle => {
+                self.pending_clipboard = Some(self.board.to_rle(&self.config));
+            }
+            UserAction::ApplyStartupPattern => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    let board = get_initial_state(&self.config).create_board(&self.config);
+                    self.start_from_board(board);
+                }
+            }
+            UserAction::PlacePatternCentered => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped
+                    && let Some(pattern) = self.side_panel.placement_pattern().cloned() {
+                    let mut board = self.board.clone();
+                    let error = (!board.place_centered(&pattern, &self.config))
+                        .then(|| format!("{} does not fit on the board and was clipped", pattern.name));
+                    self.side_panel.set_pattern_file_error(error);
+                    self.side_panel.set_selected_pattern(None);
+                    self.start_from_board(board);
+                }
+            }
+            UserAction::ExportStatistics => {
+                self.export_statistics_to_file();
+            }
+            UserAction::PasteBoard => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    self.paste_board_from_clipboard();
+                }
+            }
+            UserAction::CopyAsText => {
+                let header = format!(
+                    "# Generation {}, rule {}",
+                    self.side_panel.generation_count(),
+                    self.config.rule_string()
+                );
+                let grid = self.board.to_ascii('#', '.');
+                self.pending_clipboard = Some(if grid.is_empty() {
+                    format!("{} - the board is empty\n", header)
+                } else {
+                    format!("{}\n{}", header, grid)
+                });
+            }
+            UserAction::SaveBoardToFile(format) => {
+                self.save_board_to_file(format);
+            }
+            UserAction::AnalyzePattern => {
+                let max_period = self.config.detection_window;
+                let analysis = match self.board.analyze_period(max_period, &self.config) {
+                    Some(info) => info.description(),
+                    None if self.board.is_empty() => "Empty board".to_string(),
+                    None => format!("No period up to {}", max_period),
+                };
+                self.side_panel.set_pattern_analysis(Some(analysis));
+            }
+            UserAction::TrimBoard => {
+                // Zmiana rozmiaru planszy jest dozwolona tylko gdy symulacja jest zatrzymana
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    self.trim_board();
                 }
             }
+            UserAction::AddBoardLayers(layers) => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    self.add_board_layers(layers);
+                }
+            }
+            UserAction::SetBoardTool(tool) => {
+                self.set_board_tool(tool);
+            }
+            UserAction::SetEditMode(mode) => {
+                // Wypełnianie działa na zwykłej edycji komórek, nie na zaznaczeniu
+                self.set_board_tool(BoardTool::Draw);
+                self.cell_state_manager.set_edit_mode(mode);
+            }
+            UserAction::SetSymmetryMode(mode) => {
+                self.cell_state_manager.set_symmetry_mode(mode);
+            }
+            UserAction::SetBrushSize(size) => {
+                self.cell_state_manager.set_brush_size(size);
+            }
+            UserAction::InvertBoard => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    self.board.invert();
+                    self.on_board_edited();
+                }
+            }
+            UserAction::SetRecording(enabled) => {
+                self.set_recording(enabled);
+            }
+            UserAction::SaveRecording => {
+                self.save_recording_to_file();
+            }
+            UserAction::LoadRecording => {
+                if self.side_panel.simulation_state() == SimulationState::Stopped {
+                    self.load_recording_from_file();
+                }
+            }
+            UserAction::ExitPlayback => {
+                self.exit_playback();
+            }
             UserAction::None => {
                 // Brak akcji
             }
         }
     }
     
+    /// Obsługuje skróty klawiszowe: Spacja (Start/Stop), S lub → (Step),
+    /// R (Reset), +/- (prędkość symulacji), Shift + strzałki i Enter (kursor klawiatury)
+    /// oraz Ctrl + strzałki (przesunięcie całej planszy)
+    /// 
+    /// Skróty są ignorowane, gdy pole tekstowe ma fokus, aby wpisywanie
+    /// np. reguły nie sterowało symulacją.
+    fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
+        // Okna potwierdzenia resetu i przywracania planszy przejmują klawiaturę
+        if ctx.wants_keyboard_input() || self.reset_confirmation_open || self.pending_recovery.is_some() {
+            return;
+        }
+        
+        // Strzałki z Shiftem lub Ctrl przesuwają kursor klawiatury lub planszę, więc nie wykonują kroku
+        let (toggle, step, reset, faster, slower) = ctx.input(|i| (
+            i.key_pressed(egui::Key::Space),
+            i.key_pressed(egui::Key::S)
+                || (i.key_pressed(egui::Key::ArrowRight) && !i.modifiers.shift && !i.modifiers.command),
+            i.key_pressed(egui::Key::R),
+            i.key_pressed(egui::Key::Plus) || i.key_pressed(egui::Key::Equals),
+            i.key_pressed(egui::Key::Minus),
+        ));
+        
+        // Skróty schowka - egui zamienia Ctrl+C/X/V na osobne zdarzenia
+        let (copy, cut, paste, cancel) = ctx.input(|i| (
+            i.events.iter().any(|event| matches!(event, egui::Event::Copy))
+                || (i.modifiers.command && i.key_pressed(egui::Key::C)),
+            i.events.iter().any(|event| matches!(event, egui::Event::Cut))
+                || (i.modifiers.command && i.key_pressed(egui::Key::X)),
+            i.events.iter().any(|event| matches!(event, egui::Event::Paste(_)))
+                || (i.modifiers.command && i.key_pressed(egui::Key::V)),
+            i.key_pressed(egui::Key::Escape),
+        ));
+        
+        if self.side_panel.simulation_state() == SimulationState::Stopped {
+            if copy || cut {
+                self.copy_selection(cut);
+            }
+            if paste && self.clipboard.is_some() {
+                self.set_board_tool(BoardTool::Paste);
+            }
+            self.handle_cursor_keys(ctx);
+            self.handle_shift_keys(ctx);
+        }
+        
+        // Escape najpierw anuluje wybór wzoru, a dopiero potem zaznaczenie
+        if cancel {
+            if self.side_panel.placement_pattern().is_some() {
+                self.side_panel.set_selected_pattern(None);
+            } else {
+                self.cancel_selection();
+            }
+        }
+        
+        if toggle {
+            let action = match self.side_panel.simulation_state() {
+                SimulationState::Stopped => UserAction::Start,
+                SimulationState::Running => UserAction::Stop,
+            };
+            self.handle_user_action(action);
+        }
+        
+        if step {
+            self.handle_user_action(UserAction::Step);
+        }
+        
+        // Podczas umieszczania wzoru klawisz R obraca wzór zamiast resetować planszę
+        if reset && self.side_panel.placement_pattern().is_none() {
+            self.handle_user_action(UserAction::Reset);
+        }
+        
+        if faster || slower {
+            let step = self.config.ui_config.simulation_speed_step;
+            let delta = if faster { step } else { -step };
+            self.side_panel.set_simulation_speed(self.side_panel.simulation_speed() + delta, &self.config);
+        }
+    }
+    
+    /// Przesuwa kursor klawiatury (Shift + strzałki) i przełącza wskazaną komórkę (Enter)
+    /// 
+    /// Kursor nie wychodzi poza planszę - także gdy plansza zmniejszyła się od ostatniego ruchu.
+    fn handle_cursor_keys(&mut self, ctx: &egui::Context) {
+        let (dx, dy, toggle_cell) = ctx.input(|i| {
+            let pressed = |key| i.modifiers.shift && i.key_pressed(key);
+            let dx = pressed(egui::Key::ArrowRight) as isize - pressed(egui::Key::ArrowLeft) as isize;
+            let dy = pressed(egui::Key::ArrowDown) as isize - pressed(egui::Key::ArrowUp) as isize;
+            (dx, dy, i.key_pressed(egui::Key::Enter))
+        });
+        
+        if dx != 0 || dy != 0 {
+            let (x, y) = self.clamped_cursor();
+            let max_x = self.board.width().saturating_sub(1);
+            let max_y = self.board.height().saturating_sub(1);
+            self.cursor = (
+                x.saturating_add_signed(dx).min(max_x),
+                y.saturating_add_signed(dy).min(max_y),
+            );
+            self.cursor_active = true;
+        }
+        
+        if toggle_cell && self.cursor_active {
+            let (x, y) = self.clamped_cursor();
+            self.cursor = (x, y);
+            self.handle_user_action(UserAction::EditCell(x, y));
+        }
+    }
+    
+    /// Przesuwa całą planszę o jedną komórkę (Ctrl + strzałki)
+    /// 
+    /// Na planszy toroidalnej komórki przechodzą przez krawędź, w pozostałych
+    /// trybach komórki wysunięte poza planszę są usuwane.
+    fn handle_shift_keys(&mut self, ctx: &egui::Context) {
+        let (dx, dy) = ctx.input(|i| {
+            let pressed = |key| i.modifiers.command && !i.modifiers.shift && i.key_pressed(key);
+            (
+                pressed(egui::Key::ArrowRight) as i32 - pressed(egui::Key::ArrowLeft) as i32,
+                pressed(egui::Key::ArrowDown) as i32 - pressed(egui::Key::ArrowUp) as i32,
+            )
+        });
+        
+        if dx != 0 || dy != 0 {
+            self.board.shift(dx, dy, self.config.is_toroidal());
+            self.on_board_edited();
+        }
+    }
+    
+    /// Zwraca pozycję kursora klawiatury ograniczoną do wymiarów planszy
+    fn clamped_cursor(&self) -> (usize, usize) {
+        (
+            self.cursor.0.min(self.board.width().saturating_sub(1)),
+            self.cursor.1.min(self.board.height().saturating_sub(1)),
+        )
+    }
+    
+    /// Zwraca komórkę kursora klawiatury do wyświetlenia (tylko po jego użyciu i gdy symulacja stoi)
+    fn visible_cursor(&self) -> Option<(usize, usize)> {
+        (self.cursor_active && self.side_panel.simulation_state() == SimulationState::Stopped)
+            .then(|| self.clamped_cursor())
+    }
+    
     /// Obsługuje interakcje myszy z planszą
     fn handle_mouse_interaction(&mut self, interaction: MouseInteraction) {
         let mut board_changed = false;
         
         // Sprawdzamy czy mamy wybrany wzór do umieszczenia
-        if let Some(pattern_name) = self.side_panel.selected_pattern().cloned() {
-            // Tryb umieszczania wzoru
+        if let Some(pattern) = self.side_panel.placement_pattern().cloned() {
+            // Tryb umieszczania wzoru - klawisze R/F zmieniają orientację wzoru
+            if interaction.rotate_pattern_pressed {
+                self.side_panel.rotate_placement_pattern();
+            }
+            if interaction.flip_pattern_pressed {
+                self.side_panel.mirror_placement_pattern(true);
+            }
+            
             if let Some((x, y)) = interaction.clicked_cell {
                 // Kliknięto - umieść wzór
-                self.place_pattern_on_board(&pattern_name, x, y);
+                self.place_pattern_on_board(&pattern, x, y);
                 // Anuluj wybór wzoru po umieszczeniu
                 self.side_panel.set_selected_pattern(None);
                 return; // Nie obsługujemy normalnej edycji komórek
             }
+            if interaction.clicked_outside_board {
+                // Kliknięcie obok planszy anuluje wybór wzoru
+                self.side_panel.set_selected_pattern(None);
+            }
             // W trybie umieszczania wzoru nie obsługujemy normalnej edycji
             return;
         }
         
+        // Zaznaczanie i wklejanie zastępują edycję pojedynczych komórek
+        match self.renderer.tool() {
+            BoardTool::Select => {
+                self.handle_selection_drag(&interaction);
+                return;
+            }
+            BoardTool::Paste => {
+                if let Some((x, y)) = interaction.clicked_cell {
+                    self.paste_clipboard_at(x, y);
+                }
+                return;
+            }
+            BoardTool::Draw => {}
+        }
+        
         // Normalna obsługa edycji komórek (gdy nie ma wybranego wzoru)
         // Obsługa kliknięcia (bez przeciągania)
         if let Some((x, y)) = interaction.clicked_cell {
@@ -264,20 +954,529 @@ impl GameOfLifeApp {
         
         // Aktualizujemy liczbę żywych komórek jeśli plansza się zmieniła
         if board_changed {
-            self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
-            // Invalidujemy cache przewidywania po zmianie planszy
-            self.current_prediction = None;
+            self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+            // Aktualizujemy przewidywanie wokół zmienionych komórek
+            self.update_prediction_after_edit();
+            // Historia nie odpowiada już edytowanej planszy
+            self.clear_board_tracking();
+        }
+    }
+    
+    /// Zmienia narzędzie interakcji z planszą
+    /// 
+    /// Tryb wklejania wymaga skopiowanego fragmentu, a powrót do rysowania
+    /// usuwa zaznaczenie.
+    fn set_board_tool(&mut self, tool: BoardTool) {
+        if tool == BoardTool::Paste && self.clipboard.is_none() {
+            return;
+        }
+        
+        self.is_selecting = false;
+        self.cell_state_manager.end_drag();
+        self.renderer.set_tool(tool);
+        
+        let paste_preview = if tool == BoardTool::Paste { self.clipboard.clone() } else { None };
+        self.renderer.set_paste_preview(paste_preview);
+        if tool == BoardTool::Draw {
+            self.renderer.set_selection(None);
+        }
+    }
+    
+    /// Obsługuje przeciąganie prostokąta zaznaczenia
+    fn handle_selection_drag(&mut self, interaction: &MouseInteraction) {
+        if interaction.mouse_pressed && let Some(cell) = interaction.hovered_cell {
+            self.renderer.set_selection(Some(Selection::new(cell)));
+            self.is_selecting = true;
+        }
+        
+        // Poza planszą zaznaczenie zatrzymuje się na ostatniej komórce
+        if interaction.is_mouse_down
+            && self.is_selecting
+            && let (Some(mut selection), Some(cell)) = (self.renderer.selection(), interaction.hovered_cell)
+        {
+            selection.extend_to(cell);
+            self.renderer.set_selection(Some(selection));
+        }
+        
+        if interaction.mouse_released {
+            self.is_selecting = false;
+        }
+    }
+    
+    /// Zapisuje żywe komórki planszy do pliku wybranego w oknie dialogowym
+    fn save_board_to_file(&mut self, format: PatternFormat) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter(format!("{} pattern", format.name()), &[format.extension()])
+            .set_file_name(format!("board.{}", format.extension()))
+            .save_file()
+        else {
+            return;
+        };
+        
+        let error = std::fs::write(&path, format.export(&self.board, &self.config))
+            .err()
+            .map(|error| format!("Cannot write file: {}", error));
+        self.side_panel.set_pattern_file_error(error);
+    }
+    
+    /// Zastępuje planszę siatką znaków odczytaną ze schowka systemowego
+    /// 
+    /// egui udostępnia wklejany tekst tylko po Ctrl+V, dlatego przycisk
+    /// odczytuje schowek bezpośrednio.
+    fn paste_board_from_clipboard(&mut self) {
+        let text = match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+            Ok(text) => text,
+            Err(error) => {
+                self.side_panel.set_board_io_error(Some(format!("Cannot read the clipboard: {}", error)));
+                return;
+            }
+        };
+        
+        match Board::from_ascii(&text) {
+            Ok(board) => {
+                self.side_panel.set_board_io_error(None);
+                self.start_from_board(board);
+            }
+            Err(error) => {
+                self.side_panel.set_board_io_error(Some(format!("Cannot paste the board: {}", error)));
+            }
+        }
+    }
+    
+    /// Zastępuje planszę nową, która staje się stanem początkowym (licznik generacji od zera)
+    fn start_from_board(&mut self, board: Board) {
+        self.board = board;
+        self.initial_board = self.board.clone();
+        self.clear_board_tracking();
+        self.side_panel.reset_generation_count();
+        self.statistics.clear();
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+        self.current_prediction = None;
+    }
+    
+    /// Zapisuje statystyki kolejnych generacji do pliku CSV wybranego w oknie dialogowym
+    fn export_statistics_to_file(&mut self) {
+        if self.statistics.is_empty() {
+            self.side_panel.set_board_io_error(Some("No generations have been computed since the last reset".to_string()));
+            return;
+        }
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name("statistics.csv")
+            .save_file()
+        else {
+            return;
+        };
+        
+        let error = std::fs::write(&path, self.statistics.to_csv())
+            .err()
+            .map(|error| format!("Cannot write file: {}", error));
+        self.side_panel.set_board_io_error(error);
+    }
+    
+    /// Włącza lub wyłącza nagrywanie generacji
+    /// 
+    /// Włączenie rozpoczyna nowe nagranie od aktualnej planszy, a wyłączenie
+    /// zachowuje nagrane klatki do zapisania.
+    fn set_recording(&mut self, enabled: bool) {
+        if enabled && !self.is_recording {
+            self.recording = Some(Recording::new());
+            self.is_recording = true;
+            self.side_panel.set_recording_message(None);
+            self.record_current_board();
+        } else if !enabled {
+            self.is_recording = false;
+        }
+        self.sync_recording_status();
+    }
+    
+    /// Dopisuje aktualną planszę do nagrania (jeśli nagrywanie jest włączone)
+    fn record_current_board(&mut self) {
+        if !self.is_recording {
+            return;
+        }
+        
+        if let Some(recording) = self.recording.as_mut()
+            && !recording.push(&self.board, &self.config) {
+            // Po osiągnięciu limitu pamięci nagrywanie jest zatrzymywane
+            self.is_recording = false;
+        }
+        self.sync_recording_status();
+    }
+    
+    /// Przekazuje stan nagrywania i odtwarzania do panelu bocznego
+    fn sync_recording_status(&mut self) {
+        let info = self.recording.as_ref().map(|recording| (recording.len(), recording.size_bytes()));
+        let warning = self.recording.as_ref().and_then(|recording| {
+            if recording.is_truncated() {
+                Some(format!("Recording stopped at the {} MB limit", MAX_RECORDING_BYTES / (1024 * 1024)))
+            } else if recording.is_large() {
+                Some(format!("Recording is larger than {} MB", RECORDING_WARNING_BYTES / (1024 * 1024)))
+            } else {
+                None
+            }
+        });
+        self.side_panel.set_recording_state(self.is_recording, info, warning);
+        self.side_panel.set_playback_position(
+            self.playback.as_ref().map(|playback| (playback.current_frame(), playback.frame_count()))
+        );
+    }
+    
+    /// Zapisuje nagranie do pliku wybranego w oknie dialogowym
+    fn save_recording_to_file(&mut self) {
+        let Some(recording) = self.recording.as_ref().filter(|recording| !recording.is_empty()) else {
+            return;
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Game of Life recording", &["golrec"])
+            .set_file_name("recording.golrec")
+            .save_file()
+        else {
+            return;
+        };
+        
+        let message = match std::fs::write(&path, recording.to_text()) {
+            Ok(()) => (format!("Saved {} frames", recording.len()), false),
+            Err(error) => (format!("Cannot write file: {}", error), true),
+        };
+        self.side_panel.set_recording_message(Some(message));
+    }
+    
+    /// Wczytuje nagranie z pliku i rozpoczyna jego odtwarzanie od pierwszej klatki
+    fn load_recording_from_file(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Game of Life recording", &["golrec"])
+            .pick_file()
+        else {
+            return;
+        };
+        
+        let loaded = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Cannot read file: {}", error))
+            .and_then(|text| Recording::from_text(&text).map_err(|error| format!("Invalid recording: {}", error)));
+        let recording = match loaded {
+            Ok(recording) => recording,
+            Err(error) => {
+                self.side_panel.set_recording_message(Some((error, true)));
+                return;
+            }
+        };
+        
+        let playback = Playback::new(recording);
+        let Some(first_board) = playback.current_board() else {
+            return;
+        };
+        
+        // Odtwarzanie zaczyna się od nowa - jak po wczytaniu nowej planszy
+        self.is_recording = false;
+        self.board = first_board;
+        self.initial_board = self.board.clone();
+        self.clear_board_tracking();
+        self.side_panel.reset_generation_count();
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+        self.current_prediction = None;
+        self.side_panel.set_recording_message(Some((format!("Loaded {} frames", playback.frame_count()), false)));
+        self.playback = Some(playback);
+        self.sync_recording_status();
+    }
+    
+    /// Kończy odtwarzanie nagrania (plansza zostaje na aktualnej klatce)
+    fn exit_playback(&mut self) {
+        if self.playback.take().is_some() {
+            self.sync_recording_status();
         }
     }
     
+    /// Kopiuje zaznaczony obszar do schowka (przy wycinaniu także go czyści)
+    /// i przechodzi do trybu wklejania
+    fn copy_selection(&mut self, cut: bool) {
+        let Some(selection) = self.renderer.selection() else {
+            return;
+        };
+        
+        self.clipboard = Some(BoardClipboard::from_selection(&self.board, &selection));
+        
+        if cut && self.cell_state_manager.clear_selection(&mut self.board, &selection) {
+            self.on_board_edited();
+        }
+        
+        self.set_board_tool(BoardTool::Paste);
+    }
+    
+    /// Wkleja skopiowany fragment z lewym górnym rogiem w klikniętej komórce
+    /// (fragment jest przesuwany, aby zmieścił się na planszy)
+    fn paste_clipboard_at(&mut self, x: usize, y: usize) {
+        let Some(clipboard) = self.clipboard.clone() else {
+            return;
+        };
+        
+        if self.cell_state_manager.paste_clipboard(&mut self.board, &clipboard, x, y) {
+            self.on_board_edited();
+        }
+        
+        // Wklejony obszar zostaje zaznaczony, aby można go było od razu skopiować ponownie
+        let (origin_x, origin_y) = clipboard.clamped_origin(&self.board, x, y);
+        let mut selection = Selection::new((origin_x, origin_y));
+        selection.extend_to((
+            (origin_x + clipboard.width()).min(self.board.width()).saturating_sub(1),
+            (origin_y + clipboard.height()).min(self.board.height()).saturating_sub(1),
+        ));
+        self.set_board_tool(BoardTool::Select);
+        self.renderer.set_selection(Some(selection));
+    }
+    
+    /// Anuluje zaznaczenie (Escape) - w trybie wklejania wraca do zaznaczania
+    fn cancel_selection(&mut self) {
+        match self.renderer.tool() {
+            BoardTool::Paste => self.set_board_tool(BoardTool::Select),
+            BoardTool::Select => {
+                self.is_selecting = false;
+                self.renderer.set_selection(None);
+            }
+            BoardTool::Draw => {}
+        }
+    }
+    
+    /// Aktualizuje statystyki i cache po ręcznej zmianie planszy
+    fn on_board_edited(&mut self) {
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+        self.current_prediction = None;
+        self.prediction_invalidated_at = Some(Instant::now());
+        self.clear_board_tracking();
+    }
+    
+    /// Aktualizuje przyspieszanie i pomiar prędkości po generacji uruchomionej symulacji
+    fn on_running_generation(&mut self) {
+        self.generations_since_start += 1;
+        
+        if let Some(interval) = self.side_panel.speed_ramp_interval()
+            && !self.side_panel.uncapped_speed()
+            && self.generations_since_start.is_multiple_of(interval) {
+            let speed = self.side_panel.simulation_speed() + self.config.ui_config.simulation_speed_step;
+            self.side_panel.set_simulation_speed(speed, &self.config);
+        }
+        
+        self.generation_timestamps.push_back(Instant::now());
+    }
+    
+    /// Przelicza prędkość osiągniętą w ostatniej sekundzie na podstawie czasów generacji
+    fn update_actual_speed(&mut self) {
+        let now = Instant::now();
+        while self.generation_timestamps.front()
+            .is_some_and(|&timestamp| now.duration_since(timestamp) > SPEED_MEASUREMENT_WINDOW) {
+            self.generation_timestamps.pop_front();
+        }
+        
+        // Tuż po uruchomieniu okno pomiaru jest krótsze niż sekunda
+        let window = self.run_started.elapsed().min(SPEED_MEASUREMENT_WINDOW).as_secs_f32();
+        if window > 0.0 {
+            self.side_panel.set_actual_speed(Some(self.generation_timestamps.len() as f32 / window));
+        }
+    }
+    
+    /// Zwraca pas rozszerzania planszy do narysowania: szerokość i czy plansza osiągnęła maksymalny rozmiar
+    /// 
+    /// Pas jest rysowany tylko w trybie Dynamic na planszy z krawędziami.
+    fn expansion_margin_overlay(&self) -> Option<(usize, bool)> {
+        if !self.config.appearance.show_expansion_margin
+            || !self.config.can_expand_in_current_mode()
+            || self.config.is_toroidal() {
+            return None;
+        }
+        
+        let at_limit = self.board.width() >= self.config.max_board_size && self.board.height() >= self.config.max_board_size;
+        Some((self.config.expansion_margin, at_limit))
+    }
+    
+    /// Zapisuje stabilność aktywnej planszy i przekazuje ją do panelu statystyk
+    /// 
+    /// Przy regułach probabilistycznych stabilności nie da się określić.
+    fn set_board_stable(&mut self, stable: Option<bool>) {
+        let stable = stable.filter(|_| !self.config.is_stochastic());
+        self.board_stable = stable;
+        self.side_panel.set_board_stable(stable);
+    }
+    
+    /// Sprawdza stabilność planszy po zmianie spoza symulacji (nie częściej niż co `STABILITY_CHECK_INTERVAL`)
+    /// 
+    /// W trakcie symulacji stabilność wynika z porównania kolejnych generacji.
+    fn refresh_board_stability(&mut self, ctx: &egui::Context) {
+        if self.board_stable.is_some() {
+            return;
+        }
+        if self.config.is_stochastic() {
+            self.side_panel.set_board_stable(None);
+            return;
+        }
+        if let Some(remaining) = STABILITY_CHECK_INTERVAL.checked_sub(self.last_stability_check.elapsed()) {
+            ctx.request_repaint_after(remaining);
+            return;
+        }
+        
+        self.last_stability_check = Instant::now();
+        let stable = self.board.is_stable(&self.config);
+        self.set_board_stable(Some(stable));
+    }
+    
     /// Wykonuje następną generację gry
     fn next_generation(&mut self) {
-        self.board = self.board.next_generation();
+        self.step_generations(1);
+    }
+    
+    /// Wykonuje podaną liczbę generacji bez odświeżania widoku pomiędzy nimi
+    /// 
+    /// Przerywa wcześniej, jeśli symulacja powinna się zatrzymać (np. plansza się ustabilizowała).
+    fn step_generations(&mut self, steps: u32) {
+        for _ in 0..steps {
+            if !self.advance_one_generation() {
+                break;
+            }
+        }
+        
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+        
+        // Invalidujemy cache przewidywania po zmianie stanu
+        self.current_prediction = None;
+    }
+    
+    /// Przechodzi do następnej generacji aktywnej planszy (i pozostałych zakładek we wspólnym trybie)
+    /// 
+    /// Zwraca false, jeśli symulacja powinna się na tej generacji zatrzymać.
+    fn advance_one_generation(&mut self) -> bool {
+        // Pierwszy wiersz statystyk opisuje planszę, od której zaczęła się symulacja
+        if self.statistics.is_empty() {
+            self.statistics.record(self.side_panel.generation_count(), &self.board, 0, 0);
+        }
+        let mut changes = (0, 0);
+        
+        // Podczas odtwarzania następna generacja pochodzi z nagrania
+        let next_board = if let Some(playback) = self.playback.as_mut() {
+            let Some(frame_board) = playback.next_board() else {
+                self.side_panel.set_simulation_state(SimulationState::Stopped);
+                self.side_panel.set_recording_message(Some(("End of recording".to_string(), false)));
+                return false;
+            };
+            self.history.push(&self.board, &self.config);
+            // Przy zmianie wymiarów planszy nie da się porównać komórek
+            if (frame_board.width(), frame_board.height()) == (self.board.width(), self.board.height()) {
+                changes = self.board.count_changes(&frame_board);
+                self.side_panel.record_generation_changes(changes.0, changes.1);
+            } else {
+                self.side_panel.forget_last_generation_changes();
+            }
+            self.sync_recording_status();
+            frame_board
+        } else {
+            self.history.push(&self.board, &self.config);
+            // Komórki rodzące się poza planszą z krawędziami są tracone - informujemy o tym
+            let clipped_births = self.board.count_clipped_births(&self.config);
+            if clipped_births > 0 {
+                self.side_panel.report_boundary_loss(clipped_births);
+            }
+            let stepped_board = self.board.next_generation_with_buffer(&self.config, &mut self.neighbor_counts);
+            // Narodziny i śmierci liczymy przed zmianą wymiarów planszy
+            changes = self.board.count_changes(&stepped_board);
+            self.side_panel.record_generation_changes(changes.0, changes.1);
+            Self::fit_board_size(stepped_board, &self.config)
+        };
+        // Porównujemy tylko z poprzednią generacją - oscylatory nie są uznawane za stabilne
+        // (przy regułach probabilistycznych plansza może się jeszcze zmienić, więc nie sprawdzamy)
+        let is_stable = !self.config.is_stochastic() && next_board == self.board;
+        self.board = next_board;
+        self.set_board_stable(Some(is_stable));
         self.side_panel.increment_generation();
-        self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
+        self.statistics.record(self.side_panel.generation_count(), &self.board, changes.0, changes.1);
+        self.record_current_board();
+        let mut keep_going = true;
+        
+        // Sprawdzamy czy symulacja osiągnęła stan ustalony (niemożliwe do stwierdzenia przy regułach probabilistycznych)
+        let settled = if self.config.is_stochastic() {
+            None
+        } else {
+            self.state_detector.observe(&self.board, self.side_panel.generation_count(), &self.config)
+        };
+        self.side_panel.set_settled_state(settled);
+        if settled.is_some() && self.side_panel.stop_when_settled() {
+            self.side_panel.set_simulation_state(SimulationState::Stopped);
+            keep_going = false;
+        }
+        if is_stable && self.config.pause_on_stable {
+            self.side_panel.stop_stable();
+            keep_going = false;
+        }
+        // Limit generacji zatrzymuje tylko uruchomioną symulację - po jego przekroczeniu
+        // (np. po ręcznych krokach) symulacja może działać dalej
+        if self.side_panel.simulation_state() == SimulationState::Running
+            && self.side_panel.stop_at_generation() == Some(self.side_panel.generation_count()) {
+            self.side_panel.stop_at_generation_reached();
+            keep_going = false;
+        }
+        // Limit populacji sprawdzamy po ewentualnym powiększeniu planszy
+        if let Some(limit) = self.side_panel.stop_above_population()
+            && self.board.count_alive_cells() > limit {
+            self.side_panel.stop_population_limit_reached();
+            keep_going = false;
+        }
+        
+        // W trybie wspólnego kroku pozostałe zakładki również przechodzą do następnej generacji
+        if self.tab_step_mode == TabStepMode::AllTogether {
+            self.step_inactive_tabs();
+        }
+        
+        keep_going
+    }
+    
+    /// Przywraca poprzednią generację z historii
+    fn previous_generation(&mut self) {
+        if let Some(previous_board) = self.history.pop() {
+            self.board = previous_board;
+            if let Some(playback) = self.playback.as_mut() {
+                playback.step_back();
+                self.sync_recording_status();
+            }
+            self.side_panel.decrement_generation();
+            self.statistics.truncate_after(self.side_panel.generation_count());
+            self.side_panel.forget_last_generation_changes();
+            self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+            self.board_stable = None;
+            
+            // Po cofnięciu wykrywanie stanu ustalonego zaczyna się od nowa
+            self.state_detector.clear();
+            self.side_panel.set_settled_state(None);
+            
+            // Invalidujemy cache przewidywania po zmianie stanu
+            self.current_prediction = None;
+        }
+    }
+    
+    /// Czyści historię i wykrywanie stanu ustalonego po zmianie planszy spoza symulacji
+    /// 
+    /// Odtwarzanie nagrania jest przerywane, bo plansza nie odpowiada już jego klatkom.
+    fn clear_board_tracking(&mut self) {
+        self.board_stable = None;
+        self.exit_playback();
+        self.history.clear();
+        self.state_detector.clear();
+        self.side_panel.set_settled_state(None);
+    }
+    
+    /// Oblicza następną generację planszy wraz z zarządzaniem jej rozmiarem
+    /// 
+    /// Reguły i tryb planszy pochodzą z podanej konfiguracji (aktywnej lub zakładki).
+    fn advance_board(board: &Board, config: &GameConfig) -> Board {
+        Self::fit_board_size(board.next_generation(config), config)
+    }
+    
+    /// Rozszerza lub przycina planszę następnej generacji zgodnie z trybem planszy
+    fn fit_board_size(next_board: Board, config: &GameConfig) -> Board {
+        let mut next_board = next_board;
         
         // Zarządzanie rozmiarem planszy w zależności od trybu
-        let config = config::get_config();
+        
+        // Plansza toroidalna ma stały rozmiar - zmiana wymiarów zmieniłaby jej topologię
+        if config.is_toroidal() {
+            return next_board;
+        }
         
         match config.board_size_mode {
             config::BoardSizeMode::Static => {
@@ -288,18 +1487,18 @@ impl GameOfLifeApp {
                 // W trybie Dynamic zarządzamy rozmiarem automatycznie
                 
                 // Najpierw sprawdzamy czy plansza potrzebuje rozszerzenia
-                if let Some(expanded_board) = self.board.auto_expand_if_needed(config.expansion_margin) {
-                    self.board = expanded_board;
+                if let Some((expanded_board, _)) = next_board.auto_expand_if_needed(config.expansion_margin, config) {
+                    next_board = expanded_board;
                 } else {
                     // Jeśli nie rozszerzaliśmy, sprawdzamy czy można zoptymalizować rozmiar
                     // Optymalizujemy tylko jeśli plansza nie jest zbyt mała
-                    if self.board.width() > config.optimization_margin * 4 && 
-                       self.board.height() > config.optimization_margin * 4 {
-                        if let Some(optimized_board) = self.board.optimize_size(config.optimization_margin) {
+                    if next_board.width() > config.optimization_margin * 4 && 
+                       next_board.height() > config.optimization_margin * 4 {
+                        if let Some(optimized_board) = next_board.optimize_size(config.optimization_margin) {
                             // Sprawdzamy czy optymalizacja rzeczywiście zmniejszyła planszę
-                            if optimized_board.width() < self.board.width() || 
-                               optimized_board.height() < self.board.height() {
-                                self.board = optimized_board;
+                            if optimized_board.width() < next_board.width() || 
+                               optimized_board.height() < next_board.height() {
+                                next_board = optimized_board;
                             }
                         }
                     }
@@ -307,19 +1506,201 @@ impl GameOfLifeApp {
             }
         }
         
-        // Invalidujemy cache przewidywania po zmianie stanu
+        next_board
+    }
+    
+    /// Wykonuje krok symulacji we wszystkich nieaktywnych zakładkach
+    /// 
+    /// Każda zakładka liczy generację według własnej konfiguracji.
+    fn step_inactive_tabs(&mut self) {
+        for (index, tab) in self.tabs.iter_mut().enumerate() {
+            if index == self.active_tab {
+                continue;
+            }
+            
+            tab.history.push(&tab.board, &tab.config);
+            tab.board = Self::advance_board(&tab.board, &tab.config);
+            tab.generation_count += 1;
+            
+            // Pierwszy krok zakładki zapamiętuje jej stan przed uruchomieniem
+            if !tab.ever_started {
+                tab.reset_manager.save_pre_start_state(&tab.initial_board);
+                tab.ever_started = true;
+            }
+        }
+    }
+    
+    /// Zapisuje stan aktywnej planszy do jej wpisu na liście zakładek
+    fn store_active_tab(&mut self) {
+        let tab = &mut self.tabs[self.active_tab];
+        tab.board = self.board.clone();
+        tab.initial_board = self.initial_board.clone();
+        tab.generation_count = self.side_panel.generation_count();
+        tab.config = self.config.clone();
+        tab.reset_manager = self.reset_manager.clone();
+        tab.ever_started = self.ever_started;
+        tab.history = self.history.clone();
+    }
+    
+    /// Wczytuje stan zakładki o podanym indeksie jako aktywną planszę
+    fn load_tab(&mut self, index: usize) {
+        let tab = self.tabs[index].clone();
+        self.active_tab = index;
+        
+        // Nagranie dotyczy jednej planszy - po zmianie zakładki kończymy je,
+        // zachowując zebrane klatki do zapisania
+        self.is_recording = false;
+        self.playback = None;
+        self.sync_recording_status();
+        self.board_stable = None;
+        self.board = tab.board;
+        self.initial_board = tab.initial_board;
+        self.reset_manager = tab.reset_manager;
+        self.ever_started = tab.ever_started;
+        self.history = tab.history;
+        self.state_detector.clear();
+        self.side_panel.set_settled_state(None);
+        self.config = tab.config;
+        
+        // Odświeżamy panel boczny i stan edycji
+        self.side_panel.set_generation_count(tab.generation_count);
+        self.side_panel.clear_generation_changes();
+        self.statistics.clear();
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+        self.side_panel.sync_settings_with_config(&self.config);
+        self.side_panel.set_selected_pattern(None);
+        self.cell_state_manager.reset();
+        self.renderer.reset_view();
         self.current_prediction = None;
     }
     
+    /// Przełącza widok na inną zakładkę
+    fn switch_tab(&mut self, index: usize) {
+        if index >= self.tabs.len() || index == self.active_tab {
+            return;
+        }
+        
+        self.store_active_tab();
+        self.load_tab(index);
+    }
+    
+    /// Otwiera nową zakładkę z pustą planszą i aktualną konfiguracją
+    fn open_new_tab(&mut self) {
+        self.store_active_tab();
+        
+        let name = format!("Board {}", self.next_tab_number);
+        self.next_tab_number += 1;
+        
+        let board = get_initial_state(&self.config).create_board(&self.config);
+        self.tabs.push(BoardTab::new(name, board, self.config.clone()));
+        self.load_tab(self.tabs.len() - 1);
+    }
+    
+    /// Tworzy kopię aktywnej zakładki i przełącza się na nią
+    fn duplicate_active_tab(&mut self) {
+        self.store_active_tab();
+        
+        let mut copy = self.tabs[self.active_tab].clone();
+        copy.name = format!("{} (copy)", copy.name);
+        self.tabs.push(copy);
+        self.load_tab(self.tabs.len() - 1);
+    }
+    
+    /// Zamyka zakładkę - zawsze pozostaje co najmniej jedna
+    fn close_tab(&mut self, index: usize) {
+        if self.tabs.len() <= 1 || index >= self.tabs.len() {
+            return;
+        }
+        
+        self.store_active_tab();
+        self.tabs.remove(index);
+        
+        // Wybieramy zakładkę, która ma zostać aktywna po zamknięciu
+        let new_active = if index < self.active_tab {
+            self.active_tab - 1
+        } else {
+            self.active_tab.min(self.tabs.len() - 1)
+        };
+        self.load_tab(new_active);
+    }
+    
+    /// Wyświetla okno z pytaniem, czy wyczyścić planszę resetem
+    /// 
+    /// Escape lub kliknięcie poza oknem anuluje reset.
+    fn render_reset_confirmation(&mut self, ctx: &egui::Context) {
+        let mut confirmed = false;
+        let mut cancelled = false;
+        
+        let response = egui::Modal::new(egui::Id::new("reset_confirmation")).show(ctx, |ui| {
+            ui.heading("Clear the board?");
+            ui.label("This reset will remove all live cells from the board.");
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                confirmed = ui.button("🗑 Clear board").clicked();
+                cancelled = ui.button("Cancel").clicked();
+            });
+        });
+        
+        if confirmed {
+            self.reset_confirmation_open = false;
+            self.reset_to_initial_state();
+        } else if cancelled || response.should_close() {
+            self.reset_confirmation_open = false;
+        }
+    }
+    
+    /// Wyświetla pytanie o przywrócenie planszy z nieoczekiwanie zakończonej sesji
+    fn render_recovery_prompt(&mut self, ctx: &egui::Context) {
+        let mut restore = false;
+        let mut discard = false;
+        
+        egui::Modal::new(egui::Id::new("recovery_prompt")).show(ctx, |ui| {
+            ui.heading("Restore previous session?");
+            ui.label("The application did not close normally last time.");
+            ui.label("The board from the last autosave can be restored.");
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                restore = ui.button("⟲ Restore").clicked();
+                discard = ui.button("Discard").clicked();
+            });
+        });
+        
+        // Zamknięcie okna bez wyboru nie usuwa planszy - pytanie pojawi się ponownie
+        if restore {
+            if let Some(snapshot) = self.pending_recovery.take() {
+                self.restore_recovery(snapshot);
+            }
+        } else if discard {
+            self.pending_recovery = None;
+        }
+    }
+    
+    /// Przywraca planszę i konfigurację gry z automatycznego zapisu
+    fn restore_recovery(&mut self, snapshot: RecoverySnapshot) {
+        let board = Recording::from_text(&snapshot.board)
+            .ok()
+            .and_then(|recording| recording.frame(0));
+        let Some(board) = board else {
+            self.side_panel.set_board_io_error(Some("The autosaved board is damaged and cannot be restored".to_string()));
+            return;
+        };
+        
+        self.config = snapshot.config;
+        self.side_panel.sync_settings_with_config(&self.config);
+        self.start_from_board(board);
+    }
+    
     /// Resetuje planszę do stanu początkowego
     fn reset_to_initial_state(&mut self) {
         // Zatrzymujemy symulację
         self.side_panel.set_simulation_state(SimulationState::Stopped);
         self.side_panel.reset_generation_count();
+        self.statistics.clear();
         self.cell_state_manager.reset();
+        self.clear_board_tracking();
         
         // Używamy ResetManager do obsługi logiki resetowania
-        let (new_board, should_reset_ever_started) = self.reset_manager.reset_board(&self.board, self.ever_started);
+        let (new_board, should_reset_ever_started) = self.reset_manager.reset_board(&self.board, self.ever_started, &self.config);
         
         // Aktualizujemy planszę
         self.board = new_board;
@@ -333,70 +1714,100 @@ impl GameOfLifeApp {
         self.initial_board = self.board.clone();
         
         // Aktualizujemy statystyki
-        self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
         
         // Synchronizujemy ustawienia w GUI z konfiguracją po resecie
-        self.side_panel.sync_settings_with_config();
+        self.side_panel.sync_settings_with_config(&self.config);
         
         // Invalidujemy cache przewidywania po resecie
         self.current_prediction = None;
     }
     
     /// Aktualizuje przewidywanie następnego stanu jeśli jest potrzebne
-    fn update_prediction_if_needed(&mut self) {
+    /// 
+    /// Po edycji planszy pełne przeliczenie czeka, aż przez `PREDICTION_DEBOUNCE`
+    /// nie będzie kolejnych zmian.
+    fn update_prediction_if_needed(&mut self, ctx: &egui::Context) {
         // Obliczamy przewidywanie tylko jeśli:
         // 1. Symulacja jest zatrzymana (aby nie obciążać podczas działania)
         // 2. Użytkownik włączył podgląd
         // 3. Nie mamy jeszcze cache'owanego przewidywania
+        // 4. Reguły są deterministyczne (inaczej następny stan nie jest znany)
         if self.side_panel.simulation_state() == SimulationState::Stopped 
             && (self.side_panel.show_next_state_preview() || self.side_panel.show_previous_state_preview())
-            && self.current_prediction.is_none() {
-            self.current_prediction = Some(predict_next_state(&self.board));
+            && self.current_prediction.is_none()
+            && !self.config.is_stochastic() {
+            let remaining = self.prediction_invalidated_at
+                .and_then(|edited_at| PREDICTION_DEBOUNCE.checked_sub(edited_at.elapsed()));
+            match remaining {
+                Some(remaining) => ctx.request_repaint_after(remaining),
+                None => {
+                    self.prediction_invalidated_at = None;
+                    self.current_prediction = Some(predict_next_state(&self.board, &self.config));
+                }
+            }
         }
         
         // Jeśli użytkownik wyłączył podgląd, możemy wyczyścić cache
-        if !self.side_panel.show_next_state_preview() && !self.side_panel.show_previous_state_preview() {
+        if (!self.side_panel.show_next_state_preview() && !self.side_panel.show_previous_state_preview())
+            || self.config.is_stochastic() {
             self.current_prediction = None;
         }
     }
     
-    /// Zmienia rozmiar planszy do podanego rozmiaru
-    fn resize_board_to(&mut self, new_size: usize) {
+    /// Aktualizuje przewidywanie po edycji komórek przez CellStateManager
+    /// 
+    /// Przeliczane są tylko komórki wokół zmian; gdy zmiany nie są znane
+    /// (np. po wypełnieniu obszaru), cache jest czyszczony i przewidywanie
+    /// zostanie policzone od nowa dla całej planszy.
+    fn update_prediction_after_edit(&mut self) {
+        match (self.cell_state_manager.take_changed_cells(), self.current_prediction.as_mut()) {
+            (Some(changed_cells), Some(prediction)) => {
+                self.board.recompute_prediction_around(&changed_cells, &self.config, prediction);
+            }
+            _ => {
+                self.current_prediction = None;
+                self.prediction_invalidated_at = Some(Instant::now());
+            }
+        }
+    }
+    
+    /// Zmienia rozmiar planszy do podanych wymiarów
+    fn resize_board_to(&mut self, new_width: usize, new_height: usize) {
         // Zatrzymujemy symulację podczas zmiany rozmiaru
         self.side_panel.set_simulation_state(SimulationState::Stopped);
         
-        // Pobieramy aktualne ustawienia z konfiguracji
-        let config = config::get_config();
-        
         // Zmieniamy rozmiar tylko jeśli aplikacja nie była jeszcze uruchomiona
         // lub jeśli użytkownik świadomie zmienia rozmiar w trybie Static
         if !self.ever_started {
             // Aplikacja nie była uruchomiona - możemy bezpiecznie zmienić rozmiar
-            self.board = self.board.resize_to_square(new_size);
+            self.board = self.board.resize_to(new_width, new_height);
+            self.clear_board_tracking();
             self.initial_board = self.board.clone();
             
             // Aktualizujemy liczbę żywych komórek
-            self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
+            self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
         } else {
             // Aplikacja była uruchomiona - w obu trybach pozwalamy na zmianę rozmiaru
             // ale w trybie Dynamic nie zmieniamy aktualnej planszy, tylko zapisujemy nowy rozmiar
             // który zostanie użyty przy następnym resecie
-            if config.board_size_mode == config::BoardSizeMode::Static {
+            if self.config.board_size_mode == config::BoardSizeMode::Static {
                 // W trybie Static zmieniamy rozmiar natychmiast
-                self.board = self.board.resize_to_square(new_size);
+                self.board = self.board.resize_to(new_width, new_height);
+                self.clear_board_tracking();
                 
                 // Aktualizujemy też zapisany stan przed uruchomieniem jeśli istnieje
                 if self.reset_manager.has_pre_start_state() {
                     // Tworzymy tymczasową planszę do aktualizacji stanu przed uruchomieniem
                     // To jest trochę skomplikowane, ale potrzebne aby zachować enkapsulację
-                    let (temp_board, _) = self.reset_manager.reset_board(&self.board, true);
-                    let resized_temp = temp_board.resize_to_square(new_size);
+                    let (temp_board, _) = self.reset_manager.reset_board(&self.board, true, &self.config);
+                    let resized_temp = temp_board.resize_to(new_width, new_height);
                     self.reset_manager.clear_pre_start_state();
                     self.reset_manager.save_pre_start_state(&resized_temp);
                 }
                 
                 // Aktualizujemy liczbę żywych komórek
-                self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
+                self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
             }
             // W trybie Dynamic nie zmieniamy aktualnej planszy, ale nowy rozmiar
             // jest już zapisany w konfiguracji i zostanie użyty przy resecie
@@ -406,16 +1817,55 @@ impl GameOfLifeApp {
         self.current_prediction = None;
     }
     
+    /// Przycina planszę do najmniejszego prostokąta z żywymi komórkami i marginesem
+    fn trim_board(&mut self) {
+        // None oznacza, że plansza jest już minimalna
+        if let Some(trimmed_board) = self.board.optimize_size(self.config.optimization_margin) {
+            self.replace_board_keeping_cells(trimmed_board);
+        }
+    }
+    
+    /// Dodaje puste warstwy komórek dookoła planszy (nie przekraczając największego wymiaru)
+    fn add_board_layers(&mut self, layers: usize) {
+        let larger_dimension = self.board.width().max(self.board.height());
+        let layers = layers.min(MAX_BOARD_DIMENSION.saturating_sub(larger_dimension) / 2);
+        
+        let expanded_board = match layers {
+            0 => None,
+            1 => Some(self.board.expand()),
+            _ => self.board.expand_by_layers(layers),
+        };
+        if let Some((expanded_board, _)) = expanded_board {
+            self.replace_board_keeping_cells(expanded_board);
+        }
+    }
+    
+    /// Zastępuje planszę wersją o innym rozmiarze, ale z tymi samymi żywymi komórkami
+    fn replace_board_keeping_cells(&mut self, new_board: Board) {
+        self.board = new_board;
+        self.clear_board_tracking();
+        self.initial_board = self.board.clone();
+        
+        // Aktualizujemy liczbę żywych komórek
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+        
+        // Invalidujemy cache przewidywania
+        self.current_prediction = None;
+    }
+    
     /// Generuje losową planszę używając inteligentnego algorytmu randomizera
     fn generate_random_board(&mut self) {
         // Generujemy nową losową planszę na podstawie aktualnego rozmiaru
-        let new_board = randomizer::generate_random_board(&self.board);
+        let new_board = randomizer::generate_random_board(&self.board, &self.config);
         
         // Zastępujemy aktualną planszę nową losową planszą
         self.board = new_board;
+        self.clear_board_tracking();
         
         // Aktualizujemy liczbę żywych komórek w panelu bocznym
-        self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
+        let alive_cells = self.board.count_alive_cells();
+        self.side_panel.set_alive_cells_count(alive_cells, self.board.total_cells());
+        self.side_panel.set_last_fill(alive_cells, self.board.total_cells());
         
         // Invalidujemy cache przewidywania
         self.current_prediction = None;
@@ -431,61 +1881,78 @@ impl GameOfLifeApp {
         }
     }
     
+    /// Zamienia pozycję komórki wzoru na współrzędne planszy
+    /// 
+    /// Zwraca None dla pozycji poza planszą, chyba że plansza zawija się na krawędziach.
+    fn pattern_position_to_cell(board: &Board, pos: assets::Position, wrap: bool) -> Option<(usize, usize)> {
+        if wrap {
+            // Na planszy toroidalnej część wzoru za krawędzią pojawia się po drugiej stronie
+            let x = pos.x.rem_euclid(board.width() as i32) as usize;
+            let y = pos.y.rem_euclid(board.height() as i32) as usize;
+            return Some((x, y));
+        }
+        
+        // Sprawdzamy czy pozycja jest w granicach planszy
+        if pos.x >= 0 && pos.y >= 0 && board.is_valid_coords(pos.x as usize, pos.y as usize) {
+            Some((pos.x as usize, pos.y as usize))
+        } else {
+            None
+        }
+    }
+    
     /// Umieszcza wzór na planszy w podanej pozycji
-    fn place_pattern_on_board(&mut self, pattern_name: &str, center_x: usize, center_y: usize) {
-        if let Some(pattern) = self.side_panel.get_pattern(pattern_name) {
-            let center_pos = assets::Position::new(center_x as i32, center_y as i32);
-            
-            // Pobieramy obszar do wyczyszczenia i komórki wzoru
-            let clear_area = pattern.get_clear_area(center_pos);
-            let pattern_cells = pattern.get_cells_at_center(center_pos);
-            
-            // Najpierw czyścimy obszar wzoru
-            for pos in clear_area {
-                if pos.x >= 0 && pos.y >= 0 {
-                    let x = pos.x as usize;
-                    let y = pos.y as usize;
-                    
-                    // Sprawdzamy czy pozycja jest w granicach planszy
-                    if x < self.board.width() && y < self.board.height() {
-                        self.board.set_cell(x, y, CellState::Dead);
-                    }
-                }
+    fn place_pattern_on_board(&mut self, pattern: &assets::Pattern, center_x: usize, center_y: usize) {
+        let mut center_pos = assets::Position::new(center_x as i32, center_y as i32);
+        let wrap = self.config.is_toroidal();
+        
+        // Wzór wystający poza planszę (bez zawijania) nie może zostać obcięty:
+        // w trybie Dynamic powiększamy planszę, a gdy to nie wystarcza - przesuwamy wzór
+        if !wrap && !self.board.can_fit_pattern_at(center_pos, pattern) {
+            if self.config.can_expand_in_current_mode()
+                && let Some((expanded_board, (offset_x, offset_y))) = self.board.expand_to_fit_pattern(center_pos, pattern, &self.config) {
+                self.board = expanded_board;
+                center_pos = assets::Position::new(center_pos.x + offset_x as i32, center_pos.y + offset_y as i32);
             }
-            
-            // Następnie ustawiamy komórki wzoru
-            for pos in pattern_cells {
-                if pos.x >= 0 && pos.y >= 0 {
-                    let x = pos.x as usize;
-                    let y = pos.y as usize;
-                    
-                    // Sprawdzamy czy pozycja jest w granicach planszy
-                    if x < self.board.width() && y < self.board.height() {
-                        self.board.set_cell(x, y, CellState::Alive);
-                    }
-                }
+            center_pos = self.board.clamp_pattern_center(center_pos, pattern);
+        }
+        
+        // Pobieramy obszar do wyczyszczenia i komórki wzoru
+        let clear_area = pattern.get_clear_area(center_pos);
+        let pattern_cells = pattern.get_cells_at_center(center_pos);
+        
+        // Najpierw czyścimy obszar wzoru
+        for pos in clear_area {
+            if let Some((x, y)) = Self::pattern_position_to_cell(&self.board, pos, wrap) {
+                self.board.set_cell(x, y, CellState::Dead);
             }
-            
-            // Aktualizujemy statystyki
-            self.side_panel.set_alive_cells_count(self.board.count_alive_cells());
-            
-            // Invalidujemy cache przewidywania
-            self.current_prediction = None;
-            
-            // Zapisujemy nowy stan jako stan początkowy do resetowania
-            // (jeśli gra była już kiedyś uruchomiona)
-            if self.ever_started {
-                self.reset_manager.clear_pre_start_state();
-                self.reset_manager.save_pre_start_state(&self.board);
+        }
+        
+        // Następnie ustawiamy komórki wzoru
+        for pos in pattern_cells {
+            if let Some((x, y)) = Self::pattern_position_to_cell(&self.board, pos, wrap) {
+                self.board.set_cell(x, y, CellState::Alive);
             }
         }
+        
+        // Aktualizujemy statystyki
+        self.side_panel.set_alive_cells_count(self.board.count_alive_cells(), self.board.total_cells());
+        
+        // Invalidujemy cache przewidywania i historię
+        self.current_prediction = None;
+        self.clear_board_tracking();
+        
+        // Zapisujemy nowy stan jako stan początkowy do resetowania
+        // (jeśli gra była już kiedyś uruchomiona)
+        if self.ever_started {
+            self.reset_manager.clear_pre_start_state();
+            self.reset_manager.save_pre_start_state(&self.board);
+        }
     }
 }
 
 fn main() -> Result<(), eframe::Error> {
-    // Inicjalizujemy konfigurację
-    init_config();
-    let config = config::get_config();
+    // Konfiguracja gry - aplikacja przekazuje ją dalej do logiki i panelu ustawień
+    let config = GameConfig::default();
     
     // Konfiguracja okna aplikacji z centralnych ustawień
     let options = eframe::NativeOptions {
@@ -499,6 +1966,8 @@ fn main() -> Result<(), eframe::Error> {
                 config.ui_config.window_config.min_size.1
             ])
             .with_title(&config.ui_config.window_config.title),
+        // Rozmiar okna przywracamy sami (z zachowaniem minimalnego rozmiaru), pozycji nie
+        persist_window: false,
         ..Default::default()
     };
     
@@ -506,8 +1975,29 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "Conway's Game of Life",
         options,
-        Box::new(|_cc| {
-            Ok(Box::new(GameOfLifeApp::default()))
+        Box::new(move |cc| {
+            // Przywracamy rozmiar okna i panelu z poprzedniego uruchomienia (jeśli zostały zapisane)
+            let layout = GameOfLifeApp::load_window_layout(cc.storage);
+            if let Some(layout) = layout {
+                let (min_width, min_height) = config.ui_config.window_config.min_size;
+                let (width, height) = layout.window_size;
+                cc.egui_ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(
+                    egui::vec2(width.max(min_width), height.max(min_height))
+                ));
+            }
+            
+            let mut config = config;
+            if let Some(pattern) = GameOfLifeApp::load_startup_pattern(cc.storage) {
+                config.initial_pattern = pattern;
+            }
+            if let Some(autosave) = GameOfLifeApp::load_autosave(cc.storage) {
+                config.ui_config.autosave = autosave;
+            }
+            
+            let mut app = GameOfLifeApp::new(config);
+            app.side_panel_width = layout.and_then(|layout| layout.side_panel_width);
+            app.pending_recovery = GameOfLifeApp::load_recovery(cc.storage);
+            Ok(Box::new(app))
         }),
     )
 }
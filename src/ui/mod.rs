@@ -9,7 +9,9 @@ pub mod preview_render;
 pub mod settings;
 pub mod styles;
 pub mod pattern_selector;
+pub mod tab_bar;
 
 // Re-eksportujemy główne typy
-pub use render::{GameRenderer, MouseInteraction};
-pub use side_panel::SidePanel;
\ No newline at end of file
+pub use render::{BoardTool, GameRenderer, MouseInteraction};
+pub use side_panel::SidePanel;
+pub use tab_bar::TabBar;
\ No newline at end of file
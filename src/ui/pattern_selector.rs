@@ -1,11 +1,31 @@
-use egui::{Image, Vec2, Rect, Color32, Stroke, Pos2};
-use crate::assets::{PatternManager, Pattern};
-use super::styles::{UIStyles, helpers};
+use egui::{Image, Vec2, Rect, Color32, Stroke, Pos2, TextureHandle};
+use std::collections::HashMap;
+use crate::assets::{PatternManager, Pattern, PatternFormat};
+use super::styles::{UIStyles, ButtonType, helpers};
+
+/// Akcja wybrana na przycisku wzoru
+enum PatternButtonAction {
+    /// Wybór wzoru do umieszczenia na planszy
+    Select,
+    /// Usunięcie wzoru użytkownika
+    Delete,
+}
 
 /// Selektor wzorów do umieszczania na planszy
 pub struct PatternSelector {
     pattern_manager: PatternManager,
     styles: UIStyles,
+    /// Komunikat błędu ostatniego wczytywania lub zapisu pliku wzoru
+    file_error: Option<String>,
+    /// Format plików wzorów wybrany do wczytywania i zapisu
+    file_format: PatternFormat,
+    /// Czy kliknięto przycisk zapisu planszy do pliku
+    save_requested: bool,
+    /// Tekst wyszukiwania - wyświetlane są tylko wzory zawierające go w nazwie
+    search_query: String,
+    /// Tekstury obrazków wzorów wczytane przy pierwszym wyświetleniu
+    /// (None - brak pliku lub błędny obrazek, rysowana jest mini plansza)
+    thumbnails: HashMap<String, Option<TextureHandle>>,
 }
 
 impl PatternSelector {
@@ -13,6 +33,11 @@ impl PatternSelector {
         Self {
             pattern_manager: PatternManager::new(),
             styles: UIStyles::new(),
+            file_error: None,
+            file_format: PatternFormat::default(),
+            save_requested: false,
+            search_query: String::new(),
+            thumbnails: HashMap::new(),
         }
     }
     
@@ -32,6 +57,38 @@ impl PatternSelector {
                 return;
             }
             
+            // Wyszukiwanie wzorów po nazwie
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut self.search_query)
+                    .desired_width(ui.available_width() - 30.0)
+                    .hint_text("🔍 Search patterns"));
+                if !self.search_query.is_empty() && ui.small_button("✖").on_hover_text("Clear search").clicked() {
+                    self.search_query.clear();
+                }
+            });
+            ui.add_space(self.styles.dimensions.margin_small);
+            
+            // Wczytywanie wzoru z pliku i zapis planszy w wybranym formacie
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("pattern_file_format")
+                    .selected_text(self.file_format.name())
+                    .show_ui(ui, |ui| {
+                        for format in PatternFormat::ALL {
+                            ui.selectable_value(&mut self.file_format, format, format.name());
+                        }
+                    });
+                if ui.add(helpers::styled_button("📂 Load", self.styles.colors.text_primary, &self.styles, ButtonType::Medium)).clicked() {
+                    selected_pattern = self.load_pattern_from_file();
+                }
+                if ui.add(helpers::styled_button("💾 Save Board", self.styles.colors.text_primary, &self.styles, ButtonType::Medium)).clicked() {
+                    self.save_requested = true;
+                }
+            });
+            if let Some(error) = &self.file_error {
+                ui.label(helpers::small_text(error, &self.styles).color(self.styles.colors.error));
+            }
+            ui.add_space(self.styles.dimensions.margin_small);
+            
             // Siatka wzorów
             let patterns = self.pattern_manager.get_all_patterns();
             
@@ -40,42 +97,121 @@ impl PatternSelector {
                 return;
             }
             
+            // Wzory filtrowane po nazwie (bez rozróżniania wielkości liter)
+            let query = self.search_query.trim().to_lowercase();
+            let patterns: Vec<&Pattern> = patterns
+                .into_iter()
+                .filter(|pattern| pattern.name.to_lowercase().contains(&query))
+                .collect();
+            
+            if patterns.is_empty() {
+                ui.label(helpers::label_text("No matching patterns", &self.styles));
+                return;
+            }
+            
             // Renderujemy wzory w układzie adaptacyjnym
             let available_width = ui.available_width();
             let spacing = 10.0;
             let base_height = 80.0; // bazowa wysokość wzoru
             
-            // Renderujemy każdy wzór osobno z odpowiednim rozmiarem
+            // Obrazki wzorów są wczytywane z dysku tylko raz - przy pierwszym wyświetleniu
+            for pattern in &patterns {
+                if let Some(image_path) = &pattern.image_path && !self.thumbnails.contains_key(&pattern.name) {
+                    let texture = Self::load_thumbnail(ui.ctx(), &pattern.name, image_path);
+                    self.thumbnails.insert(pattern.name.clone(), texture);
+                }
+            }
+            
+            // Renderujemy każdy wzór osobno z rozmiarem zależnym od jego proporcji
+            let mut pattern_to_delete = None;
             for pattern in patterns {
-                let pattern_width = if pattern.name == "Glider Gun" {
-                    // Glider Gun ma podwójną szerokość
-                    available_width - spacing
-                } else {
-                    // Pozostałe wzory mają pełną szerokość
-                    available_width - spacing
-                };
+                let (pattern_width, pattern_height) = Self::button_size_for(pattern, available_width - spacing, base_height);
                 
-                let pattern_height = if pattern.name == "Glider Gun" {
-                    // Glider Gun ma mniejszą wysokość (prostokątny)
-                    base_height * 0.6
-                } else {
-                    // Pozostałe wzory mają standardową wysokość
-                    base_height
-                };
-                
-                if self.render_pattern_button(ui, pattern, pattern_width, pattern_height) {
-                    selected_pattern = Some(pattern.name.clone());
+                match self.render_pattern_button(ui, pattern, pattern_width, pattern_height) {
+                    Some(PatternButtonAction::Select) => selected_pattern = Some(pattern.name.clone()),
+                    Some(PatternButtonAction::Delete) => pattern_to_delete = Some(pattern.name.clone()),
+                    None => {}
                 }
                 ui.add_space(spacing);
             }
+            
+            if let Some(name) = pattern_to_delete {
+                self.thumbnails.remove(&name);
+                self.file_error = self.pattern_manager.remove_user_pattern(&name)
+                    .err()
+                    .map(|error| format!("Cannot save user patterns: {}", error));
+            }
             });
         });
         
         selected_pattern
     }
     
+    /// Zwraca format wybrany do zapisu, jeśli kliknięto przycisk zapisu planszy
+    /// 
+    /// Żądanie jest kasowane po odczytaniu.
+    pub fn take_save_request(&mut self) -> Option<PatternFormat> {
+        std::mem::take(&mut self.save_requested).then_some(self.file_format)
+    }
+    
+    /// Ustawia (lub czyści) komunikat błędu operacji na pliku wzoru
+    pub fn set_file_error(&mut self, error: Option<String>) {
+        self.file_error = error;
+    }
+    
+    /// Otwiera okno wyboru pliku i dodaje wczytany wzór do managera
+    /// 
+    /// Plik jest parsowany w formacie wybranym w interfejsie.
+    /// Zwraca nazwę dodanego wzoru lub None, jeśli wybór anulowano lub plik jest błędny.
+    fn load_pattern_from_file(&mut self) -> Option<String> {
+        let format = self.file_format;
+        let path = rfd::FileDialog::new()
+            .add_filter(format!("{} pattern", format.name()), &[format.extension()])
+            .pick_file()?;
+        
+        let result = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Cannot read file: {}", error))
+            .and_then(|text| format.parse(&text));
+        
+        match result {
+            Ok(mut pattern) => {
+                // Wzory bez nazwy w pliku nazywamy nazwą pliku
+                if pattern.name == format.default_pattern_name() && let Some(stem) = path.file_stem() {
+                    pattern.name = stem.to_string_lossy().into_owned();
+                }
+                let name = pattern.name.clone();
+                self.add_pattern(pattern);
+                Some(name)
+            }
+            Err(error) => {
+                self.file_error = Some(error);
+                None
+            }
+        }
+    }
+    
+    /// Oblicza rozmiar przycisku wzoru na podstawie proporcji jego rozmiaru
+    /// 
+    /// Przycisk zajmuje pełną szerokość, a wysokość maleje dla szerokich wzorów
+    /// (np. Glider Gun 36x9), nie schodząc poniżej ustalonego minimum.
+    fn button_size_for(pattern: &Pattern, width: f32, base_height: f32) -> (f32, f32) {
+        // Minimalny stosunek wysokości do szerokości przycisku
+        const MIN_ASPECT_RATIO: f32 = 0.6;
+        
+        let (pattern_w, pattern_h) = pattern.size;
+        let aspect_ratio = if pattern_w > 0 {
+            pattern_h as f32 / pattern_w as f32
+        } else {
+            1.0
+        };
+        
+        (width, base_height * aspect_ratio.clamp(MIN_ASPECT_RATIO, 1.0))
+    }
+    
     /// Renderuje przycisk dla pojedynczego wzoru
-    fn render_pattern_button(&self, ui: &mut egui::Ui, pattern: &Pattern, width: f32, height: f32) -> bool {
+    /// 
+    /// Wzory użytkownika mają w rogu przycisk usunięcia.
+    fn render_pattern_button(&self, ui: &mut egui::Ui, pattern: &Pattern, width: f32, height: f32) -> Option<PatternButtonAction> {
         let (rect, response) = ui.allocate_exact_size(Vec2::new(width, height), egui::Sense::click());
         
         // Tło przycisku
@@ -97,24 +233,16 @@ impl PatternSelector {
         let stroke = Stroke::new(1.0, stroke_color);
         ui.painter().rect_stroke(rect, 4.0, stroke, egui::StrokeKind::Inside);
         
-        // Próbujemy załadować obrazek wzoru
-        if let Some(image_path) = &pattern.image_path {
-            // Sprawdzamy czy plik istnieje
-            if std::path::Path::new(image_path).exists() {
-                // Renderujemy obrazek - wykorzystujemy prawie całą dostępną przestrzeń
-                let padding = 4.0;
-                let image_rect = rect.shrink(padding);
-                
-                ui.scope_builder(egui::UiBuilder::new().max_rect(image_rect), |ui| {
-                    ui.add(Image::from_uri(format!("file://{}", image_path))
-                        .fit_to_exact_size(image_rect.size()));
-                });
-            } else {
-                // Fallback - renderujemy wzór jako mini planszę
-                self.render_pattern_preview(ui, pattern, rect);
-            }
+        // Obrazek wzoru z pamięci podręcznej, a gdy go brak - mini plansza
+        if let Some(Some(texture)) = self.thumbnails.get(&pattern.name) {
+            // Renderujemy obrazek - wykorzystujemy prawie całą dostępną przestrzeń
+            let padding = 4.0;
+            let image_rect = rect.shrink(padding);
+            
+            ui.scope_builder(egui::UiBuilder::new().max_rect(image_rect), |ui| {
+                ui.add(Image::new(texture).fit_to_exact_size(image_rect.size()));
+            });
         } else {
-            // Renderujemy wzór jako mini planszę
             self.render_pattern_preview(ui, pattern, rect);
         }
         
@@ -130,7 +258,27 @@ impl PatternSelector {
             });
         });
         
-        response.clicked()
+        // Przycisk usunięcia jest dodany po przycisku wzoru, więc ma pierwszeństwo przy kliknięciu
+        if self.pattern_manager.is_user_pattern(&pattern.name) {
+            let delete_rect = Rect::from_min_size(Pos2::new(rect.max.x - 22.0, rect.min.y + 2.0), Vec2::splat(20.0));
+            if ui.put(delete_rect, egui::Button::new("✕").small())
+                .on_hover_text("Delete this pattern")
+                .clicked() {
+                return Some(PatternButtonAction::Delete);
+            }
+        }
+        
+        response.clicked().then_some(PatternButtonAction::Select)
+    }
+    
+    /// Wczytuje obrazek wzoru z pliku i tworzy z niego teksturę
+    /// 
+    /// Zwraca None, gdy pliku nie ma lub nie jest poprawnym obrazkiem.
+    fn load_thumbnail(ctx: &egui::Context, name: &str, image_path: &str) -> Option<TextureHandle> {
+        let image = image::open(image_path).ok()?.to_rgba8();
+        let size = [image.width() as usize, image.height() as usize];
+        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, image.as_raw());
+        Some(ctx.load_texture(format!("pattern_thumbnail_{}", name), color_image, egui::TextureOptions::LINEAR))
     }
     
     /// Renderuje podgląd wzoru jako mini planszę
@@ -195,6 +343,15 @@ impl PatternSelector {
     pub fn get_pattern(&self, name: &str) -> Option<&Pattern> {
         self.pattern_manager.get_pattern(name)
     }
+    
+    /// Dodaje wzór do listy dostępnych wzorów (i do pliku wzorów użytkownika)
+    pub fn add_pattern(&mut self, pattern: Pattern) {
+        // Wzór o tej samej nazwie mógł mieć inny obrazek
+        self.thumbnails.remove(&pattern.name);
+        self.file_error = self.pattern_manager.add_pattern(pattern)
+            .err()
+            .map(|error| format!("Cannot save user patterns: {}", error));
+    }
 }
 
 impl Default for PatternSelector {
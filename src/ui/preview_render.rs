@@ -1,10 +1,11 @@
 /// Moduł renderowania podglądu następnego stanu gry
 /// 
-/// Zawiera funkcje do renderowania zielonych podświetleń komórek,
-/// które będą żywe w następnej generacji.
+/// Zawiera funkcje do renderowania podświetleń komórek, które się narodzą,
+/// umrą lub przeżyją w następnej generacji.
 
 use egui::{Color32, Pos2, Rect, Vec2};
 use crate::logic::prediction::PredictionResult;
+use crate::logic::raster;
 
 /// Renderer podglądu następnego stanu
 pub struct PreviewRenderer {
@@ -12,6 +13,10 @@ pub struct PreviewRenderer {
     birth_highlight_color: Color32,
     /// Kolor podświetlenia komórek, które umrą (delikatnie czerwony, przezroczysty)
     death_highlight_color: Color32,
+    /// Kolor podświetlenia komórek, które przeżyją (słabszy niż narodziny i śmierci)
+    survival_highlight_color: Color32,
+    /// Czy podświetlać komórki, które przeżyją
+    show_survivors: bool,
 }
 
 impl Default for PreviewRenderer {
@@ -21,6 +26,9 @@ impl Default for PreviewRenderer {
             birth_highlight_color: Color32::from_rgba_unmultiplied(0, 255, 0, 60),
             // Delikatnie czerwony, przezroczysty kolor dla komórek, które umrą
             death_highlight_color: Color32::from_rgba_unmultiplied(255, 0, 0, 40),
+            // Delikatnie niebieski kolor dla komórek, które przeżyją - domyślnie ukryty
+            survival_highlight_color: Color32::from_rgba_unmultiplied(0, 128, 255, 30),
+            show_survivors: false,
         }
     }
 }
@@ -41,6 +49,16 @@ impl PreviewRenderer {
         self.death_highlight_color = color;
     }
     
+    /// Ustawia kolor podświetlenia komórek, które przeżyją
+    pub fn set_survival_highlight_color(&mut self, color: Color32) {
+        self.survival_highlight_color = color;
+    }
+    
+    /// Ustawia czy podświetlać komórki, które przeżyją
+    pub fn set_show_survivors(&mut self, show: bool) {
+        self.show_survivors = show;
+    }
+    
     /// Renderuje podświetlenia komórek na podstawie przewidywania
     pub fn render_preview_highlights(
         &self,
@@ -53,6 +71,14 @@ impl PreviewRenderer {
     ) {
         let painter = ui.painter();
         
+        // Przeżywające komórki rysujemy najpierw, aby narodziny i śmierci były na wierzchu
+        if self.show_survivors {
+            for &(x, y) in &prediction.survival_cells {
+                let cell_rect = self.get_cell_rect(board_rect, x, y, cell_size);
+                painter.rect_filled(cell_rect, 0.0, self.survival_highlight_color);
+            }
+        }
+        
         // Renderujemy podświetlenia komórek, które się narodzą
         if show_births {
             for &(x, y) in &prediction.birth_cells {
@@ -104,12 +130,8 @@ impl PreviewRenderer {
     
     /// Oblicza prostokąt dla pojedynczej komórki
     fn get_cell_rect(&self, board_rect: Rect, x: usize, y: usize, cell_size: f32) -> Rect {
-        let cell_min = Pos2::new(
-            board_rect.min.x + x as f32 * cell_size,
-            board_rect.min.y + y as f32 * cell_size,
-        );
-        
-        Rect::from_min_size(cell_min, Vec2::splat(cell_size))
+        let cell = raster::cell_rect((board_rect.min.x, board_rect.min.y), cell_size, x, y);
+        Rect::from_min_size(Pos2::new(cell.min.0, cell.min.1), Vec2::splat(cell.size))
     }
     
     /// Zwraca aktualny kolor podświetlenia komórek, które się narodzą
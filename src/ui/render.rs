@@ -1,27 +1,68 @@
 /// Moduł renderowania planszy gry w życie
 /// 
 /// Odpowiada za wizualizację stanu gry w oknie aplikacji.
-/// Plansza jest renderowana jako kwadrat wyrównany do prawej strony.
+/// Widoczny obszar wyznacza kamera (punkt świata i powiększenie), więc widok
+/// może wychodzić poza planszę - obszar poza nią ma osobny, neutralny kolor.
+/// Bez przesunięcia widoku plansza zachowuje swoje proporcje, jest wyrównana
+/// do prawej strony i wyśrodkowana w pionie.
 
-use egui::{Color32, Pos2, Rect, Stroke, Vec2};
+use egui::{Color32, ColorImage, Pos2, Rect, Stroke, TextureHandle, TextureOptions, Vec2};
+use std::time::{Duration, Instant};
 use crate::logic::board::{Board, CellState};
+use crate::logic::change_state::BrushSize;
 use crate::logic::prediction::PredictionResult;
+use crate::logic::raster;
+use crate::logic::selection::{BoardClipboard, Selection};
 use crate::assets::Pattern;
+use crate::config::{AppearanceConfig, CellShape};
 use super::preview_render::PreviewRenderer;
+use super::styles::UIStyles;
 
 /// Informacje o interakcji myszy z planszą
 #[derive(Debug, Clone)]
 pub struct MouseInteraction {
     /// Współrzędne komórki, na którą kliknięto (lewy przycisk myszy)
     pub clicked_cell: Option<(usize, usize)>,
+    /// Czy kliknięto w obszarze widoku poza planszą
+    pub clicked_outside_board: bool,
     /// Współrzędne komórki, nad którą znajduje się kursor
     pub hovered_cell: Option<(usize, usize)>,
+    /// Stan komórki, nad którą znajduje się kursor
+    pub hovered_state: Option<CellState>,
     /// Czy lewy przycisk myszy jest wciśnięty
     pub is_mouse_down: bool,
     /// Czy lewy przycisk myszy został właśnie wciśnięty
     pub mouse_pressed: bool,
     /// Czy lewy przycisk myszy został właśnie zwolniony
     pub mouse_released: bool,
+    /// Czy wciśnięto klawisz obrotu wzoru (R) podczas jego umieszczania
+    pub rotate_pattern_pressed: bool,
+    /// Czy wciśnięto klawisz odbicia wzoru (F) podczas jego umieszczania
+    pub flip_pattern_pressed: bool,
+}
+
+/// Narzędzie używane do interakcji z planszą lewym przyciskiem myszy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoardTool {
+    /// Klikanie i przeciąganie zmienia stan komórek
+    #[default]
+    Draw,
+    /// Przeciąganie zaznacza prostokątny obszar
+    Select,
+    /// Kliknięcie wkleja skopiowany fragment pod kursorem
+    Paste,
+}
+
+/// Kamera widoku planszy
+/// 
+/// Położenie jest wyrażone we współrzędnych świata (w komórkach), a nie w pikselach,
+/// dlatego widok nie jest ograniczony do obszaru zajmowanego przez planszę.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    /// Punkt świata (w komórkach) widoczny w środku obszaru widoku
+    pub center: Pos2,
+    /// Współczynnik powiększenia (1.0 = cała plansza mieści się w wysokości widoku)
+    pub zoom: f32,
 }
 
 /// Renderer planszy gry
@@ -34,12 +75,105 @@ pub struct GameRenderer {
     dead_color: Color32,
     /// Kolor siatki
     grid_color: Color32,
+    /// Kolor obszaru widoku leżącego poza planszą
+    out_of_bounds_color: Color32,
+    /// Kształt rysowania żywych komórek
+    cell_shape: CellShape,
     /// Grubość linii siatki
     grid_stroke: Stroke,
+    /// Czy rysować linie siatki
+    show_grid: bool,
+    /// Co ile komórek rysować grubszą, ciemniejszą linię główną (None - bez linii głównych)
+    major_grid_interval: Option<usize>,
     /// Renderer podglądu następnego stanu
     preview_renderer: PreviewRenderer,
+    /// Kamera ustawiona przez użytkownika (None = domyślny widok całej planszy)
+    camera: Option<Camera>,
+    /// Obszar widoku, w którym ostatnio renderowano planszę
+    view_rect: Rect,
+    /// Komórka wyróżniona znacznikiem po przejściu do współrzędnych wraz z czasem rozpoczęcia
+    flash_marker: Option<((usize, usize), Instant)>,
+    /// Czy podgląd wzoru ma pokazywać części zawijające się na przeciwległą krawędź
+    wrap_pattern_preview: bool,
+    /// Czy podgląd wzoru jest przesuwany tak, aby wzór mieścił się na planszy
+    clamp_pattern_preview: bool,
+    /// Czy zmiany rozmiaru komórek i przesunięcia widoku są animowane
+    smooth_zoom: bool,
+    /// Czy rysować minimapę, gdy plansza nie mieści się w całości w widoku
+    show_minimap: bool,
+    /// Czy trwa przesuwanie widoku środkowym przyciskiem myszy
+    is_panning: bool,
+    /// Aktywne narzędzie interakcji z planszą
+    tool: BoardTool,
+    /// Zaznaczony prostokątny obszar planszy
+    selection: Option<Selection>,
+    /// Komórka wskazywana kursorem klawiatury (None - kursor ukryty)
+    keyboard_cursor: Option<(usize, usize)>,
+    /// Rozmiar pędzla, którego obrys jest rysowany pod kursorem myszy
+    brush_size: BrushSize,
+    /// Szerokość pasa przy krawędzi, w którym żywa komórka powoduje rozszerzenie planszy,
+    /// i czy plansza osiągnęła już maksymalny rozmiar (None - pas nie jest rysowany)
+    expansion_margin: Option<(usize, bool)>,
+    /// Kolor pasa rozszerzania planszy
+    expansion_margin_color: Color32,
+    /// Kolor pasa rozszerzania, gdy plansza nie może już rosnąć
+    expansion_limit_color: Color32,
+    /// Fragment planszy wyświetlany pod kursorem w trybie wklejania
+    paste_preview: Option<BoardClipboard>,
+    /// Kolory (najmłodsze, najstarsze) żywych komórek przy kolorowaniu według wieku
+    age_colors: Option<(Color32, Color32)>,
+    /// Tekstura z komórkami planszy (jeden piksel na komórkę) rysowana przy kwadratowych komórkach
+    board_texture: Option<TextureHandle>,
 }
 
+/// Czas trwania mignięcia znacznika po przejściu do komórki
+const FLASH_MARKER_DURATION: Duration = Duration::from_millis(1200);
+
+/// Czas płynnego przejścia powiększenia/przesunięcia widoku (w sekundach)
+const ZOOM_ANIMATION_TIME: f32 = 0.15;
+
+/// Najmniejsze dozwolone powiększenie widoku
+const MIN_ZOOM: f32 = 0.5;
+
+/// Największe dozwolone powiększenie widoku
+const MAX_ZOOM: f32 = 40.0;
+
+/// Czułość powiększania kółkiem myszy (na piksel przewinięcia)
+const SCROLL_ZOOM_SPEED: f32 = 0.0015;
+
+/// Rozmiar komórki (w pikselach), poniżej którego siatka jest ukrywana
+/// (gęste linie zlewają się w jednolitą szarą plamę)
+const MIN_GRID_CELL_SIZE: f32 = 3.0;
+
+/// Wiek (w generacjach), od którego komórka ma kolor najstarszych komórek
+const AGE_GRADIENT_SPAN: u32 = 50;
+
+/// Najmniejsza grubość linii siatki (w pikselach)
+pub const MIN_GRID_THICKNESS: f32 = 0.5;
+
+/// Największa grubość linii siatki (w pikselach)
+pub const MAX_GRID_THICKNESS: f32 = 3.0;
+
+/// Ile razy komórka musi być większa od grubości linii, aby rysować zwykłe linie siatki
+/// (grubsze linie zakrywałyby komórki)
+const MIN_CELL_TO_GRID_THICKNESS_RATIO: f32 = 3.0;
+
+/// Przyciemnienie głównych linii siatki względem zwykłych
+const MAJOR_GRID_DARKEN: f32 = 0.4;
+
+/// Rozmiar komórki (w pikselach), poniżej którego żywe komórki są zawsze rysowane jako kwadraty
+/// (koła i zaokrąglenia byłyby nieczytelne, a ich rysowanie jest droższe)
+const MIN_SHAPED_CELL_SIZE: f32 = 4.0;
+
+/// Promień zaokrąglenia rogów jako ułamek rozmiaru komórki
+const ROUNDED_CELL_RADIUS_RATIO: f32 = 0.25;
+
+/// Największy wymiar minimapy w pikselach
+const MINIMAP_MAX_SIZE: f32 = 160.0;
+
+/// Odstęp minimapy od krawędzi obszaru widoku
+const MINIMAP_MARGIN: f32 = 8.0;
+
 impl Default for GameRenderer {
     fn default() -> Self {
         Self {
@@ -48,7 +182,29 @@ impl Default for GameRenderer {
             dead_color: Color32::WHITE,
             grid_color: Color32::GRAY,
             grid_stroke: Stroke::new(1.0, Color32::GRAY),
+            out_of_bounds_color: Color32::from_gray(200),
+            cell_shape: CellShape::Square,
+            show_grid: true,
+            major_grid_interval: None,
             preview_renderer: PreviewRenderer::new(),
+            camera: None,
+            view_rect: Rect::NOTHING,
+            flash_marker: None,
+            wrap_pattern_preview: false,
+            clamp_pattern_preview: false,
+            smooth_zoom: false,
+            show_minimap: true,
+            is_panning: false,
+            tool: BoardTool::Draw,
+            selection: None,
+            keyboard_cursor: None,
+            brush_size: BrushSize::Single,
+            expansion_margin: None,
+            expansion_margin_color: Color32::from_rgba_unmultiplied(70, 130, 220, 40),
+            expansion_limit_color: Color32::from_rgba_unmultiplied(230, 160, 30, 60),
+            paste_preview: None,
+            age_colors: None,
+            board_texture: None,
         }
     }
 }
@@ -77,16 +233,265 @@ impl GameRenderer {
         )
     }
     
-    /// Oblicza optymalny rozmiar komórki dla danej wysokości okna
-    pub fn calculate_optimal_cell_size(&self, board: &Board, available_height: f32) -> f32 {
+    /// Oblicza optymalny rozmiar komórki, przy którym plansza mieści się w całym obszarze
+    pub fn calculate_optimal_cell_size(&self, board: &Board, available_size: Vec2) -> f32 {
+        let board_width = board.width() as f32;
         let board_height = board.height() as f32;
-        if board_height > 0.0 {
-            (available_height / board_height).max(1.0)
+        if board_width > 0.0 && board_height > 0.0 {
+            (available_size.x / board_width)
+                .min(available_size.y / board_height)
+                .max(1.0)
         } else {
             self.cell_size
         }
     }
     
+    /// Ustawia czy podgląd wzoru ma zawijać się przez krawędzie planszy
+    pub fn set_wrap_pattern_preview(&mut self, wrap: bool) {
+        self.wrap_pattern_preview = wrap;
+    }
+    
+    /// Ustawia czy podgląd wzoru ma być przesuwany tak, aby wzór mieścił się na planszy
+    pub fn set_clamp_pattern_preview(&mut self, clamp: bool) {
+        self.clamp_pattern_preview = clamp;
+    }
+    
+    /// Zwraca aktywne narzędzie interakcji z planszą
+    pub fn tool(&self) -> BoardTool {
+        self.tool
+    }
+    
+    /// Ustawia aktywne narzędzie interakcji z planszą
+    pub fn set_tool(&mut self, tool: BoardTool) {
+        self.tool = tool;
+    }
+    
+    /// Zwraca aktualne zaznaczenie
+    pub fn selection(&self) -> Option<Selection> {
+        self.selection
+    }
+    
+    /// Ustawia (lub usuwa) zaznaczenie wyświetlane na planszy
+    pub fn set_selection(&mut self, selection: Option<Selection>) {
+        self.selection = selection;
+    }
+    
+    /// Ustawia czy rysować minimapę planszy
+    pub fn set_show_minimap(&mut self, show: bool) {
+        self.show_minimap = show;
+    }
+    
+    /// Ustawia komórkę wskazywaną kursorem klawiatury (None ukrywa kursor)
+    pub fn set_keyboard_cursor(&mut self, cursor: Option<(usize, usize)>) {
+        self.keyboard_cursor = cursor;
+    }
+    
+    /// Ustawia rozmiar pędzla (dla pojedynczej komórki obrys nie jest rysowany)
+    pub fn set_brush_size(&mut self, size: BrushSize) {
+        self.brush_size = size;
+    }
+    
+    /// Ustawia pas rozszerzania planszy: szerokość w komórkach i czy plansza
+    /// osiągnęła maksymalny rozmiar (None ukrywa pas)
+    pub fn set_expansion_margin(&mut self, margin: Option<(usize, bool)>) {
+        self.expansion_margin = margin;
+    }
+    
+    /// Ustawia fragment planszy podglądany pod kursorem w trybie wklejania
+    pub fn set_paste_preview(&mut self, clipboard: Option<BoardClipboard>) {
+        self.paste_preview = clipboard;
+    }
+    
+    /// Ustawia czy rysować linie siatki
+    pub fn set_show_grid(&mut self, show: bool) {
+        self.show_grid = show;
+    }
+    
+    /// Ustawia grubość linii siatki i odstęp między liniami głównymi (None - bez linii głównych)
+    pub fn set_grid_style(&mut self, thickness: f32, major_interval: Option<usize>) {
+        self.grid_stroke.width = thickness.clamp(MIN_GRID_THICKNESS, MAX_GRID_THICKNESS);
+        self.major_grid_interval = major_interval.filter(|&interval| interval > 1);
+    }
+    
+    /// Ustawia kolory komórek i siatki na podstawie konfiguracji wyglądu
+    /// 
+    /// Przy włączonym dopasowaniu do motywu kolory pochodzą z palety `styles`.
+    pub fn set_colors(&mut self, appearance: &AppearanceConfig, styles: &UIStyles) {
+        let to_color32 = |[r, g, b, a]: [u8; 4]| Color32::from_rgba_unmultiplied(r, g, b, a);
+        if appearance.match_theme {
+            self.alive_color = styles.colors.primary;
+            self.dead_color = styles.colors.background_dark;
+            self.grid_color = styles.colors.border_subtle;
+        } else {
+            self.alive_color = to_color32(appearance.alive_color);
+            self.dead_color = to_color32(appearance.dead_color);
+            self.grid_color = to_color32(appearance.grid_color);
+        }
+        self.grid_stroke = Stroke::new(self.grid_stroke.width, self.grid_color);
+        self.cell_shape = appearance.cell_shape;
+        self.expansion_margin_color = styles.colors.info.gamma_multiply(0.2);
+        self.expansion_limit_color = styles.colors.warning.gamma_multiply(0.3);
+        
+        // Kolory podglądu następnego stanu - domyślnie takie jak legenda w panelu
+        if appearance.themed_preview_colors {
+            self.preview_renderer.set_birth_highlight_color(styles.colors.preview_birth);
+            self.preview_renderer.set_death_highlight_color(styles.colors.preview_death);
+        } else {
+            self.preview_renderer.set_birth_highlight_color(to_color32(appearance.birth_preview_color));
+            self.preview_renderer.set_death_highlight_color(to_color32(appearance.death_preview_color));
+        }
+        self.preview_renderer.set_survival_highlight_color(to_color32(appearance.survival_preview_color));
+        self.preview_renderer.set_show_survivors(appearance.show_survivors);
+    }
+    
+    /// Włącza kolorowanie żywych komórek według wieku (kolory: najmłodsze, najstarsze)
+    /// lub przywraca jednolity kolor (None)
+    pub fn set_age_colors(&mut self, colors: Option<(Color32, Color32)>) {
+        self.age_colors = colors;
+    }
+    
+    /// Zwraca aktualny współczynnik powiększenia
+    pub fn zoom(&self) -> f32 {
+        self.camera.map_or(1.0, |camera| camera.zoom)
+    }
+    
+    /// Przywraca domyślny widok (cała plansza, bez przesunięcia)
+    pub fn reset_view(&mut self) {
+        self.camera = None;
+    }
+    
+    /// Zwraca kamerę odpowiadającą bieżącemu widokowi
+    /// 
+    /// Gdy użytkownik nie przesuwał ani nie powiększał widoku, kamera jest wyznaczana
+    /// z domyślnego położenia planszy, więc widok domyślny jest zwykłym przypadkiem kamery.
+    fn current_camera(&self, board: &Board, available_rect: Rect, base_cell_size: f32) -> Camera {
+        self.camera.unwrap_or_else(|| {
+            let base_min = self.base_board_rect(board, available_rect, base_cell_size).min;
+            Camera {
+                center: ((available_rect.center() - base_min) / base_cell_size).to_pos2(),
+                zoom: 1.0,
+            }
+        })
+    }
+    
+    /// Oblicza położenie lewego górnego rogu planszy na ekranie dla danej kamery
+    fn board_origin(available_rect: Rect, center: Pos2, cell_size: f32) -> Pos2 {
+        available_rect.center() - center.to_vec2() * cell_size
+    }
+    
+    /// Centruje widok na podanej komórce i na chwilę wyróżnia ją znacznikiem
+    /// 
+    /// Zwraca false jeśli współrzędne leżą poza planszą - widok pozostaje wtedy bez zmian.
+    pub fn center_on_cell(&mut self, board: &Board, x: usize, y: usize) -> bool {
+        if !board.is_valid_coords(x, y) || self.view_rect == Rect::NOTHING {
+            return false;
+        }
+        
+        // Środek komórki ma wypaść w środku obszaru widoku przy aktualnym powiększeniu
+        self.camera = Some(Camera {
+            center: Pos2::new(x as f32 + 0.5, y as f32 + 0.5),
+            zoom: self.zoom(),
+        });
+        self.flash_marker = Some(((x, y), Instant::now()));
+        
+        true
+    }
+    
+    /// Obsługuje powiększanie kółkiem myszy i przesuwanie środkowym przyciskiem
+    /// 
+    /// Powiększenie zachowuje w miejscu punkt planszy znajdujący się pod kursorem.
+    fn handle_view_input(&mut self, ui: &egui::Ui, board: &Board, available_rect: Rect, base_cell_size: f32) {
+        let (pointer_pos, scroll_delta, zoom_delta, middle_pressed, middle_down, pointer_delta) = ui.input(|i| (
+            i.pointer.hover_pos(),
+            i.smooth_scroll_delta.y,
+            i.zoom_delta(),
+            i.pointer.button_pressed(egui::PointerButton::Middle),
+            i.pointer.middle_down(),
+            i.pointer.delta(),
+        ));
+        let pointer_over_board = pointer_pos.is_some_and(|pos| available_rect.contains(pos));
+        
+        // Przesuwanie - rozpoczęte nad planszą trwa aż do zwolnienia przycisku
+        if middle_pressed && pointer_over_board {
+            self.is_panning = true;
+        }
+        if !middle_down {
+            self.is_panning = false;
+        }
+        if self.is_panning && pointer_delta != Vec2::ZERO {
+            let mut camera = self.current_camera(board, available_rect, base_cell_size);
+            camera.center -= pointer_delta / (base_cell_size * camera.zoom);
+            self.camera = Some(camera);
+        }
+        
+        // Powiększanie
+        let Some(pointer_pos) = pointer_pos.filter(|_| pointer_over_board) else {
+            return;
+        };
+        let factor = zoom_delta * (scroll_delta * SCROLL_ZOOM_SPEED).exp();
+        if (factor - 1.0).abs() < f32::EPSILON {
+            return;
+        }
+        
+        let mut camera = self.current_camera(board, available_rect, base_cell_size);
+        let new_zoom = (camera.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        
+        // Punkt świata pod kursorem pozostaje pod kursorem po zmianie powiększenia
+        let pointer_offset = pointer_pos - available_rect.center();
+        let anchor = camera.center + pointer_offset / (base_cell_size * camera.zoom);
+        camera.center = anchor - pointer_offset / (base_cell_size * new_zoom);
+        camera.zoom = new_zoom;
+        self.camera = Some(camera);
+    }
+    
+    /// Ustawia czy zmiany powiększenia i przesunięcia widoku mają być animowane
+    pub fn set_smooth_zoom(&mut self, smooth: bool) {
+        self.smooth_zoom = smooth;
+    }
+    
+    /// Zwraca rozmiar komórki i środek kamery do użycia w bieżącej klatce
+    /// 
+    /// Przy włączonym płynnym powiększeniu wartości są interpolowane w kierunku
+    /// docelowych przez `ZOOM_ANIMATION_TIME`, w przeciwnym razie zwracane wprost.
+    fn animated_view(&self, ui: &egui::Ui, target_cell_size: f32, target_center: Pos2) -> (f32, Pos2) {
+        if !self.smooth_zoom {
+            return (target_cell_size, target_center);
+        }
+        
+        let ctx = ui.ctx();
+        let id = egui::Id::new("board_view_animation");
+        let cell_size = ctx.animate_value_with_time(id.with("cell_size"), target_cell_size, ZOOM_ANIMATION_TIME);
+        let center_x = ctx.animate_value_with_time(id.with("center_x"), target_center.x, ZOOM_ANIMATION_TIME);
+        let center_y = ctx.animate_value_with_time(id.with("center_y"), target_center.y, ZOOM_ANIMATION_TIME);
+        
+        (cell_size, Pos2::new(center_x, center_y))
+    }
+    
+    /// Oblicza domyślny prostokąt planszy (bez powiększenia i przesunięcia)
+    /// 
+    /// Plansza jest wyrównana do prawej strony i wyśrodkowana w pionie (szeroka plansza
+    /// nie przylega wtedy do górnej krawędzi), a gdy się nie mieści - wyśrodkowana.
+    fn base_board_rect(&self, board: &Board, available_rect: Rect, base_cell_size: f32) -> Rect {
+        let board_size = Vec2::new(
+            board.width() as f32 * base_cell_size,
+            board.height() as f32 * base_cell_size,
+        );
+        
+        let board_rect = Rect::from_min_size(
+            Pos2::new(
+                available_rect.max.x - board_size.x,
+                available_rect.center().y - board_size.y / 2.0,
+            ),
+            board_size,
+        );
+        
+        if board_rect.min.x < available_rect.min.x {
+            Rect::from_center_size(available_rect.center(), board_size)
+        } else {
+            board_rect
+        }
+    }
+    
     /// Renderuje planszę w podanym obszarze i zwraca informacje o interakcji myszy
     pub fn render_board(
         &mut self,
@@ -123,44 +528,61 @@ impl GameRenderer {
         show_deaths: bool,
         pattern_preview: Option<&Pattern>,
     ) -> MouseInteraction {
-        // Obliczamy optymalny rozmiar komórki na podstawie wysokości
-        let optimal_cell_size = self.calculate_optimal_cell_size(board, available_rect.height());
-        self.set_cell_size(optimal_cell_size);
+        // Obliczamy optymalny rozmiar komórki na podstawie przydzielonego obszaru
+        let optimal_cell_size = self.calculate_optimal_cell_size(board, available_rect.size());
+        self.view_rect = available_rect;
         
-        // Obliczamy rozmiar planszy w pikselach
-        let board_size = self.calculate_board_size(board);
+        // Kółko myszy powiększa widok, środkowy przycisk go przesuwa
+        self.handle_view_input(ui, board, available_rect, optimal_cell_size);
         
-        // Wyrównujemy planszę do prawej strony dostępnego obszaru
-        let board_rect = Rect::from_min_size(
-            Pos2::new(
-                available_rect.max.x - board_size.x,
-                available_rect.min.y,
-            ),
-            board_size,
+        // Docelowy rozmiar komórki i środek kamery - przy płynnym powiększeniu
+        // dochodzimy do nich stopniowo (egui sam żąda odświeżania podczas animacji)
+        let camera = self.current_camera(board, available_rect, optimal_cell_size);
+        let (cell_size, center) = self.animated_view(ui, optimal_cell_size * camera.zoom, camera.center);
+        self.set_cell_size(cell_size);
+        
+        // Położenie planszy wynika z kamery - plansza może leżeć częściowo lub całkiem poza widokiem
+        let final_board_rect = Rect::from_min_size(
+            Self::board_origin(available_rect, center, self.cell_size),
+            self.calculate_board_size(board),
         );
         
-        // Sprawdzamy czy plansza mieści się w dostępnym obszarze
-        let final_board_rect = if board_rect.min.x < available_rect.min.x {
-            // Jeśli plansza nie mieści się, centrujemy ją
-            Rect::from_center_size(available_rect.center(), board_size)
-        } else {
-            board_rect
-        };
+        // Przycinamy rysowanie do obszaru widoku, aby przesunięty widok nie nachodził na panel
+        ui.set_clip_rect(available_rect);
+        
+        // Obszar widoku poza planszą ma neutralny kolor
+        ui.painter().rect_filled(available_rect, 0.0, self.out_of_bounds_color);
+        
+        // Minimapa przykrywa róg widoku - komórki pod nią nie są edytowane
+        let minimap_rect = self.minimap_rect(board, available_rect, final_board_rect);
         
         // Sprawdzamy interakcje myszy PRZED renderowaniem, żeby móc użyć hover do podglądu wzoru
         let pointer_pos = ui.input(|i| i.pointer.interact_pos());
-        let hovered_cell = if let Some(pos) = pointer_pos {
-            self.screen_to_cell_coords(final_board_rect, pos)
-        } else {
-            None
+        let pointer_over_minimap = pointer_pos.zip(minimap_rect).is_some_and(|(pos, rect)| rect.contains(pos));
+        let hovered_cell = match pointer_pos {
+            Some(pos) if available_rect.contains(pos) && !pointer_over_minimap => {
+                self.screen_to_cell_coords(board, final_board_rect, pos)
+            }
+            _ => None,
         };
         
         // Renderujemy planszę
-        self.render_board_in_rect(ui, board, final_board_rect);
+        self.render_board_in_rect(ui, board, final_board_rect, available_rect);
         
         // Renderujemy podgląd wzoru jeśli jest wybrany i myszka jest nad planszą
         if let (Some(pattern), Some((hover_x, hover_y))) = (pattern_preview, hovered_cell) {
-            self.render_pattern_hover_preview(ui, pattern, final_board_rect, hover_x, hover_y);
+            self.render_pattern_hover_preview(ui, board, pattern, final_board_rect, hover_x, hover_y);
+        }
+        
+        // Renderujemy obrys pędzla pod kursorem (przy rysowaniu bez wybranego wzoru)
+        if let (BoardTool::Draw, None, Some((hover_x, hover_y))) = (self.tool, pattern_preview, hovered_cell) {
+            self.render_brush_outline(ui, board, final_board_rect, hover_x, hover_y);
+        }
+        
+        // Renderujemy zaznaczenie oraz podgląd wklejanego fragmentu
+        self.render_selection(ui, final_board_rect);
+        if let (BoardTool::Paste, Some((hover_x, hover_y))) = (self.tool, hovered_cell) {
+            self.render_paste_preview(ui, board, final_board_rect, hover_x, hover_y);
         }
         
         // Renderujemy podgląd następnego stanu jeśli jest dostępny
@@ -175,150 +597,545 @@ impl GameRenderer {
             );
         }
         
-        let clicked_cell = if ui.input(|i| i.pointer.any_click()) {
-            hovered_cell
-        } else {
-            None
-        };
+        // Renderujemy znacznik komórki, do której przeniesiono widok
+        self.render_flash_marker(ui, final_board_rect);
+        
+        // Renderujemy obramowanie komórki wskazywanej kursorem klawiatury
+        if let Some((cursor_x, cursor_y)) = self.keyboard_cursor {
+            let cell_rect = self.get_cell_rect(final_board_rect, cursor_x, cursor_y);
+            let stroke = Stroke::new(2.0, Color32::from_rgb(220, 20, 60));
+            ui.painter().rect_stroke(cell_rect, 0.0, stroke, egui::StrokeKind::Outside);
+        }
+        
+        // Minimapa jest rysowana na wierzchu planszy i nakładek
+        if let Some(minimap_rect) = minimap_rect {
+            self.render_minimap(ui, board, minimap_rect, final_board_rect, available_rect);
+        }
+        
+        // Kliknięcie środkowym przyciskiem kończy przesuwanie widoku - nie edytuje komórek
+        let clicked = ui.input(|i| i.pointer.any_click() && !i.pointer.button_clicked(egui::PointerButton::Middle));
+        let clicked_cell = if clicked { hovered_cell } else { None };
+        let clicked_outside_board = clicked
+            && hovered_cell.is_none()
+            && !pointer_over_minimap
+            && pointer_pos.is_some_and(|pos| available_rect.contains(pos));
+        
+        // Kliknięcie minimapy przenosi widok w wskazane miejsce planszy
+        if clicked
+            && let (Some(minimap_rect), Some(pos)) = (minimap_rect, pointer_pos)
+            && minimap_rect.contains(pos) {
+            let scale = minimap_rect.width() / board.width() as f32;
+            self.camera = Some(Camera {
+                center: ((pos - minimap_rect.min) / scale).to_pos2(),
+                zoom: self.zoom(),
+            });
+        }
         
         let is_mouse_down = ui.input(|i| i.pointer.primary_down());
         let mouse_pressed = ui.input(|i| i.pointer.primary_pressed());
         let mouse_released = ui.input(|i| i.pointer.primary_released());
         
+        // Skróty przekształcania wzoru działają tylko podczas jego umieszczania
+        // i gdy żadne pole tekstowe nie przejmuje klawiatury
+        let pattern_keys_active = pattern_preview.is_some() && !ui.ctx().wants_keyboard_input();
+        let rotate_pattern_pressed = pattern_keys_active && ui.input(|i| i.key_pressed(egui::Key::R));
+        let flip_pattern_pressed = pattern_keys_active && ui.input(|i| i.key_pressed(egui::Key::F));
+        
+        let hovered_state = hovered_cell.and_then(|(x, y)| board.get_cell(x, y));
+        
         MouseInteraction {
             clicked_cell,
+            clicked_outside_board,
             hovered_cell,
+            hovered_state,
             is_mouse_down,
             mouse_pressed,
             mouse_released,
+            rotate_pattern_pressed,
+            flip_pattern_pressed,
+        }
+    }
+    
+    /// Renderuje zanikający znacznik wokół komórki wskazanej przez "Go to"
+    fn render_flash_marker(&mut self, ui: &mut egui::Ui, board_rect: Rect) {
+        let Some(((x, y), started)) = self.flash_marker else {
+            return;
+        };
+        
+        let elapsed = started.elapsed();
+        if elapsed >= FLASH_MARKER_DURATION {
+            self.flash_marker = None;
+            return;
         }
+        
+        // Znacznik stopniowo zanika
+        let remaining = 1.0 - elapsed.as_secs_f32() / FLASH_MARKER_DURATION.as_secs_f32();
+        let alpha = (remaining * 255.0) as u8;
+        let cell_rect = self.get_cell_rect(board_rect, x, y);
+        let stroke = Stroke::new(3.0, Color32::from_rgba_unmultiplied(255, 140, 0, alpha));
+        ui.painter().rect_stroke(cell_rect.expand(self.cell_size * 0.5), 0.0, stroke, egui::StrokeKind::Outside);
+        
+        // Animacja wymaga ciągłego odświeżania
+        ui.ctx().request_repaint();
+    }
+    
+    /// Wyznacza położenie minimapy w prawym dolnym rogu widoku
+    /// 
+    /// Zwraca None, gdy minimapa jest wyłączona lub cała plansza mieści się w widoku.
+    fn minimap_rect(&self, board: &Board, available_rect: Rect, board_rect: Rect) -> Option<Rect> {
+        // Tolerancja jednego piksela pomija błędy zaokrągleń przy domyślnym widoku
+        if !self.show_minimap || board.total_cells() == 0 || available_rect.expand(1.0).contains_rect(board_rect) {
+            return None;
+        }
+        
+        let max_size = MINIMAP_MAX_SIZE.min(available_rect.width().min(available_rect.height()) / 3.0);
+        let scale = max_size / board.width().max(board.height()) as f32;
+        let size = Vec2::new(board.width() as f32 * scale, board.height() as f32 * scale);
+        let max = available_rect.max - Vec2::splat(MINIMAP_MARGIN);
+        Some(Rect::from_min_max(max - size, max))
+    }
+    
+    /// Renderuje pomniejszoną planszę z prostokątem aktualnie widocznego obszaru
+    /// 
+    /// Przy skali mniejszej niż piksel na komórkę kilka komórek trafia do jednego
+    /// bloku, więc liczba rysowanych prostokątów nie zależy od rozmiaru planszy.
+    fn render_minimap(&self, ui: &mut egui::Ui, board: &Board, minimap_rect: Rect, board_rect: Rect, view_rect: Rect) {
+        let painter = ui.painter();
+        let scale = minimap_rect.width() / board.width() as f32;
+        
+        painter.rect_filled(minimap_rect.expand(1.0), 0.0, self.grid_color);
+        painter.rect_filled(minimap_rect, 0.0, self.dead_color);
+        
+        // Bok bloku w komórkach - blok zajmuje na minimapie co najmniej jeden piksel
+        let block = (1.0 / scale).ceil().max(1.0) as usize;
+        let blocks_x = board.width().div_ceil(block);
+        let blocks_y = board.height().div_ceil(block);
+        let mut occupied = vec![false; blocks_x * blocks_y];
+        for (x, y, state) in board.iter_cells() {
+            if state == CellState::Alive {
+                occupied[(y / block) * blocks_x + x / block] = true;
+            }
+        }
+        
+        let block_size = block as f32 * scale;
+        for (index, _) in occupied.iter().enumerate().filter(|(_, occupied)| **occupied) {
+            let min = minimap_rect.min + Vec2::new((index % blocks_x) as f32, (index / blocks_x) as f32) * block_size;
+            let block_rect = Rect::from_min_size(min, Vec2::splat(block_size)).intersect(minimap_rect);
+            painter.rect_filled(block_rect, 0.0, self.alive_color);
+        }
+        
+        // Widoczny fragment planszy przeliczony na współrzędne minimapy
+        let visible = view_rect.intersect(board_rect);
+        if visible.is_positive() {
+            let to_minimap = |pos: Pos2| minimap_rect.min + (pos - board_rect.min) / self.cell_size * scale;
+            let viewport = Rect::from_min_max(to_minimap(visible.min), to_minimap(visible.max));
+            painter.rect_stroke(viewport, 0.0, Stroke::new(1.5, Color32::from_rgb(220, 20, 60)), egui::StrokeKind::Middle);
+        }
+    }
+    
+    /// Renderuje obramowanie zaznaczonego obszaru
+    fn render_selection(&self, ui: &mut egui::Ui, board_rect: Rect) {
+        let Some(selection) = self.selection else {
+            return;
+        };
+        
+        let (min_x, min_y, max_x, max_y) = selection.bounds();
+        let selection_rect = self.get_cell_rect(board_rect, min_x, min_y)
+            .union(self.get_cell_rect(board_rect, max_x, max_y));
+        
+        let painter = ui.painter();
+        painter.rect_filled(selection_rect, 0.0, Color32::from_rgba_unmultiplied(30, 144, 255, 40));
+        painter.rect_stroke(selection_rect, 0.0, Stroke::new(2.0, Color32::from_rgb(30, 144, 255)), egui::StrokeKind::Outside);
+    }
+    
+    /// Renderuje obrys komórek, które zmieni pędzel o środku w komórce pod kursorem
+    fn render_brush_outline(&self, ui: &mut egui::Ui, board: &Board, board_rect: Rect, hover_x: usize, hover_y: usize) {
+        if self.brush_size == BrushSize::Single {
+            return;
+        }
+        
+        let (min_x, min_y, max_x, max_y) = self.brush_size.bounds(hover_x, hover_y, board.width(), board.height());
+        let brush_rect = self.get_cell_rect(board_rect, min_x, min_y)
+            .union(self.get_cell_rect(board_rect, max_x, max_y));
+        ui.painter().rect_stroke(brush_rect, 0.0, Stroke::new(1.5, Color32::from_gray(140)), egui::StrokeKind::Outside);
+    }
+    
+    /// Renderuje podgląd wklejanego fragmentu w miejscu, w którym zostanie umieszczony
+    fn render_paste_preview(&self, ui: &mut egui::Ui, board: &Board, board_rect: Rect, hover_x: usize, hover_y: usize) {
+        let Some(clipboard) = &self.paste_preview else {
+            return;
+        };
+        
+        let painter = ui.painter();
+        let (origin_x, origin_y) = clipboard.clamped_origin(board, hover_x, hover_y);
+        
+        // Żywe komórki fragmentu (półprzezroczyste)
+        for (dx, dy, state) in clipboard.iter_cells() {
+            if state == CellState::Alive && board.is_valid_coords(origin_x + dx, origin_y + dy) {
+                let cell_rect = self.get_cell_rect(board_rect, origin_x + dx, origin_y + dy);
+                painter.rect_filled(cell_rect, 0.0, Color32::from_rgba_unmultiplied(30, 144, 255, 140));
+            }
+        }
+        
+        // Obramowanie obszaru, który zostanie nadpisany
+        let max_x = (origin_x + clipboard.width()).min(board.width()).saturating_sub(1);
+        let max_y = (origin_y + clipboard.height()).min(board.height()).saturating_sub(1);
+        let paste_rect = self.get_cell_rect(board_rect, origin_x, origin_y)
+            .union(self.get_cell_rect(board_rect, max_x, max_y));
+        painter.rect_stroke(paste_rect, 0.0, Stroke::new(1.5, Color32::from_rgb(30, 144, 255)), egui::StrokeKind::Outside);
     }
     
     /// Renderuje podgląd wzoru pod kursorem myszy
     fn render_pattern_hover_preview(
         &self,
         ui: &mut egui::Ui,
+        board: &Board,
         pattern: &Pattern,
         board_rect: Rect,
         hover_x: usize,
         hover_y: usize,
     ) {
         let painter = ui.painter();
-        let center_pos = crate::assets::Position::new(hover_x as i32, hover_y as i32);
+        let mut center_pos = crate::assets::Position::new(hover_x as i32, hover_y as i32);
+        if self.clamp_pattern_preview {
+            center_pos = board.clamp_pattern_center(center_pos, pattern);
+        }
         
         // Podświetlamy centrum wzoru (żółty)
-        let center_cell_rect = self.get_cell_rect(board_rect, hover_x, hover_y);
+        let center_cell_rect = self.get_cell_rect(board_rect, center_pos.x as usize, center_pos.y as usize);
         painter.rect_filled(center_cell_rect, 0.0, Color32::YELLOW);
         
         // Renderujemy podgląd wzoru (półprzezroczyste komórki)
         let pattern_cells = pattern.get_cells_at_center(center_pos);
         for pos in pattern_cells {
-            if pos.x >= 0 && pos.y >= 0 {
-                let x = pos.x as usize;
-                let y = pos.y as usize;
-                
+            if let Some((x, y)) = self.preview_position_to_cell(board, pos) {
                 let cell_rect = self.get_cell_rect(board_rect, x, y);
-                // Sprawdzamy czy komórka jest w granicach planszy
-                if board_rect.contains(cell_rect.center()) {
-                    painter.rect_filled(cell_rect, 0.0, Color32::from_rgba_unmultiplied(0, 255, 0, 100));
-                }
+                painter.rect_filled(cell_rect, 0.0, Color32::from_rgba_unmultiplied(0, 255, 0, 100));
             }
         }
         
         // Renderujemy obszar, który zostanie wyczyszczony (półprzezroczyste czerwone)
         let clear_area = pattern.get_clear_area(center_pos);
         for pos in clear_area {
-            if pos.x >= 0 && pos.y >= 0 {
-                let x = pos.x as usize;
-                let y = pos.y as usize;
-                
+            if let Some((x, y)) = self.preview_position_to_cell(board, pos) {
                 let cell_rect = self.get_cell_rect(board_rect, x, y);
-                // Sprawdzamy czy komórka jest w granicach planszy
-                if board_rect.contains(cell_rect.center()) {
-                    let stroke = Stroke::new(1.0, Color32::from_rgba_unmultiplied(255, 0, 0, 150));
-                    painter.rect_stroke(cell_rect, 0.0, stroke, egui::StrokeKind::Inside);
-                }
+                let stroke = Stroke::new(1.0, Color32::from_rgba_unmultiplied(255, 0, 0, 150));
+                painter.rect_stroke(cell_rect, 0.0, stroke, egui::StrokeKind::Inside);
+            }
+        }
+    }
+    
+    /// Mapuje pozycję komórki podglądu wzoru na współrzędne planszy
+    /// 
+    /// Przy włączonym zawijaniu pozycje spoza planszy są przenoszone na przeciwległą
+    /// krawędź (modulo wymiary planszy), w przeciwnym razie są pomijane.
+    fn preview_position_to_cell(&self, board: &Board, pos: crate::assets::Position) -> Option<(usize, usize)> {
+        if self.wrap_pattern_preview {
+            let width = board.width() as i32;
+            let height = board.height() as i32;
+            if width == 0 || height == 0 {
+                return None;
             }
+            return Some((pos.x.rem_euclid(width) as usize, pos.y.rem_euclid(height) as usize));
+        }
+        
+        if pos.x >= 0 && pos.y >= 0 && board.is_valid_coords(pos.x as usize, pos.y as usize) {
+            Some((pos.x as usize, pos.y as usize))
+        } else {
+            None
         }
     }
     
     /// Renderuje planszę w określonym prostokącie
+    /// 
+    /// Rysowane są tylko komórki i linie siatki widoczne w obszarze `view_rect`.
+    /// Kwadratowe komórki pochodzą z `raster::rasterize_board`, więc okno pokazuje
+    /// ten sam obraz, który sprawdzają testy rasteryzacji.
     fn render_board_in_rect(
-        &self,
+        &mut self,
         ui: &mut egui::Ui,
         board: &Board,
         rect: Rect,
+        view_rect: Rect,
     ) {
-        let painter = ui.painter();
-        
-        // Renderujemy tło planszy
-        painter.rect_filled(rect, 0.0, self.dead_color);
+        let Some((x_range, y_range)) = self.visible_cell_range(board, rect, view_rect) else {
+            return;
+        };
         
-        // Renderujemy komórki
-        for (x, y, state) in board.iter_cells() {
-            let cell_rect = self.get_cell_rect(rect, x, y);
-            
-            match state {
-                CellState::Alive => {
-                    painter.rect_filled(cell_rect, 0.0, self.alive_color);
-                }
-                CellState::Dead => {
-                    // Martwe komórki są już wyrenderowane jako tło
+        // Renderujemy komórki (przy bardzo małych komórkach zawsze jako kwadraty)
+        let cell_shape = if self.cell_size < MIN_SHAPED_CELL_SIZE {
+            CellShape::Square
+        } else {
+            self.cell_shape
+        };
+        match cell_shape {
+            CellShape::Square => self.render_rasterized_cells(ui, board, rect),
+            CellShape::RoundedSquare | CellShape::Circle => {
+                let painter = ui.painter();
+                
+                // Renderujemy tło planszy
+                painter.rect_filled(rect.intersect(view_rect), 0.0, self.dead_color);
+                
+                for y in y_range.clone() {
+                    for x in x_range.clone() {
+                        // Martwe komórki są już wyrenderowane jako tło
+                        if board.get_cell(x, y) == Some(CellState::Alive) {
+                            let cell_rect = self.get_cell_rect(rect, x, y);
+                            let color = self.alive_cell_color(board, x, y);
+                            if cell_shape == CellShape::Circle {
+                                painter.circle_filled(cell_rect.center(), self.cell_size * 0.5, color);
+                            } else {
+                                painter.rect_filled(cell_rect, self.cell_size * ROUNDED_CELL_RADIUS_RATIO, color);
+                            }
+                        }
+                    }
                 }
             }
         }
         
-        // Renderujemy siatkę
-        self.render_grid(ui, board, rect);
+        self.render_expansion_margin(ui, board, rect, view_rect);
+        
+        // Renderujemy siatkę (przy bardzo małych komórkach tylko linie główne)
+        if self.show_grid {
+            self.render_grid(ui, rect, x_range, y_range);
+        }
     }
     
-    /// Renderuje siatkę na planszy
-    fn render_grid(&self, ui: &mut egui::Ui, board: &Board, rect: Rect) {
+    /// Rysuje planszę jako teksturę otrzymaną z `raster::rasterize_board`
+    /// 
+    /// Obraz ma jeden piksel na komórkę i jest rozciągany na prostokąt planszy
+    /// bez wygładzania, więc każda komórka zajmuje dokładnie swój prostokąt z `raster::cell_rect`.
+    fn render_rasterized_cells(&mut self, ui: &mut egui::Ui, board: &Board, rect: Rect) {
+        let image = raster::rasterize_board(board, 1.0, self.dead_color.to_srgba_unmultiplied(), |x, y| {
+            self.alive_cell_color(board, x, y).to_srgba_unmultiplied()
+        });
+        let image = ColorImage::from_rgba_unmultiplied([image.width(), image.height()], &image.as_bytes());
+        
+        let texture = match &mut self.board_texture {
+            Some(texture) => {
+                texture.set(image, TextureOptions::NEAREST);
+                texture
+            }
+            None => self.board_texture.insert(ui.ctx().load_texture("board_cells", image, TextureOptions::NEAREST)),
+        };
+        let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+        ui.painter().image(texture.id(), rect, uv, Color32::WHITE);
+    }
+    
+    /// Renderuje półprzezroczysty pas przy krawędziach, w którym żywe komórki
+    /// powodują rozszerzenie planszy (tryb Dynamic)
+    /// 
+    /// Gdy plansza nie może już rosnąć, pas ma kolor ostrzeżenia.
+    fn render_expansion_margin(&self, ui: &mut egui::Ui, board: &Board, rect: Rect, view_rect: Rect) {
+        let Some((margin, at_limit)) = self.expansion_margin else {
+            return;
+        };
+        if margin == 0 {
+            return;
+        }
+        
+        let color = if at_limit {
+            self.expansion_limit_color
+        } else {
+            self.expansion_margin_color
+        };
+        let band_x = (margin.min(board.width()) as f32 * self.cell_size).min(rect.width() / 2.0);
+        let band_y = (margin.min(board.height()) as f32 * self.cell_size).min(rect.height() / 2.0);
+        
+        // Górny i dolny pas na całą szerokość, boczne tylko pomiędzy nimi
+        let bands = [
+            Rect::from_min_max(rect.min, Pos2::new(rect.max.x, rect.min.y + band_y)),
+            Rect::from_min_max(Pos2::new(rect.min.x, rect.max.y - band_y), rect.max),
+            Rect::from_min_max(Pos2::new(rect.min.x, rect.min.y + band_y), Pos2::new(rect.min.x + band_x, rect.max.y - band_y)),
+            Rect::from_min_max(Pos2::new(rect.max.x - band_x, rect.min.y + band_y), Pos2::new(rect.max.x, rect.max.y - band_y)),
+        ];
+        for band in bands {
+            let visible = band.intersect(view_rect);
+            if visible.is_positive() {
+                ui.painter().rect_filled(visible, 0.0, color);
+            }
+        }
+    }
+    
+    /// Zwraca zakresy kolumn i wierszy planszy widocznych w obszarze widoku
+    /// 
+    /// Zwraca None, gdy plansza leży całkowicie poza widokiem.
+    fn visible_cell_range(
+        &self,
+        board: &Board,
+        rect: Rect,
+        view_rect: Rect,
+    ) -> Option<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+        let visible = rect.intersect(view_rect);
+        if !visible.is_positive() {
+            return None;
+        }
+        
+        let to_range = |start: f32, end: f32, origin: f32, len: usize| {
+            let first = ((start - origin) / self.cell_size).floor().max(0.0) as usize;
+            let last = ((end - origin) / self.cell_size).ceil().max(0.0) as usize;
+            first.min(len)..last.min(len)
+        };
+        
+        Some((
+            to_range(visible.min.x, visible.max.x, rect.min.x, board.width()),
+            to_range(visible.min.y, visible.max.y, rect.min.y, board.height()),
+        ))
+    }
+    
+    /// Zwraca kolor żywej komórki - jednolity lub zależny od jej wieku
+    fn alive_cell_color(&self, board: &Board, x: usize, y: usize) -> Color32 {
+        match self.age_colors {
+            Some((young, old)) => {
+                let age = board.cell_age(x, y).unwrap_or(0).min(AGE_GRADIENT_SPAN);
+                young.lerp_to_gamma(old, age as f32 / AGE_GRADIENT_SPAN as f32)
+            }
+            None => self.alive_color,
+        }
+    }
+    
+    /// Renderuje siatkę na widocznym fragmencie planszy
+    /// 
+    /// Linie są rysowane na granicach komórek po narysowaniu komórek, więc ich
+    /// grubość nie przesuwa prostokątów komórek. Co `major_grid_interval` komórek
+    /// rysowana jest grubsza i ciemniejsza linia główna; przy małych komórkach
+    /// zwykłe linie są pomijane i zostają tylko linie główne.
+    fn render_grid(
+        &self,
+        ui: &mut egui::Ui,
+        rect: Rect,
+        x_range: std::ops::Range<usize>,
+        y_range: std::ops::Range<usize>,
+    ) {
         let painter = ui.painter();
+        let top = rect.min.y + y_range.start as f32 * self.cell_size;
+        let bottom = rect.min.y + y_range.end as f32 * self.cell_size;
+        let left = rect.min.x + x_range.start as f32 * self.cell_size;
+        let right = rect.min.x + x_range.end as f32 * self.cell_size;
+        
+        let show_minor = self.cell_size >= MIN_GRID_CELL_SIZE
+            && self.cell_size >= self.grid_stroke.width * MIN_CELL_TO_GRID_THICKNESS_RATIO;
+        let major_interval = self.major_grid_interval
+            .filter(|&interval| interval as f32 * self.cell_size >= MIN_GRID_CELL_SIZE);
+        let major_stroke = Stroke::new(
+            (self.grid_stroke.width * 2.0).min(MAX_GRID_THICKNESS),
+            self.grid_color.lerp_to_gamma(Color32::BLACK, MAJOR_GRID_DARKEN),
+        );
+        
+        // Zwraca obrys linii o podanym indeksie (None - linia nie jest rysowana)
+        let line_stroke = |index: usize| match major_interval {
+            Some(interval) if index.is_multiple_of(interval) => Some(major_stroke),
+            _ if show_minor => Some(self.grid_stroke),
+            _ => None,
+        };
         
         // Linie pionowe
-        for x in 0..=board.width() {
-            let x_pos = rect.min.x + x as f32 * self.cell_size;
-            painter.line_segment(
-                [Pos2::new(x_pos, rect.min.y), Pos2::new(x_pos, rect.max.y)],
-                self.grid_stroke,
-            );
+        for x in x_range.start..=x_range.end {
+            if let Some(stroke) = line_stroke(x) {
+                let x_pos = rect.min.x + x as f32 * self.cell_size;
+                painter.line_segment([Pos2::new(x_pos, top), Pos2::new(x_pos, bottom)], stroke);
+            }
         }
         
         // Linie poziome
-        for y in 0..=board.height() {
-            let y_pos = rect.min.y + y as f32 * self.cell_size;
-            painter.line_segment(
-                [Pos2::new(rect.min.x, y_pos), Pos2::new(rect.max.x, y_pos)],
-                self.grid_stroke,
-            );
+        for y in y_range.start..=y_range.end {
+            if let Some(stroke) = line_stroke(y) {
+                let y_pos = rect.min.y + y as f32 * self.cell_size;
+                painter.line_segment([Pos2::new(left, y_pos), Pos2::new(right, y_pos)], stroke);
+            }
         }
     }
     
     /// Oblicza prostokąt dla pojedynczej komórki
+    /// 
+    /// Geometria pochodzi z `raster::cell_rect`, więc obraz z `raster::rasterize_board`
+    /// ma komórki w tych samych miejscach co plansza w oknie.
     fn get_cell_rect(&self, board_rect: Rect, x: usize, y: usize) -> Rect {
-        let cell_min = Pos2::new(
-            board_rect.min.x + x as f32 * self.cell_size,
-            board_rect.min.y + y as f32 * self.cell_size,
-        );
-        
-        Rect::from_min_size(cell_min, Vec2::splat(self.cell_size))
+        let cell = raster::cell_rect((board_rect.min.x, board_rect.min.y), self.cell_size, x, y);
+        Rect::from_min_size(Pos2::new(cell.min.0, cell.min.1), Vec2::splat(cell.size))
     }
     
     /// Konwertuje pozycję myszy na współrzędne komórki
+    /// 
+    /// Prostokąt planszy jest półotwarty: punkt leżący dokładnie na prawej lub dolnej
+    /// krawędzi nie należy do żadnej komórki, więc zwracane współrzędne zawsze leżą na planszy.
     pub fn screen_to_cell_coords(
         &self,
+        board: &Board,
         board_rect: Rect,
         screen_pos: Pos2,
     ) -> Option<(usize, usize)> {
-        if !board_rect.contains(screen_pos) {
-            return None;
-        }
-        
-        let relative_pos = screen_pos - board_rect.min;
-        let x = (relative_pos.x / self.cell_size) as usize;
-        let y = (relative_pos.y / self.cell_size) as usize;
-        
-        Some((x, y))
+        position_to_cell(board_rect.min, self.cell_size, board.width(), board.height(), screen_pos)
+    }
+}
+
+/// Wyznacza komórkę planszy leżącą pod punktem ekranu
+/// 
+/// `origin` to lewy górny róg planszy. Zakres komórek jest półotwarty, więc punkt
+/// na prawej lub dolnej krawędzi planszy (albo dalej) zwraca None.
+fn position_to_cell(
+    origin: Pos2,
+    cell_size: f32,
+    width: usize,
+    height: usize,
+    position: Pos2,
+) -> Option<(usize, usize)> {
+    let relative_pos = position - origin;
+    if !(relative_pos.x >= 0.0 && relative_pos.y >= 0.0) {
+        return None;
+    }
+    
+    let x = (relative_pos.x / cell_size) as usize;
+    let y = (relative_pos.y / cell_size) as usize;
+    
+    (x < width && y < height).then_some((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const CELL_SIZE: f32 = 10.0;
+    const WIDTH: usize = 7;
+    const HEIGHT: usize = 5;
+    
+    /// Prostokąt planszy WIDTH x HEIGHT przesunięty względem początku ekranu
+    fn board_rect() -> Rect {
+        Rect::from_min_size(
+            Pos2::new(15.0, 30.0),
+            Vec2::new(WIDTH as f32 * CELL_SIZE, HEIGHT as f32 * CELL_SIZE),
+        )
+    }
+    
+    fn cell_at(rect: Rect, position: Pos2) -> Option<(usize, usize)> {
+        position_to_cell(rect.min, CELL_SIZE, WIDTH, HEIGHT, position)
+    }
+    
+    #[test]
+    fn top_left_corner_is_the_first_cell() {
+        let rect = board_rect();
+        assert_eq!(cell_at(rect, rect.min), Some((0, 0)));
+    }
+    
+    #[test]
+    fn point_just_inside_the_far_corner_is_the_last_cell() {
+        let rect = board_rect();
+        let position = rect.max - Vec2::splat(0.001);
+        assert_eq!(cell_at(rect, position), Some((WIDTH - 1, HEIGHT - 1)));
+    }
+    
+    #[test]
+    fn far_edges_do_not_belong_to_any_cell() {
+        let rect = board_rect();
+        assert_eq!(cell_at(rect, rect.max), None);
+        assert_eq!(cell_at(rect, Pos2::new(rect.max.x, rect.min.y)), None);
+        assert_eq!(cell_at(rect, Pos2::new(rect.min.x, rect.max.y)), None);
+    }
+    
+    #[test]
+    fn points_before_the_board_are_rejected() {
+        let rect = board_rect();
+        assert_eq!(cell_at(rect, rect.min - Vec2::new(0.001, 0.0)), None);
+        assert_eq!(cell_at(rect, rect.min - Vec2::new(0.0, 0.001)), None);
     }
 }
\ No newline at end of file
@@ -3,7 +3,11 @@
 /// Zawiera komponenty UI do edycji zasad gry i ustawień planszy.
 
 use egui::{Slider, RichText, Color32};
-use crate::config::{BoardSizeMode, modify_config, get_config};
+use std::collections::HashSet;
+use crate::config::{AppearanceConfig, BoardSizeMode, CellShape, BoundaryMode, NeighborhoodType, NeighborMask, RandomFillShape};
+use crate::config::initial_state::get_available_patterns;
+use crate::config::rules::{GameConfig, MAX_BOARD_DIMENSION, MAX_EXPANSION_LAYERS, MAX_EXPANSION_MARGIN, MAX_NEIGHBOR_BONUS, MIN_BOARD_DIMENSION, RULE_PRESETS};
+use crate::logic::randomizer;
 use super::styles::{UIStyles, ButtonType, TextType, helpers};
 
 /// Akcje związane z ustawieniami
@@ -15,8 +19,8 @@ pub enum SettingsAction {
     RulesChanged,
     /// Zmieniono ustawienia planszy
     BoardSettingsChanged,
-    /// Zmieniono rozmiar planszy (nowy rozmiar)
-    BoardSizeChanged(usize),
+    /// Zmieniono rozmiar planszy (nowa szerokość i wysokość)
+    BoardSizeChanged(usize, usize),
     /// Zresetuj zasady gry do wartości domyślnych
     ResetRules,
     /// Zresetuj ustawienia planszy do wartości domyślnych
@@ -25,6 +29,18 @@ pub enum SettingsAction {
     RandomizerChanged,
     /// Zresetuj ustawienia randomizera do wartości domyślnych
     ResetRandomizer,
+    /// Zmieniono kolory planszy
+    AppearanceChanged,
+    /// Zresetuj kolory planszy do wartości domyślnych
+    ResetAppearance,
+    /// Przytnij planszę do żywych komórek (z marginesem)
+    TrimBoard,
+    /// Dodaj podaną liczbę pustych warstw komórek dookoła planszy
+    AddBoardLayers(usize),
+    /// Wylosuj planszę ponownie z tymi samymi parametrami
+    RegenerateBoard,
+    /// Zastąp planszę wybranym wzorem startowym
+    ApplyStartupPattern,
 }
 
 /// Panel ustawień gry
@@ -37,67 +53,200 @@ pub struct SettingsPanel {
     board_settings_expanded: bool,
     /// Czy sekcja randomizera jest rozwinięta
     randomizer_expanded: bool,
+    /// Czy sekcja wyglądu jest rozwinięta
+    appearance_expanded: bool,
+    /// Czy sekcja reguł probabilistycznych jest rozwinięta
+    stochastic_expanded: bool,
+    /// Czy symulacja jest zatrzymana (narzędzia planszy są wtedy dostępne)
+    simulation_stopped: bool,
+    /// Liczba warstw dodawanych przyciskiem "Add N Layers"
+    layers_to_add: usize,
     
     // Lokalne kopie wartości do edycji
     birth_min: usize,
     birth_max: usize,
     survival_min: usize,
     survival_max: usize,
+    /// Tekst reguły w polu edycji (np. "B36/S23")
+    rule_input: String,
+    /// Komunikat błędu ostatniego parsowania reguły
+    rule_error: Option<String>,
     board_mode: BoardSizeMode,
+    boundary_mode: BoundaryMode,
+    /// Wybrany rodzaj sąsiedztwa (określa zakres suwaków reguł)
+    neighborhood: NeighborhoodType,
     max_board_size: usize,
     initial_board_size: usize,
-    static_board_size: usize,
+    static_board_width: usize,
+    static_board_height: usize,
+    /// Odległość żywych komórek od krawędzi, przy której plansza jest rozszerzana
+    expansion_margin: usize,
+    /// Liczba warstw dodawanych przy jednym rozszerzeniu
+    expansion_layers: usize,
+    
+    // Stochastic settings
+    birth_probability: f32,
+    survival_probability: f32,
+    use_stochastic_seed: bool,
+    stochastic_seed: u64,
     
     // Randomizer settings
     base_probability: f32,
     neighbor_bonus: f32,
+    use_fixed_seed: bool,
+    seed: u64,
+    /// Obszar planszy, w którym losowane są żywe komórki
+    fill_shape: RandomFillShape,
+    /// Czy losowanie dodaje komórki do aktualnej planszy
+    additive_fill: bool,
+    /// Oszacowany ułamek żywych komórek dla aktualnych parametrów randomizera
+    expected_density: f32,
+    /// Liczba żywych komórek i wszystkich komórek po ostatnim losowaniu
+    last_fill: Option<(usize, usize)>,
+    
+    // Appearance settings
+    alive_color: Color32,
+    dead_color: Color32,
+    grid_color: Color32,
+    cell_shape: CellShape,
+    match_theme: bool,
+    themed_preview_colors: bool,
+    /// Kolory podglądu (RGB i krycie) - przechowywane jako RGBA bez premultiplikacji,
+    /// aby zerowe krycie nie gubiło koloru
+    birth_preview_color: [u8; 4],
+    death_preview_color: [u8; 4],
+    survival_preview_color: [u8; 4],
+    show_survivors: bool,
+    show_expansion_margin: bool,
 }
 
 impl Default for SettingsPanel {
     fn default() -> Self {
-        let config = get_config();
+        Self::from_config(&GameConfig::default())
+    }
+}
+
+impl SettingsPanel {
+    /// Tworzy nowy panel ustawień
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Tworzy panel ustawień z wartościami z podanej konfiguracji
+    pub fn from_config(config: &GameConfig) -> Self {
+        let (birth_min, birth_max) = neighbor_bounds(&config.birth_neighbors);
+        let (survival_min, survival_max) = neighbor_bounds(&config.survival_neighbors);
         Self {
             settings_expanded: false,
             rules_expanded: false,
             board_settings_expanded: false,
             randomizer_expanded: false,
-            birth_min: *config.birth_neighbors.start(),
-            birth_max: *config.birth_neighbors.end(),
-            survival_min: *config.survival_neighbors.start(),
-            survival_max: *config.survival_neighbors.end(),
+            appearance_expanded: false,
+            stochastic_expanded: false,
+            simulation_stopped: true,
+            layers_to_add: 5,
+            birth_min,
+            birth_max,
+            survival_min,
+            survival_max,
+            rule_input: config.rule_string(),
+            rule_error: None,
+            neighborhood: config.neighborhood,
             board_mode: config.board_size_mode,
+            boundary_mode: config.boundary_mode,
             max_board_size: config.max_board_size,
             initial_board_size: config.initial_board_size,
-            static_board_size: config.static_board_size,
+            static_board_width: config.static_board_width,
+            static_board_height: config.static_board_height,
+            expansion_margin: config.expansion_margin,
+            expansion_layers: config.expansion_layers,
+            birth_probability: config.birth_probability,
+            survival_probability: config.survival_probability,
+            use_stochastic_seed: config.stochastic_seed.is_some(),
+            stochastic_seed: config.stochastic_seed.unwrap_or(0),
             base_probability: config.randomizer_config.base_probability,
             neighbor_bonus: config.randomizer_config.neighbor_bonus,
+            use_fixed_seed: config.randomizer_config.seed.is_some(),
+            seed: config.randomizer_config.seed.unwrap_or(0),
+            fill_shape: config.randomizer_config.shape,
+            additive_fill: config.randomizer_config.additive,
+            expected_density: randomizer::estimate_density(config),
+            last_fill: None,
+            alive_color: color_from_config(config.appearance.alive_color),
+            dead_color: color_from_config(config.appearance.dead_color),
+            grid_color: color_from_config(config.appearance.grid_color),
+            cell_shape: config.appearance.cell_shape,
+            match_theme: config.appearance.match_theme,
+            themed_preview_colors: config.appearance.themed_preview_colors,
+            birth_preview_color: config.appearance.birth_preview_color,
+            death_preview_color: config.appearance.death_preview_color,
+            survival_preview_color: config.appearance.survival_preview_color,
+            show_survivors: config.appearance.show_survivors,
+            show_expansion_margin: config.appearance.show_expansion_margin,
         }
     }
-}
-
-impl SettingsPanel {
-    /// Tworzy nowy panel ustawień
-    pub fn new() -> Self {
-        Self::default()
-    }
     
-    /// Synchronizuje lokalne wartości z globalną konfiguracją
-    pub fn sync_with_config(&mut self) {
-        let config = get_config();
-        self.birth_min = *config.birth_neighbors.start();
-        self.birth_max = *config.birth_neighbors.end();
-        self.survival_min = *config.survival_neighbors.start();
-        self.survival_max = *config.survival_neighbors.end();
+    /// Synchronizuje lokalne wartości z podaną konfiguracją (np. po przełączeniu zakładki)
+    pub fn sync_with_config(&mut self, config: &GameConfig) {
+        self.load_rules_from(config);
         self.board_mode = config.board_size_mode;
+        self.boundary_mode = config.boundary_mode;
         self.max_board_size = config.max_board_size;
         self.initial_board_size = config.initial_board_size;
-        self.static_board_size = config.static_board_size;
+        self.static_board_width = config.static_board_width;
+        self.static_board_height = config.static_board_height;
+        self.expansion_margin = config.expansion_margin;
+        self.expansion_layers = config.expansion_layers;
+        self.birth_probability = config.birth_probability;
+        self.survival_probability = config.survival_probability;
+        self.use_stochastic_seed = config.stochastic_seed.is_some();
+        self.stochastic_seed = config.stochastic_seed.unwrap_or(self.stochastic_seed);
         self.base_probability = config.randomizer_config.base_probability;
         self.neighbor_bonus = config.randomizer_config.neighbor_bonus;
+        self.use_fixed_seed = config.randomizer_config.seed.is_some();
+        self.seed = config.randomizer_config.seed.unwrap_or(self.seed);
+        self.fill_shape = config.randomizer_config.shape;
+        self.additive_fill = config.randomizer_config.additive;
+        self.expected_density = randomizer::estimate_density(config);
+        self.load_appearance_from(&config.appearance);
+    }
+    
+    /// Ustawia czy symulacja jest zatrzymana
+    pub fn set_simulation_stopped(&mut self, stopped: bool) {
+        self.simulation_stopped = stopped;
+    }
+    
+    /// Zapisuje wynik ostatniego losowania planszy (liczba żywych komórek, liczba wszystkich komórek)
+    pub fn set_last_fill(&mut self, alive_cells: usize, total_cells: usize) {
+        self.last_fill = Some((alive_cells, total_cells));
+    }
+    
+    /// Ustawia lokalne kopie kolorów na podstawie konfiguracji wyglądu
+    fn load_appearance_from(&mut self, appearance: &AppearanceConfig) {
+        self.alive_color = color_from_config(appearance.alive_color);
+        self.dead_color = color_from_config(appearance.dead_color);
+        self.grid_color = color_from_config(appearance.grid_color);
+        self.cell_shape = appearance.cell_shape;
+        self.match_theme = appearance.match_theme;
+        self.themed_preview_colors = appearance.themed_preview_colors;
+        self.birth_preview_color = appearance.birth_preview_color;
+        self.death_preview_color = appearance.death_preview_color;
+        self.survival_preview_color = appearance.survival_preview_color;
+        self.show_survivors = appearance.show_survivors;
+        self.show_expansion_margin = appearance.show_expansion_margin;
+    }
+    
+    /// Ustawia lokalne wartości reguł (suwaki i pole tekstowe) na podstawie konfiguracji
+    fn load_rules_from(&mut self, config: &GameConfig) {
+        (self.birth_min, self.birth_max) = neighbor_bounds(&config.birth_neighbors);
+        (self.survival_min, self.survival_max) = neighbor_bounds(&config.survival_neighbors);
+        self.neighborhood = config.neighborhood;
+        self.rule_input = config.rule_string();
+        self.rule_error = None;
     }
     
     /// Renderuje panel ustawień
-    pub fn render(&mut self, ui: &mut egui::Ui) -> SettingsAction {
+    pub fn render(&mut self, ui: &mut egui::Ui, config: &mut GameConfig) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         // Główna sekcja ustawień (zwijalna)
@@ -118,12 +267,12 @@ impl SettingsPanel {
                 ui.separator();
                 
                 // Sekcja zasad gry
-                action = self.render_rules_section(ui).max(action);
+                action = self.render_rules_section(ui, config).max(action);
                 
                 ui.separator();
                 
                 // Sekcja ustawień planszy
-                action = self.render_board_settings_section(ui).max(action);
+                action = self.render_board_settings_section(ui, config).max(action);
             }
         });
         
@@ -131,7 +280,7 @@ impl SettingsPanel {
     }
     
     /// Renderuje sekcję zasad gry
-    fn render_rules_section(&mut self, ui: &mut egui::Ui) -> SettingsAction {
+    fn render_rules_section(&mut self, ui: &mut egui::Ui, config: &mut GameConfig) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         ui.horizontal(|ui| {
@@ -159,7 +308,7 @@ impl SettingsPanel {
                 ui.label(RichText::new("Birth Neighbors:").strong());
                 ui.horizontal(|ui| {
                     ui.label("Min:");
-                    if ui.add(Slider::new(&mut self.birth_min, 0..=8)).changed() {
+                    if ui.add(Slider::new(&mut self.birth_min, 0..=self.neighborhood.max_neighbors())).changed() {
                         if self.birth_min > self.birth_max {
                             self.birth_max = self.birth_min;
                         }
@@ -167,7 +316,7 @@ impl SettingsPanel {
                     }
                     
                     ui.label("Max:");
-                    if ui.add(Slider::new(&mut self.birth_max, 0..=8)).changed() {
+                    if ui.add(Slider::new(&mut self.birth_max, 0..=self.neighborhood.max_neighbors())).changed() {
                         if self.birth_max < self.birth_min {
                             self.birth_min = self.birth_max;
                         }
@@ -189,7 +338,7 @@ impl SettingsPanel {
                 ui.label(RichText::new("Survival Neighbors:").strong());
                 ui.horizontal(|ui| {
                     ui.label("Min:");
-                    if ui.add(Slider::new(&mut self.survival_min, 0..=8)).changed() {
+                    if ui.add(Slider::new(&mut self.survival_min, 0..=self.neighborhood.max_neighbors())).changed() {
                         if self.survival_min > self.survival_max {
                             self.survival_max = self.survival_min;
                         }
@@ -197,7 +346,7 @@ impl SettingsPanel {
                     }
                     
                     ui.label("Max:");
-                    if ui.add(Slider::new(&mut self.survival_max, 0..=8)).changed() {
+                    if ui.add(Slider::new(&mut self.survival_max, 0..=self.neighborhood.max_neighbors())).changed() {
                         if self.survival_max < self.survival_min {
                             self.survival_min = self.survival_max;
                         }
@@ -215,22 +364,16 @@ impl SettingsPanel {
                 
                 // Zastosuj zmiany
                 if action == SettingsAction::RulesChanged {
-                    modify_config(|config| {
-                        config.set_birth_neighbors(self.birth_min, self.birth_max);
-                        config.set_survival_neighbors(self.survival_min, self.survival_max);
-                    });
+                    config.set_birth_neighbors(self.birth_min, self.birth_max);
+                    config.set_survival_neighbors(self.survival_min, self.survival_max);
                 } else if action == SettingsAction::ResetRules {
                     // Resetuj do wartości domyślnych
                     let default_config = crate::config::rules::GameConfig::default();
-                    self.birth_min = *default_config.birth_neighbors.start();
-                    self.birth_max = *default_config.birth_neighbors.end();
-                    self.survival_min = *default_config.survival_neighbors.start();
-                    self.survival_max = *default_config.survival_neighbors.end();
+                    self.load_rules_from(&default_config);
                     
-                    modify_config(|config| {
-                        config.set_birth_neighbors(self.birth_min, self.birth_max);
-                        config.set_survival_neighbors(self.survival_min, self.survival_max);
-                    });
+                    config.set_neighborhood(self.neighborhood);
+                    config.set_birth_neighbors(self.birth_min, self.birth_max);
+                    config.set_survival_neighbors(self.survival_min, self.survival_max);
                     
                     action = SettingsAction::RulesChanged; // Informuj o zmianie
                 }
@@ -241,7 +384,7 @@ impl SettingsPanel {
     }
     
     /// Renderuje sekcję ustawień planszy
-    fn render_board_settings_section(&mut self, ui: &mut egui::Ui) -> SettingsAction {
+    fn render_board_settings_section(&mut self, ui: &mut egui::Ui, config: &mut GameConfig) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         ui.horizontal(|ui| {
@@ -281,35 +424,32 @@ impl SettingsPanel {
                 // Ustawienia w zależności od trybu
                 match self.board_mode {
                     BoardSizeMode::Dynamic => {
-                        action = self.render_dynamic_settings(ui).max(action);
+                        action = self.render_dynamic_settings(ui, config).max(action);
                     }
                     BoardSizeMode::Static => {
-                        action = self.render_static_settings(ui).max(action);
+                        action = self.render_static_settings(ui, config).max(action);
                     }
                 }
                 
                 // Zastosuj zmiany trybu
                 if action == SettingsAction::BoardSettingsChanged {
-                    modify_config(|config| {
-                        config.set_board_size_mode(self.board_mode);
-                        config.set_max_board_size(self.max_board_size);
-                        config.set_initial_board_size(self.initial_board_size);
-                        config.set_static_board_size(self.static_board_size);
-                    });
+                    config.set_board_size_mode(self.board_mode);
+                    config.set_max_board_size(self.max_board_size);
+                    config.set_initial_board_size(self.initial_board_size);
+                    config.set_static_board_size(self.static_board_width, self.static_board_height);
                 } else if action == SettingsAction::ResetBoardSettings {
                     // Resetuj do wartości domyślnych
                     let default_config = crate::config::rules::GameConfig::default();
                     self.board_mode = default_config.board_size_mode;
                     self.max_board_size = default_config.max_board_size;
                     self.initial_board_size = default_config.initial_board_size;
-                    self.static_board_size = default_config.static_board_size;
+                    self.static_board_width = default_config.static_board_width;
+                    self.static_board_height = default_config.static_board_height;
                     
-                    modify_config(|config| {
-                        config.set_board_size_mode(self.board_mode);
-                        config.set_max_board_size(self.max_board_size);
-                        config.set_initial_board_size(self.initial_board_size);
-                        config.set_static_board_size(self.static_board_size);
-                    });
+                    config.set_board_size_mode(self.board_mode);
+                    config.set_max_board_size(self.max_board_size);
+                    config.set_initial_board_size(self.initial_board_size);
+                    config.set_static_board_size(self.static_board_width, self.static_board_height);
                     
                     action = SettingsAction::BoardSettingsChanged; // Informuj o zmianie
                 }
@@ -320,7 +460,7 @@ impl SettingsPanel {
     }
     
     /// Renderuje ustawienia trybu dynamicznego
-    fn render_dynamic_settings(&mut self, ui: &mut egui::Ui) -> SettingsAction {
+    fn render_dynamic_settings(&mut self, ui: &mut egui::Ui, config: &mut GameConfig) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         ui.label(RichText::new("Dynamic Mode Settings:").color(Color32::BLUE));
@@ -328,7 +468,8 @@ impl SettingsPanel {
         
         ui.horizontal(|ui| {
             ui.label("Initial size:");
-            if ui.add(Slider::new(&mut self.initial_board_size, 3..=201)
+            if ui.add(Slider::new(&mut self.initial_board_size, MIN_BOARD_DIMENSION..=MAX_BOARD_DIMENSION)
+                .logarithmic(true) // Duże rozmiary są łatwo dostępne
                 .step_by(2.0) // Tylko nieparzyste wartości
                 .text("cells")).changed() {
                 // Zapewnij nieparzystość
@@ -337,16 +478,15 @@ impl SettingsPanel {
                 }
                 // W trybie Dynamic, zmiana Initial Size powinna natychmiast zmienić rozmiar planszy
                 // Zapisujemy zmianę do konfiguracji natychmiast
-                modify_config(|config| {
-                    config.set_initial_board_size(self.initial_board_size);
-                });
-                action = SettingsAction::BoardSizeChanged(self.initial_board_size);
+                config.set_initial_board_size(self.initial_board_size);
+                action = SettingsAction::BoardSizeChanged(self.initial_board_size, self.initial_board_size);
             }
         });
         
         ui.horizontal(|ui| {
             ui.label("Max size:");
-            if ui.add(Slider::new(&mut self.max_board_size, 3..=201)
+            if ui.add(Slider::new(&mut self.max_board_size, MIN_BOARD_DIMENSION..=MAX_BOARD_DIMENSION)
+                .logarithmic(true) // Duże rozmiary są łatwo dostępne
                 .step_by(2.0) // Tylko nieparzyste wartości
                 .text("cells")).changed() {
                 // Zapewnij nieparzystość
@@ -362,7 +502,7 @@ impl SettingsPanel {
                 // Wyślij akcję zmiany rozmiaru planszy tylko jeśli initial size rzeczywiście się zmienił
                 // i tylko jeśli aplikacja nie była jeszcze uruchomiona (aby nie psuć aktualnej planszy)
                 if old_initial_size != self.initial_board_size {
-                    action = SettingsAction::BoardSizeChanged(self.initial_board_size);
+                    action = SettingsAction::BoardSizeChanged(self.initial_board_size, self.initial_board_size);
                 } else {
                     action = SettingsAction::BoardSettingsChanged;
                 }
@@ -373,43 +513,48 @@ impl SettingsPanel {
     }
     
     /// Renderuje ustawienia trybu statycznego
-    fn render_static_settings(&mut self, ui: &mut egui::Ui) -> SettingsAction {
+    fn render_static_settings(&mut self, ui: &mut egui::Ui, config: &mut GameConfig) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         ui.label(RichText::new("Static Mode Settings:").color(Color32::RED));
         ui.label("Board has fixed size - no automatic expansion");
         
-        let old_size = self.static_board_size;
+        let old_size = (self.static_board_width, self.static_board_height);
+        let mut size_changed = false;
         
-        ui.horizontal(|ui| {
-            ui.label("Board size:");
-            if ui.add(Slider::new(&mut self.static_board_size, 3..=201)
-                .step_by(2.0) // Tylko nieparzyste wartości
-                .text("cells")).changed() {
-                // Zapewnij nieparzystość
-                if self.static_board_size % 2 == 0 {
-                    self.static_board_size += 1;
-                }
-                
-                // Zapisujemy zmianę do konfiguracji natychmiast
-                modify_config(|config| {
-                    config.set_static_board_size(self.static_board_size);
-                });
-                
-                action = SettingsAction::BoardSettingsChanged;
-                
-                // Jeśli rozmiar się zmienił, wyślij dodatkową akcję
-                if old_size != self.static_board_size {
-                    action = SettingsAction::BoardSizeChanged(self.static_board_size);
+        for (label, value) in [("Width:", &mut self.static_board_width), ("Height:", &mut self.static_board_height)] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                if ui.add(Slider::new(value, MIN_BOARD_DIMENSION..=MAX_BOARD_DIMENSION)
+                    .logarithmic(true) // Duże rozmiary są łatwo dostępne
+                    .step_by(2.0) // Tylko nieparzyste wartości
+                    .text("cells")).changed() {
+                    // Zapewnij nieparzystość
+                    if *value % 2 == 0 {
+                        *value += 1;
+                    }
+                    size_changed = true;
                 }
+            });
+        }
+        
+        if size_changed {
+            // Zapisujemy zmianę do konfiguracji natychmiast
+            config.set_static_board_size(self.static_board_width, self.static_board_height);
+            
+            action = SettingsAction::BoardSettingsChanged;
+            
+            // Jeśli rozmiar się zmienił, wyślij dodatkową akcję
+            if old_size != (self.static_board_width, self.static_board_height) {
+                action = SettingsAction::BoardSizeChanged(self.static_board_width, self.static_board_height);
             }
-        });
+        }
         
         action
     }
     
     /// Renderuje panel ustawień z niestandardowymi stylami
-    pub fn render_with_styles(&mut self, ui: &mut egui::Ui, styles: &UIStyles) -> SettingsAction {
+    pub fn render_with_styles(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         // Główna sekcja ustawień (zwijalna) ze stylizowanym wyglądem
@@ -430,17 +575,27 @@ impl SettingsPanel {
                 ui.add_space(styles.dimensions.margin_medium);
                 
                 // Sekcja zasad gry
-                action = self.render_rules_section_styled(ui, styles).max(action);
+                action = self.render_rules_section_styled(ui, config, styles).max(action);
+                
+                ui.add_space(styles.separator_spacing());
+                
+                // Sekcja reguł probabilistycznych
+                action = self.render_stochastic_section_styled(ui, config, styles).max(action);
                 
                 ui.add_space(styles.separator_spacing());
                 
                 // Sekcja ustawień planszy
-                action = self.render_board_settings_section_styled(ui, styles).max(action);
+                action = self.render_board_settings_section_styled(ui, config, styles).max(action);
                 
                 ui.add_space(styles.separator_spacing());
                 
                 // Sekcja randomizera
-                action = self.render_randomizer_section_styled(ui, styles).max(action);
+                action = self.render_randomizer_section_styled(ui, config, styles).max(action);
+                
+                ui.add_space(styles.separator_spacing());
+                
+                // Sekcja wyglądu planszy
+                action = self.render_appearance_section_styled(ui, config, styles).max(action);
             }
         });
         
@@ -448,7 +603,7 @@ impl SettingsPanel {
     }
     
     /// Renderuje sekcję zasad gry ze stylami
-    fn render_rules_section_styled(&mut self, ui: &mut egui::Ui, styles: &UIStyles) -> SettingsAction {
+    fn render_rules_section_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         styles.nested_group_style().show(ui, |ui| {
@@ -474,13 +629,98 @@ impl SettingsPanel {
             if self.rules_expanded {
                 ui.add_space(styles.dimensions.margin_medium);
                 
+                // Gotowe zestawy reguł
+                ui.label(helpers::subsection_header("Preset:", styles));
+                ui.add_space(styles.dimensions.margin_small);
+                
+                let active_preset = config.active_rule_preset();
+                let mut chosen_preset = None;
+                egui::ComboBox::from_id_salt("rule_preset")
+                    .selected_text(active_preset.map_or("Custom", |preset| preset.name))
+                    .show_ui(ui, |ui| {
+                        for preset in RULE_PRESETS {
+                            let is_active = active_preset == Some(preset);
+                            if ui.selectable_label(is_active, format!("{} ({})", preset.name, preset.rule)).clicked() {
+                                chosen_preset = Some(preset);
+                            }
+                        }
+                    });
+                
+                ui.add_space(styles.dimensions.margin_medium);
+                
+                // Rodzaj sąsiedztwa - reguły odnoszą się do liczby sąsiadów w nim
+                ui.label(helpers::subsection_header("Neighborhood:", styles));
+                ui.add_space(styles.dimensions.margin_small);
+                
+                let mut chosen_neighborhood = None;
+                egui::ComboBox::from_id_salt("neighborhood")
+                    .selected_text(self.neighborhood.name())
+                    .show_ui(ui, |ui| {
+                        for neighborhood in NeighborhoodType::ALL {
+                            if ui.selectable_label(self.neighborhood == neighborhood, neighborhood.name()).clicked()
+                                && self.neighborhood != neighborhood
+                            {
+                                chosen_neighborhood = Some(neighborhood);
+                            }
+                        }
+                        
+                        // Własna maska zaczyna od komórek aktualnego sąsiedztwa
+                        let is_custom = matches!(self.neighborhood, NeighborhoodType::Custom(_));
+                        if ui.selectable_label(is_custom, "Custom (3x3 mask)").clicked() && !is_custom {
+                            let mask = match self.neighborhood {
+                                NeighborhoodType::VonNeumann => NeighborMask::von_neumann(),
+                                _ => NeighborMask::moore(),
+                            };
+                            chosen_neighborhood = Some(NeighborhoodType::Custom(mask));
+                        }
+                    });
+                
+                // Siatka 3x3 wybranych sąsiadów (środek to sama komórka)
+                if let NeighborhoodType::Custom(mask) = self.neighborhood {
+                    ui.add_space(styles.dimensions.margin_small);
+                    let mut grid = mask.grid();
+                    let mut grid_changed = false;
+                    ui.horizontal(|ui| {
+                        egui::Grid::new("neighbor_mask").spacing([2.0, 2.0]).show(ui, |ui| {
+                            for (row_index, row) in grid.iter_mut().enumerate() {
+                                for (column_index, enabled) in row.iter_mut().enumerate() {
+                                    if (row_index, column_index) == (1, 1) {
+                                        ui.add_enabled(false, egui::Checkbox::without_text(&mut false));
+                                    } else {
+                                        grid_changed |= ui.checkbox(enabled, "").changed();
+                                    }
+                                }
+                                ui.end_row();
+                            }
+                        });
+                        
+                        ui.vertical(|ui| {
+                            if ui.add(helpers::styled_button("Moore", styles.colors.text_secondary, styles, ButtonType::Small)).clicked() {
+                                grid = NeighborMask::moore().grid();
+                                grid_changed = true;
+                            }
+                            if ui.add(helpers::styled_button("Von Neumann", styles.colors.text_secondary, styles, ButtonType::Small)).clicked() {
+                                grid = NeighborMask::von_neumann().grid();
+                                grid_changed = true;
+                            }
+                        });
+                    });
+                    
+                    let edited = NeighborMask::from_grid(grid);
+                    if grid_changed && edited != mask {
+                        chosen_neighborhood = Some(NeighborhoodType::Custom(edited));
+                    }
+                }
+                
+                ui.add_space(styles.dimensions.margin_medium);
+                
                 // Birth Neighbors
                 ui.label(helpers::subsection_header("Birth Neighbors:", styles));
                 ui.add_space(styles.dimensions.margin_small);
                 
                 ui.horizontal(|ui| {
                     ui.label(helpers::label_text("Min:", styles));
-                    if ui.add(Slider::new(&mut self.birth_min, 0..=8)
+                    if ui.add(Slider::new(&mut self.birth_min, 0..=self.neighborhood.max_neighbors())
                         .text("")
                         .min_decimals(0)
                         .max_decimals(0)).changed() {
@@ -491,7 +731,7 @@ impl SettingsPanel {
                     }
                     
                     ui.label(helpers::label_text("Max:", styles));
-                    if ui.add(Slider::new(&mut self.birth_max, 0..=8)
+                    if ui.add(Slider::new(&mut self.birth_max, 0..=self.neighborhood.max_neighbors())
                         .text("")
                         .min_decimals(0)
                         .max_decimals(0)).changed() {
@@ -502,12 +742,8 @@ impl SettingsPanel {
                     }
                 });
                 
-                // Wyświetl aktualny przedział
-                let birth_range_text = if self.birth_min == self.birth_max {
-                    format!("Birth at: {}", self.birth_min)
-                } else {
-                    format!("Birth range: {}-{}", self.birth_min, self.birth_max)
-                };
+                // Wyświetl aktualne wartości (reguła z pola tekstowego może nie być przedziałem)
+                let birth_range_text = format!("Birth at: {}", describe_counts(&config.birth_neighbors));
                 ui.label(RichText::new(birth_range_text)
                     .font(styles.font_id(TextType::Small))
                     .color(styles.colors.text_muted));
@@ -520,7 +756,7 @@ impl SettingsPanel {
                 
                 ui.horizontal(|ui| {
                     ui.label(helpers::label_text("Min:", styles));
-                    if ui.add(Slider::new(&mut self.survival_min, 0..=8)
+                    if ui.add(Slider::new(&mut self.survival_min, 0..=self.neighborhood.max_neighbors())
                         .text("")
                         .min_decimals(0)
                         .max_decimals(0)).changed() {
@@ -531,7 +767,7 @@ impl SettingsPanel {
                     }
                     
                     ui.label(helpers::label_text("Max:", styles));
-                    if ui.add(Slider::new(&mut self.survival_max, 0..=8)
+                    if ui.add(Slider::new(&mut self.survival_max, 0..=self.neighborhood.max_neighbors())
                         .text("")
                         .min_decimals(0)
                         .max_decimals(0)).changed() {
@@ -542,34 +778,73 @@ impl SettingsPanel {
                     }
                 });
                 
-                // Wyświetl aktualny przedział
-                let survival_range_text = if self.survival_min == self.survival_max {
-                    format!("Survive at: {}", self.survival_min)
-                } else {
-                    format!("Survival range: {}-{}", self.survival_min, self.survival_max)
-                };
+                // Wyświetl aktualne wartości
+                let survival_range_text = format!("Survive at: {}", describe_counts(&config.survival_neighbors));
                 ui.label(RichText::new(survival_range_text)
                     .font(styles.font_id(TextType::Small))
                     .color(styles.colors.text_muted));
                 
+                // Zmiana suwakami zastępuje regułę przedziałami
+                let sliders_changed = action == SettingsAction::RulesChanged;
+                
+                ui.add_space(styles.dimensions.margin_medium);
+                
+                // Reguła w zapisie tekstowym - pozwala na zbiory nieciągłe (np. HighLife B36/S23)
+                ui.label(helpers::subsection_header("Rule String:", styles));
+                ui.add_space(styles.dimensions.margin_small);
+                
+                let mut rule_submitted = false;
+                ui.horizontal(|ui| {
+                    let response = ui.add(egui::TextEdit::singleline(&mut self.rule_input)
+                        .desired_width(110.0)
+                        .hint_text("B3/S23"));
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        rule_submitted = true;
+                    }
+                    if ui.add(helpers::styled_button("Apply", styles.colors.text_primary, styles, ButtonType::Small)).clicked() {
+                        rule_submitted = true;
+                    }
+                });
+                
+                if let Some(error) = &self.rule_error {
+                    ui.label(RichText::new(error)
+                        .font(styles.font_id(TextType::Small))
+                        .color(styles.colors.error));
+                }
+                
                 // Zastosuj zmiany
-                if action == SettingsAction::RulesChanged {
-                    modify_config(|config| {
-                        config.set_birth_neighbors(self.birth_min, self.birth_max);
-                        config.set_survival_neighbors(self.survival_min, self.survival_max);
-                    });
+                if let Some(preset) = chosen_preset {
+                    // Zestawy w tabeli są poprawnymi regułami dla sąsiedztwa Moore'a
+                    config.set_neighborhood(NeighborhoodType::Moore);
+                    let _ = config.set_rule_from_string(preset.rule);
+                    self.load_rules_from(config);
+                    action = SettingsAction::RulesChanged;
+                } else if let Some(neighborhood) = chosen_neighborhood {
+                    config.set_neighborhood(neighborhood);
+                    self.load_rules_from(config);
+                    action = SettingsAction::RulesChanged;
+                } else if sliders_changed {
+                    config.set_birth_neighbors(self.birth_min, self.birth_max);
+                    config.set_survival_neighbors(self.survival_min, self.survival_max);
+                    self.rule_input = config.rule_string();
+                    self.rule_error = None;
+                } else if rule_submitted {
+                    match config.set_rule_from_string(&self.rule_input) {
+                        Ok(()) => {
+                            // Suwaki pokazują najmniejszą i największą wartość nowej reguły
+                            self.load_rules_from(config);
+                            action = SettingsAction::RulesChanged;
+                        }
+                        Err(error) => self.rule_error = Some(format!("Invalid rule: {}", error)),
+                    }
                 } else if action == SettingsAction::ResetRules {
                     // Resetuj do wartości domyślnych
                     let default_config = crate::config::rules::GameConfig::default();
-                    self.birth_min = *default_config.birth_neighbors.start();
-                    self.birth_max = *default_config.birth_neighbors.end();
-                    self.survival_min = *default_config.survival_neighbors.start();
-                    self.survival_max = *default_config.survival_neighbors.end();
+                    self.load_rules_from(&default_config);
                     
-                    modify_config(|config| {
-                        config.set_birth_neighbors(self.birth_min, self.birth_max);
-                        config.set_survival_neighbors(self.survival_min, self.survival_max);
-                    });
+                    config.set_neighborhood(self.neighborhood);
+                    config.set_birth_neighbors(self.birth_min, self.birth_max);
+                    config.set_survival_neighbors(self.survival_min, self.survival_max);
                     
                     action = SettingsAction::RulesChanged; // Informuj o zmianie
                 }
@@ -580,7 +855,7 @@ impl SettingsPanel {
     }
     
     /// Renderuje sekcję ustawień planszy ze stylami
-    fn render_board_settings_section_styled(&mut self, ui: &mut egui::Ui, styles: &UIStyles) -> SettingsAction {
+    fn render_board_settings_section_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         styles.nested_group_style().show(ui, |ui| {
@@ -621,38 +896,72 @@ impl SettingsPanel {
                 
                 ui.add_space(styles.dimensions.margin_medium);
                 
+                // Przełącznik zachowania krawędzi
+                ui.label(helpers::subsection_header("Edges:", styles));
+                ui.add_space(styles.dimensions.margin_small);
+                
+                ui.horizontal(|ui| {
+                    if ui.radio_value(&mut self.boundary_mode, BoundaryMode::Bounded, "Bounded")
+                        .on_hover_text("Cells beyond the edge are always dead")
+                        .clicked() {
+                        action = SettingsAction::BoardSettingsChanged;
+                    }
+                    if ui.radio_value(&mut self.boundary_mode, BoundaryMode::Toroidal, "Toroidal")
+                        .on_hover_text("Edges wrap around - patterns leaving one side reappear on the other")
+                        .clicked() {
+                        action = SettingsAction::BoardSettingsChanged;
+                    }
+                });
+                
+                ui.add_space(styles.dimensions.margin_medium);
+                
                 // Ustawienia w zależności od trybu
                 match self.board_mode {
                     BoardSizeMode::Dynamic => {
-                        action = self.render_dynamic_settings_styled(ui, styles).max(action);
+                        action = self.render_dynamic_settings_styled(ui, config, styles).max(action);
                     }
                     BoardSizeMode::Static => {
-                        action = self.render_static_settings_styled(ui, styles).max(action);
+                        action = self.render_static_settings_styled(ui, config, styles).max(action);
                     }
                 }
                 
+                ui.add_space(styles.separator_spacing());
+                action = self.render_board_tools_styled(ui, config, styles).max(action);
+                
+                ui.add_space(styles.separator_spacing());
+                action = self.render_startup_pattern_styled(ui, config, styles).max(action);
+                
+                ui.add_space(styles.separator_spacing());
+                self.render_autosave_styled(ui, config, styles);
+                
                 // Zastosuj zmiany trybu
                 if action == SettingsAction::BoardSettingsChanged {
-                    modify_config(|config| {
-                        config.set_board_size_mode(self.board_mode);
-                        config.set_max_board_size(self.max_board_size);
-                        config.set_initial_board_size(self.initial_board_size);
-                        config.set_static_board_size(self.static_board_size);
-                    });
+                    config.set_board_size_mode(self.board_mode);
+                    config.set_boundary_mode(self.boundary_mode);
+                    config.set_max_board_size(self.max_board_size);
+                    config.set_initial_board_size(self.initial_board_size);
+                    config.set_static_board_size(self.static_board_width, self.static_board_height);
+                    config.set_expansion_margin(self.expansion_margin);
+                    config.set_expansion_layers(self.expansion_layers);
                 } else if action == SettingsAction::ResetBoardSettings {
                     // Resetuj do wartości domyślnych
                     let default_config = crate::config::rules::GameConfig::default();
                     self.board_mode = default_config.board_size_mode;
+                    self.boundary_mode = default_config.boundary_mode;
                     self.max_board_size = default_config.max_board_size;
                     self.initial_board_size = default_config.initial_board_size;
-                    self.static_board_size = default_config.static_board_size;
+                    self.static_board_width = default_config.static_board_width;
+                    self.static_board_height = default_config.static_board_height;
+                    self.expansion_margin = default_config.expansion_margin;
+                    self.expansion_layers = default_config.expansion_layers;
                     
-                    modify_config(|config| {
-                        config.set_board_size_mode(self.board_mode);
-                        config.set_max_board_size(self.max_board_size);
-                        config.set_initial_board_size(self.initial_board_size);
-                        config.set_static_board_size(self.static_board_size);
-                    });
+                    config.set_board_size_mode(self.board_mode);
+                    config.set_boundary_mode(self.boundary_mode);
+                    config.set_max_board_size(self.max_board_size);
+                    config.set_initial_board_size(self.initial_board_size);
+                    config.set_static_board_size(self.static_board_width, self.static_board_height);
+                    config.set_expansion_margin(self.expansion_margin);
+                    config.set_expansion_layers(self.expansion_layers);
                     
                     action = SettingsAction::BoardSettingsChanged; // Informuj o zmianie
                 }
@@ -663,7 +972,7 @@ impl SettingsPanel {
     }
     
     /// Renderuje ustawienia trybu dynamicznego ze stylami
-    fn render_dynamic_settings_styled(&mut self, ui: &mut egui::Ui, styles: &UIStyles) -> SettingsAction {
+    fn render_dynamic_settings_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         ui.label(RichText::new("Dynamic Mode Settings:")
@@ -675,7 +984,8 @@ impl SettingsPanel {
         
         ui.horizontal(|ui| {
             ui.label(helpers::label_text("Initial size:", styles));
-            if ui.add(Slider::new(&mut self.initial_board_size, 3..=201)
+            if ui.add(Slider::new(&mut self.initial_board_size, MIN_BOARD_DIMENSION..=MAX_BOARD_DIMENSION)
+                .logarithmic(true) // Duże rozmiary są łatwo dostępne
                 .step_by(2.0) // Tylko nieparzyste wartości
                 .text("cells")).changed() {
                 // Zapewnij nieparzystość
@@ -684,16 +994,15 @@ impl SettingsPanel {
                 }
                 // W trybie Dynamic, zmiana Initial Size powinna natychmiast zmienić rozmiar planszy
                 // Zapisujemy zmianę do konfiguracji natychmiast
-                modify_config(|config| {
-                    config.set_initial_board_size(self.initial_board_size);
-                });
-                action = SettingsAction::BoardSizeChanged(self.initial_board_size);
+                config.set_initial_board_size(self.initial_board_size);
+                action = SettingsAction::BoardSizeChanged(self.initial_board_size, self.initial_board_size);
             }
         });
         
         ui.horizontal(|ui| {
             ui.label(helpers::label_text("Max size:", styles));
-            if ui.add(Slider::new(&mut self.max_board_size, 3..=201)
+            if ui.add(Slider::new(&mut self.max_board_size, MIN_BOARD_DIMENSION..=MAX_BOARD_DIMENSION)
+                .logarithmic(true) // Duże rozmiary są łatwo dostępne
                 .step_by(2.0) // Tylko nieparzyste wartości
                 .text("cells")).changed() {
                 // Zapewnij nieparzystość
@@ -709,18 +1018,35 @@ impl SettingsPanel {
                 // Wyślij akcję zmiany rozmiaru planszy tylko jeśli initial size rzeczywiście się zmienił
                 // i tylko jeśli aplikacja nie była jeszcze uruchomiona (aby nie psuć aktualnej planszy)
                 if old_initial_size != self.initial_board_size {
-                    action = SettingsAction::BoardSizeChanged(self.initial_board_size);
+                    action = SettingsAction::BoardSizeChanged(self.initial_board_size, self.initial_board_size);
                 } else {
                     action = SettingsAction::BoardSettingsChanged;
                 }
             }
         });
         
+        // Jak wcześnie i o ile plansza rośnie - więcej warstw to rzadsze rozszerzenia przy szybkich statkach
+        ui.horizontal(|ui| {
+            ui.label(helpers::label_text("Expansion margin:", styles))
+                .on_hover_text("Expand the board when alive cells come this close to an edge");
+            if ui.add(Slider::new(&mut self.expansion_margin, 1..=MAX_EXPANSION_MARGIN).text("cells")).changed() {
+                action = SettingsAction::BoardSettingsChanged;
+            }
+        });
+        
+        ui.horizontal(|ui| {
+            ui.label(helpers::label_text("Layers per expansion:", styles))
+                .on_hover_text("Number of rings added on each side when the board expands (limited by the max size)");
+            if ui.add(Slider::new(&mut self.expansion_layers, 1..=MAX_EXPANSION_LAYERS).text("layers")).changed() {
+                action = SettingsAction::BoardSettingsChanged;
+            }
+        });
+        
         action
     }
     
     /// Renderuje ustawienia trybu statycznego ze stylami
-    fn render_static_settings_styled(&mut self, ui: &mut egui::Ui, styles: &UIStyles) -> SettingsAction {
+    fn render_static_settings_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         ui.label(RichText::new("Static Mode Settings:")
@@ -730,37 +1056,115 @@ impl SettingsPanel {
         
         ui.add_space(styles.dimensions.margin_small);
         
-        let old_size = self.static_board_size;
+        let old_size = (self.static_board_width, self.static_board_height);
+        let mut size_changed = false;
         
-        ui.horizontal(|ui| {
-            ui.label(helpers::label_text("Board size:", styles));
-            if ui.add(Slider::new(&mut self.static_board_size, 3..=201)
-                .step_by(2.0) // Tylko nieparzyste wartości
-                .text("cells")).changed() {
-                // Zapewnij nieparzystość
-                if self.static_board_size % 2 == 0 {
-                    self.static_board_size += 1;
+        for (label, value) in [("Width:", &mut self.static_board_width), ("Height:", &mut self.static_board_height)] {
+            ui.horizontal(|ui| {
+                ui.label(helpers::label_text(label, styles));
+                if ui.add(Slider::new(value, MIN_BOARD_DIMENSION..=MAX_BOARD_DIMENSION)
+                    .logarithmic(true) // Duże rozmiary są łatwo dostępne
+                    .step_by(2.0) // Tylko nieparzyste wartości
+                    .text("cells")).changed() {
+                    // Zapewnij nieparzystość
+                    if *value % 2 == 0 {
+                        *value += 1;
+                    }
+                    size_changed = true;
                 }
-                
-                // Zapisujemy zmianę do konfiguracji natychmiast
-                modify_config(|config| {
-                    config.set_static_board_size(self.static_board_size);
-                });
-                
-                action = SettingsAction::BoardSettingsChanged;
-                
-                // Jeśli rozmiar się zmienił, wyślij dodatkową akcję
-                if old_size != self.static_board_size {
-                    action = SettingsAction::BoardSizeChanged(self.static_board_size);
+            });
+        }
+        
+        if size_changed {
+            // Zapisujemy zmianę do konfiguracji natychmiast
+            config.set_static_board_size(self.static_board_width, self.static_board_height);
+            
+            action = SettingsAction::BoardSettingsChanged;
+            
+            // Jeśli rozmiar się zmienił, wyślij dodatkową akcję
+            if old_size != (self.static_board_width, self.static_board_height) {
+                action = SettingsAction::BoardSizeChanged(self.static_board_width, self.static_board_height);
+            }
+        }
+        
+        action
+    }
+    
+    /// Renderuje narzędzia zmiany rozmiaru aktualnej planszy ze stylami
+    fn render_board_tools_styled(&mut self, ui: &mut egui::Ui, config: &GameConfig, styles: &UIStyles) -> SettingsAction {
+        let mut action = SettingsAction::None;
+        
+        ui.label(helpers::subsection_header("Board Tools:", styles));
+        ui.add_space(styles.dimensions.margin_small);
+        
+        ui.add_enabled_ui(self.simulation_stopped, |ui| {
+            ui.horizontal(|ui| {
+                if ui.add(helpers::styled_button("➕ Add Layer", styles.colors.text_primary, styles, ButtonType::Medium))
+                    .on_hover_text("Add one ring of dead cells around the board")
+                    .clicked() {
+                    action = SettingsAction::AddBoardLayers(1);
                 }
+                if ui.add(helpers::styled_button(&format!("➕ Add {} Layers", self.layers_to_add), styles.colors.text_primary, styles, ButtonType::Medium))
+                    .on_hover_text("Add this many rings of dead cells around the board")
+                    .clicked() {
+                    action = SettingsAction::AddBoardLayers(self.layers_to_add);
+                }
+                ui.add(egui::DragValue::new(&mut self.layers_to_add).range(1..=MAX_BOARD_DIMENSION / 2));
+            });
+            if ui.add(helpers::styled_button("✂ Optimize", styles.colors.text_primary, styles, ButtonType::Medium))
+                .on_hover_text(format!("Shrink the board to the live cells, keeping a margin of {} cells around them", config.optimization_margin))
+                .clicked() {
+                action = SettingsAction::TrimBoard;
             }
+        }).response.on_disabled_hover_text("Stop the simulation to change the board size");
+        
+        action
+    }
+    
+    /// Renderuje wybór wzoru startowego ze stylami
+    /// 
+    /// Wybór zmienia konfigurację od razu (działa przy następnym uruchomieniu
+    /// i w nowych zakładkach), a aktualną planszę zmienia dopiero "Apply now".
+    fn render_startup_pattern_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
+        let mut action = SettingsAction::None;
+        
+        ui.label(helpers::subsection_header("Startup Pattern:", styles));
+        ui.add_space(styles.dimensions.margin_small);
+        
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_salt("startup_pattern")
+                .selected_text(config.initial_pattern.name())
+                .show_ui(ui, |ui| {
+                    for (name, pattern) in get_available_patterns() {
+                        ui.selectable_value(&mut config.initial_pattern, pattern, name);
+                    }
+                })
+                .response
+                .on_hover_text("Pattern placed on the board at launch and in new tabs");
+            
+            ui.add_enabled_ui(self.simulation_stopped, |ui| {
+                if ui.add(helpers::styled_button("Apply now", styles.colors.text_primary, styles, ButtonType::Small))
+                    .on_hover_text("Replace the current board with the startup pattern")
+                    .clicked() {
+                    action = SettingsAction::ApplyStartupPattern;
+                }
+            }).response.on_disabled_hover_text("Stop the simulation to replace the board");
         });
         
         action
     }
     
+    /// Renderuje ustawienie automatycznego zapisu planszy ze stylami
+    fn render_autosave_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) {
+        ui.label(helpers::subsection_header("Session Recovery:", styles));
+        ui.add_space(styles.dimensions.margin_small);
+        
+        helpers::styled_checkbox(ui, &mut config.ui_config.autosave, "Autosave board", styles)
+            .on_hover_text("Save the board every 30 seconds and offer to restore it after an unexpected exit");
+    }
+    
     /// Renderuje sekcję randomizera ze stylami
-    fn render_randomizer_section_styled(&mut self, ui: &mut egui::Ui, styles: &UIStyles) -> SettingsAction {
+    fn render_randomizer_section_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
         let mut action = SettingsAction::None;
         
         styles.nested_group_style().show(ui, |ui| {
@@ -798,9 +1202,7 @@ impl SettingsPanel {
                         .step_by(0.01)).changed() {
                         
                         // Zapisujemy zmianę do konfiguracji natychmiast
-                        modify_config(|config| {
-                            config.set_randomizer_base_probability(self.base_probability);
-                        });
+                        config.set_randomizer_base_probability(self.base_probability);
                         
                         action = SettingsAction::RandomizerChanged;
                     }
@@ -815,16 +1217,14 @@ impl SettingsPanel {
                 ui.add_space(styles.dimensions.margin_small);
                 
                 ui.horizontal(|ui| {
-                    if ui.add(Slider::new(&mut self.neighbor_bonus, 0.0..=1.0)
+                    if ui.add(Slider::new(&mut self.neighbor_bonus, 0.0..=MAX_NEIGHBOR_BONUS)
                         .text("Bonus %")
                         .min_decimals(1)
                         .max_decimals(3)
                         .step_by(0.01)).changed() {
                         
                         // Zapisujemy zmianę do konfiguracji natychmiast
-                        modify_config(|config| {
-                            config.set_randomizer_neighbor_bonus(self.neighbor_bonus);
-                        });
+                        config.set_randomizer_neighbor_bonus(self.neighbor_bonus);
                         
                         action = SettingsAction::RandomizerChanged;
                     }
@@ -834,28 +1234,334 @@ impl SettingsPanel {
                 
                 ui.add_space(styles.dimensions.margin_small);
                 
+                // Kształt obszaru losowania
+                ui.label(helpers::subsection_header("Fill Shape:", styles));
+                ui.add_space(styles.dimensions.margin_small);
+                
+                egui::ComboBox::from_id_salt("random_fill_shape")
+                    .selected_text(self.fill_shape.name())
+                    .show_ui(ui, |ui| {
+                        for shape in RandomFillShape::ALL {
+                            if ui.selectable_value(&mut self.fill_shape, shape, shape.name()).changed() {
+                                config.set_randomizer_shape(self.fill_shape);
+                                action = SettingsAction::RandomizerChanged;
+                            }
+                        }
+                    })
+                    .response
+                    .on_hover_text("Cells outside the shape stay dead");
+                
+                if helpers::styled_checkbox(ui, &mut self.additive_fill, "Add to current board", styles)
+                    .on_hover_text("Keep existing live cells and only randomize dead ones")
+                    .changed() {
+                    config.set_randomizer_additive(self.additive_fill);
+                    action = SettingsAction::RandomizerChanged;
+                }
+                
+                ui.add_space(styles.dimensions.margin_small);
+                
+                // Ziarno generatora - pozwala odtworzyć tę samą losową planszę
+                ui.label(helpers::subsection_header("Seed:", styles));
+                ui.add_space(styles.dimensions.margin_small);
+                
+                ui.horizontal(|ui| {
+                    let mut seed_changed = helpers::styled_checkbox(ui, &mut self.use_fixed_seed, "Use fixed seed", styles)
+                        .on_hover_text("The same seed and board size always produce the same board")
+                        .changed();
+                    
+                    seed_changed |= ui.add_enabled(self.use_fixed_seed, egui::DragValue::new(&mut self.seed).speed(1.0))
+                        .changed();
+                    
+                    if seed_changed {
+                        let seed = self.use_fixed_seed.then_some(self.seed);
+                        config.set_randomizer_seed(seed);
+                        
+                        action = SettingsAction::RandomizerChanged;
+                    }
+                });
+                
+                ui.add_space(styles.dimensions.margin_small);
+                
                 // Wyjaśnienie działania
                 ui.label(helpers::label_text("Each cell has base probability + (neighbors × bonus)", styles));
                 ui.label(helpers::label_text("Example: 10% base + 2 neighbors × 10% = 30% chance", styles));
                 
-                // Obsługa resetowania randomizera
-                if action == SettingsAction::ResetRandomizer {
-                    // Resetuj do wartości domyślnych
-                    let default_config = crate::config::rules::GameConfig::default();
-                    self.base_probability = default_config.randomizer_config.base_probability;
-                    self.neighbor_bonus = default_config.randomizer_config.neighbor_bonus;
+                ui.add_space(styles.dimensions.margin_small);
+                
+                // Gęstość planszy - oszacowana i faktyczna po ostatnim losowaniu
+                ui.label(helpers::value_text(&format!("Expected live cells: ~{:.1}%", self.expected_density * 100.0), styles))
+                    .on_hover_text("Estimated from a sample board with the current parameters");
+                if let Some((alive_cells, total_cells)) = self.last_fill {
+                    let percentage = alive_cells as f32 / total_cells.max(1) as f32 * 100.0;
+                    ui.label(helpers::value_text(&format!("Last fill: {:.1}% ({} of {} cells)", percentage, alive_cells, total_cells), styles));
+                }
+                
+                if ui.add_enabled(self.simulation_stopped, helpers::styled_button("🎲 Regenerate", styles.colors.button_step, styles, ButtonType::Medium))
+                    .on_hover_text("Fill the board again with the same parameters")
+                    .on_disabled_hover_text("Stop the simulation to regenerate the board")
+                    .clicked() {
+                    action = SettingsAction::RegenerateBoard;
+                }
+            }
+        });
+        
+        // Reset działa także gdy sekcja jest zwinięta
+        if action == SettingsAction::ResetRandomizer {
+            // Resetuj do wartości domyślnych
+            let default_config = crate::config::rules::GameConfig::default();
+            self.base_probability = default_config.randomizer_config.base_probability;
+            self.neighbor_bonus = default_config.randomizer_config.neighbor_bonus;
+            self.use_fixed_seed = default_config.randomizer_config.seed.is_some();
+            self.fill_shape = default_config.randomizer_config.shape;
+            self.additive_fill = default_config.randomizer_config.additive;
+            
+            config.set_randomizer_base_probability(self.base_probability);
+            config.set_randomizer_neighbor_bonus(self.neighbor_bonus);
+            config.set_randomizer_seed(default_config.randomizer_config.seed);
+            config.set_randomizer_shape(self.fill_shape);
+            config.set_randomizer_additive(self.additive_fill);
+            
+            action = SettingsAction::RandomizerChanged; // Informuj o zmianie
+        }
+        
+        // Parametry mogły się zmienić - odświeżamy oszacowanie gęstości
+        if action == SettingsAction::RandomizerChanged {
+            self.expected_density = randomizer::estimate_density(config);
+        }
+        
+        action
+    }
+    
+    /// Renderuje sekcję reguł probabilistycznych ze stylami
+    fn render_stochastic_section_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
+        let mut action = SettingsAction::None;
+        let mut reset = false;
+        
+        styles.nested_group_style().show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let stochastic_text = if self.stochastic_expanded {
+                    "🔽 Stochastic"
+                } else {
+                    "▶ Stochastic"
+                };
+                
+                if ui.add(helpers::styled_button(stochastic_text, styles.colors.text_secondary, styles, ButtonType::Medium)).clicked() {
+                    self.stochastic_expanded = !self.stochastic_expanded;
+                }
+                
+                // Przycisk przywracania reguł deterministycznych
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.add(helpers::styled_button("🗑 Reset", styles.colors.error, styles, ButtonType::Small)).clicked() {
+                        reset = true;
+                    }
+                });
+            });
+            
+            if self.stochastic_expanded {
+                ui.add_space(styles.dimensions.margin_medium);
+                
+                for (label, probability) in [
+                    ("Birth probability:", &mut self.birth_probability),
+                    ("Survival probability:", &mut self.survival_probability),
+                ] {
+                    ui.label(helpers::subsection_header(label, styles));
+                    ui.add_space(styles.dimensions.margin_small);
                     
-                    modify_config(|config| {
-                        config.set_randomizer_base_probability(self.base_probability);
-                        config.set_randomizer_neighbor_bonus(self.neighbor_bonus);
-                    });
+                    if ui.add(Slider::new(probability, 0.0..=1.0)
+                        .min_decimals(1)
+                        .max_decimals(3)
+                        .step_by(0.01)).changed() {
+                        action = SettingsAction::RulesChanged;
+                    }
                     
-                    action = SettingsAction::RandomizerChanged; // Informuj o zmianie
+                    ui.add_space(styles.dimensions.margin_small);
+                }
+                
+                // Ziarno - pozwala powtórzyć ten sam przebieg losowej symulacji
+                ui.horizontal(|ui| {
+                    let mut seed_changed = helpers::styled_checkbox(ui, &mut self.use_stochastic_seed, "Use fixed seed", styles)
+                        .on_hover_text("The same seed and starting board always produce the same run")
+                        .changed();
+                    
+                    seed_changed |= ui.add_enabled(self.use_stochastic_seed, egui::DragValue::new(&mut self.stochastic_seed).speed(1.0))
+                        .changed();
+                    
+                    if seed_changed {
+                        action = SettingsAction::RulesChanged;
+                    }
+                });
+                
+                ui.add_space(styles.dimensions.margin_small);
+                
+                if config.is_stochastic() {
+                    ui.label(helpers::small_text("Preview and stable-state detection are disabled while probabilities are below 100%", styles)
+                        .color(styles.colors.warning));
                 }
             }
         });
         
+        if reset {
+            let default_config = GameConfig::default();
+            self.birth_probability = default_config.birth_probability;
+            self.survival_probability = default_config.survival_probability;
+            self.use_stochastic_seed = default_config.stochastic_seed.is_some();
+            action = SettingsAction::RulesChanged;
+        }
+        
+        // Zapisujemy zmianę do konfiguracji natychmiast
+        if action == SettingsAction::RulesChanged {
+            config.set_birth_probability(self.birth_probability);
+            config.set_survival_probability(self.survival_probability);
+            config.set_stochastic_seed(self.use_stochastic_seed.then_some(self.stochastic_seed));
+        }
+        
         action
     }
+    
+    /// Renderuje sekcję wyglądu planszy (kolory komórek i siatki) ze stylami
+    fn render_appearance_section_styled(&mut self, ui: &mut egui::Ui, config: &mut GameConfig, styles: &UIStyles) -> SettingsAction {
+        let mut action = SettingsAction::None;
+        
+        styles.nested_group_style().show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let appearance_text = if self.appearance_expanded {
+                    "🔽 Appearance"
+                } else {
+                    "▶ Appearance"
+                };
+                
+                if ui.add(helpers::styled_button(appearance_text, styles.colors.text_secondary, styles, ButtonType::Medium)).clicked() {
+                    self.appearance_expanded = !self.appearance_expanded;
+                }
+                
+                // Przycisk resetowania wyglądu
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.add(helpers::styled_button("🗑 Reset", styles.colors.error, styles, ButtonType::Small)).clicked() {
+                        action = SettingsAction::ResetAppearance;
+                    }
+                });
+            });
+            
+            if self.appearance_expanded {
+                ui.add_space(styles.dimensions.margin_medium);
+                
+                let mut colors_changed = helpers::styled_checkbox(ui, &mut self.match_theme, "Match theme", styles)
+                    .on_hover_text("Use the colors of the side panel theme instead of the colors below")
+                    .changed();
+                
+                // Własne kolory nie są używane przy kolorach motywu
+                ui.add_enabled_ui(!self.match_theme, |ui| {
+                    for (label, color) in [
+                        ("Alive cells:", &mut self.alive_color),
+                        ("Dead cells:", &mut self.dead_color),
+                        ("Grid:", &mut self.grid_color),
+                    ] {
+                        ui.horizontal(|ui| {
+                            ui.label(helpers::label_text(label, styles));
+                            colors_changed |= ui.color_edit_button_srgba(color).changed();
+                        });
+                    }
+                });
+                
+                // Kształt żywych komórek (przy bardzo małych komórkach zawsze kwadrat)
+                ui.horizontal(|ui| {
+                    ui.label(helpers::label_text("Cell shape:", styles));
+                    egui::ComboBox::from_id_salt("cell_shape")
+                        .selected_text(self.cell_shape.name())
+                        .show_ui(ui, |ui| {
+                            for shape in CellShape::ALL {
+                                colors_changed |= ui.selectable_value(&mut self.cell_shape, shape, shape.name()).changed();
+                            }
+                        })
+                        .response
+                        .on_hover_text("Small cells are always drawn as squares");
+                });
+                
+                // Kolory i krycie podglądu następnej generacji
+                ui.add_space(styles.dimensions.margin_small);
+                ui.label(helpers::label_text("Preview:", styles));
+                colors_changed |= helpers::styled_checkbox(ui, &mut self.themed_preview_colors, "Legend colors", styles)
+                    .on_hover_text("Highlight births and deaths in the same colors as the legend in the panel")
+                    .changed();
+                let themed = self.themed_preview_colors;
+                for (label, color, editable) in [
+                    ("Births:", &mut self.birth_preview_color, !themed),
+                    ("Deaths:", &mut self.death_preview_color, !themed),
+                    ("Survivors:", &mut self.survival_preview_color, true),
+                ] {
+                    ui.add_enabled_ui(editable, |ui| ui.horizontal(|ui| {
+                        ui.label(helpers::label_text(label, styles));
+                        let mut rgb = [color[0], color[1], color[2]];
+                        if ui.color_edit_button_srgb(&mut rgb).changed() {
+                            color[..3].copy_from_slice(&rgb);
+                            colors_changed = true;
+                        }
+                        colors_changed |= ui.add(Slider::new(&mut color[3], 0..=255).text("opacity")).changed();
+                    }));
+                }
+                colors_changed |= helpers::styled_checkbox(ui, &mut self.show_survivors, "Highlight survivors", styles)
+                    .on_hover_text("Also highlight cells that stay alive in the next generation")
+                    .changed();
+                colors_changed |= helpers::styled_checkbox(ui, &mut self.show_expansion_margin, "Show expansion margin", styles)
+                    .on_hover_text("In Dynamic mode, shade the edge band where alive cells make the board grow (warning color at the maximum size)")
+                    .changed();
+                
+                // Zapisujemy zmianę do konfiguracji natychmiast
+                if colors_changed {
+                    let appearance = AppearanceConfig {
+                        alive_color: self.alive_color.to_srgba_unmultiplied(),
+                        dead_color: self.dead_color.to_srgba_unmultiplied(),
+                        grid_color: self.grid_color.to_srgba_unmultiplied(),
+                        cell_shape: self.cell_shape,
+                        match_theme: self.match_theme,
+                        themed_preview_colors: self.themed_preview_colors,
+                        birth_preview_color: self.birth_preview_color,
+                        death_preview_color: self.death_preview_color,
+                        survival_preview_color: self.survival_preview_color,
+                        show_survivors: self.show_survivors,
+                        show_expansion_margin: self.show_expansion_margin,
+                    };
+                    config.set_appearance(appearance);
+                    
+                    action = SettingsAction::AppearanceChanged;
+                }
+            }
+            
+            // Obsługa resetowania wyglądu (działa także przy zwiniętej sekcji)
+            if action == SettingsAction::ResetAppearance {
+                let default_appearance = AppearanceConfig::default();
+                self.load_appearance_from(&default_appearance);
+                
+                config.set_appearance(default_appearance);
+                
+                action = SettingsAction::AppearanceChanged; // Informuj o zmianie
+            }
+        });
+        
+        action
+    }
+}
+
+
+/// Zamienia kolor z konfiguracji (RGBA) na kolor egui
+fn color_from_config([r, g, b, a]: [u8; 4]) -> Color32 {
+    Color32::from_rgba_unmultiplied(r, g, b, a)
 }
 
+/// Zwraca najmniejszą i największą liczbę sąsiadów ze zbioru (dla suwaków)
+fn neighbor_bounds(counts: &HashSet<usize>) -> (usize, usize) {
+    let min = counts.iter().copied().min().unwrap_or(0);
+    let max = counts.iter().copied().max().unwrap_or(min);
+    (min, max)
+}
+
+/// Opisuje zbiór liczby sąsiadów do wyświetlenia (np. {6, 3} -> "3, 6")
+fn describe_counts(counts: &HashSet<usize>) -> String {
+    if counts.is_empty() {
+        return "never".to_string();
+    }
+    
+    let mut sorted: Vec<usize> = counts.iter().copied().collect();
+    sorted.sort_unstable();
+    sorted.iter().map(|count| count.to_string()).collect::<Vec<_>>().join(", ")
+}
@@ -3,9 +3,34 @@
 /// Zawiera przyciski Start/Stop, Reset oraz inne opcje sterowania symulacją.
 
 use egui::RichText;
+use std::time::{Duration, Instant};
 use super::settings::{SettingsPanel, SettingsAction};
 use super::styles::{UIStyles, ButtonType, TextType, helpers};
 use super::pattern_selector::PatternSelector;
+use super::render::{BoardTool, MAX_GRID_THICKNESS, MIN_GRID_THICKNESS};
+use crate::logic::tabs::TabStepMode;
+use crate::logic::change_state::{BrushSize, EditMode, SymmetryMode};
+use crate::logic::board::CellState;
+use crate::logic::detection::SettledState;
+use crate::assets::PatternFormat;
+use crate::config::BoardSizeMode;
+use crate::config::rules::GameConfig;
+
+/// Liczba znaków rezerwowana dla wartości w sekcji statystyk
+/// (zapobiega "skakaniu" układu przy szybko rosnących licznikach)
+const STAT_VALUE_CHARS: usize = 12;
+
+/// Liczba znaków rezerwowana dla współrzędnych kursora (np. "(200, 200) alive")
+const CURSOR_VALUE_CHARS: usize = 16;
+
+/// Największa liczba generacji wykonywana jednym kliknięciem "Step ×N"
+pub const MAX_STEPS_PER_CLICK: u32 = 10_000;
+
+/// Liczba kroków, od której przed obliczeniami wyświetlany jest wskaźnik zajętości
+pub const BUSY_INDICATOR_STEPS: u32 = 1_000;
+
+/// Jak długo po ostatniej utracie komórek na krawędzi widoczny jest komunikat o niej
+const BOUNDARY_LOSS_NOTICE_DURATION: Duration = Duration::from_secs(4);
 
 /// Stan symulacji
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,6 +41,16 @@ pub enum SimulationState {
     Running,
 }
 
+/// Jednostka, w której wyświetlana i wprowadzana jest prędkość symulacji
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpeedUnit {
+    /// Generacje na sekundę
+    #[default]
+    GenerationsPerSecond,
+    /// Milisekundy na generację (wygodniejsze przy powolnym podglądzie)
+    MillisecondsPerGeneration,
+}
+
 /// Akcje które może wykonać użytkownik
 #[derive(Debug, Clone, PartialEq)]
 pub enum UserAction {
@@ -25,16 +60,22 @@ pub enum UserAction {
     Stop,
     /// Resetuj planszę do stanu początkowego
     Reset,
+    /// Przywróć planszę z chwili uruchomienia symulacji (bez zmiany etapu resetu)
+    RestoreStartState,
     /// Wykonaj jeden krok symulacji
     Step,
+    /// Wykonaj podaną liczbę kroków symulacji bez animacji
+    StepMany(u32),
+    /// Cofnij symulację o jedną generację
+    StepBack,
     /// Edytuj komórkę na podanych współrzędnych (x, y)
     EditCell(usize, usize),
     /// Zmieniono zasady gry
     RulesChanged,
     /// Zmieniono ustawienia planszy
     BoardSettingsChanged,
-    /// Zmieniono rozmiar planszy (nowy rozmiar)
-    BoardSizeChanged(usize),
+    /// Zmieniono rozmiar planszy (nowa szerokość i wysokość)
+    BoardSizeChanged(usize, usize),
     /// Wygeneruj losową planszę
     RandomFill,
     /// Wybrano wzór do umieszczenia
@@ -43,6 +84,60 @@ pub enum UserAction {
     PatternCancelled,
     /// Umieść wzór w podanej pozycji
     PlacePattern(String, usize, usize),
+    /// Wyśrodkuj widok na komórce o podanych współrzędnych (x, y)
+    GoToCell(usize, usize),
+    /// Przywróć domyślny widok planszy
+    ResetView,
+    /// Przełącz na zakładkę o podanym indeksie
+    SwitchTab(usize),
+    /// Otwórz nową, pustą zakładkę
+    NewTab,
+    /// Zamknij zakładkę o podanym indeksie
+    CloseTab(usize),
+    /// Zduplikuj aktywną zakładkę
+    DuplicateTab,
+    /// Zmień sposób wykonywania kroków w zakładkach
+    SetTabStepMode(TabStepMode),
+    /// Skopiuj planszę do schowka w formacie RLE
+    CopyAsRle,
+    /// Skopiuj planszę do schowka jako siatkę znaków `#`/`.`
+    CopyAsText,
+    /// Zastąp planszę siatką znaków ze schowka
+    PasteBoard,
+    /// Zapisz statystyki kolejnych generacji do pliku CSV
+    ExportStatistics,
+    /// Zastąp planszę wybranym wzorem umieszczonym na jej środku
+    PlacePatternCentered,
+    /// Zastąp planszę wzorem startowym z konfiguracji
+    ApplyStartupPattern,
+    /// Zmień narzędzie interakcji z planszą (rysowanie/zaznaczanie)
+    SetBoardTool(BoardTool),
+    /// Zmień sposób edycji komórek (przełączanie/wypełnianie)
+    SetEditMode(EditMode),
+    /// Zmień symetrię, z którą odbijane są zmiany komórek
+    SetSymmetryMode(SymmetryMode),
+    /// Zmień rozmiar pędzla używanego przy rysowaniu
+    SetBrushSize(BrushSize),
+    /// Odwróć stan wszystkich komórek planszy
+    InvertBoard,
+    /// Zapisz żywe komórki planszy jako wzór o podanej nazwie
+    SaveAsPattern(String),
+    /// Zapisz żywe komórki planszy do pliku w podanym formacie
+    SaveBoardToFile(PatternFormat),
+    /// Wyznacz okres wzoru na planszy (oscylator/statek)
+    AnalyzePattern,
+    /// Przytnij planszę do żywych komórek z zachowaniem marginesu
+    TrimBoard,
+    /// Dodaj podaną liczbę pustych warstw komórek dookoła planszy
+    AddBoardLayers(usize),
+    /// Włącz lub wyłącz nagrywanie kolejnych generacji
+    SetRecording(bool),
+    /// Zapisz nagranie do pliku
+    SaveRecording,
+    /// Wczytaj nagranie z pliku i rozpocznij jego odtwarzanie
+    LoadRecording,
+    /// Zakończ odtwarzanie nagrania
+    ExitPlayback,
     /// Brak akcji
     None,
 }
@@ -55,10 +150,42 @@ pub struct SidePanel {
     generation_count: u64,
     /// Liczba żywych komórek
     alive_cells_count: usize,
+    /// Procent komórek planszy, które są żywe
+    population_density: f32,
+    /// Narodziny i śmierci w ostatniej generacji (None - brak danych, np. po cofnięciu)
+    last_generation_changes: Option<(usize, usize)>,
+    /// Łączna liczba narodzin od resetu
+    total_births: u64,
+    /// Łączna liczba śmierci od resetu
+    total_deaths: u64,
     /// Prędkość symulacji (generacje na sekundę)
     simulation_speed: f32,
+    /// Jednostka prędkości wyświetlana w kontrolkach
+    speed_unit: SpeedUnit,
+    /// Czy symulacja wykonuje krok w każdej klatce, ignorując ustawioną prędkość
+    uncapped_speed: bool,
+    /// Co ile generacji prędkość jest zwiększana o jeden krok (None - bez przyspieszania)
+    speed_ramp_interval: Option<u64>,
+    /// Wartość wpisana w pole przyspieszania (pamiętana także gdy przyspieszanie jest wyłączone)
+    speed_ramp_interval_input: u64,
+    /// Prędkość faktycznie osiągnięta w ostatniej sekundzie (generacje na sekundę, None - brak pomiaru)
+    actual_speed: Option<f32>,
     /// Czy pokazywać podgląd zmian (zarówno narodziny jak i śmierci)
     show_preview: bool,
+    /// Czy zmiany powiększenia widoku mają być płynnie animowane
+    smooth_zoom: bool,
+    /// Czy pokazywać minimapę całej planszy, gdy plansza nie mieści się w widoku
+    show_minimap: bool,
+    /// Czy kolorować żywe komórki według ich wieku
+    color_by_age: bool,
+    /// Czy rysować linie siatki na planszy
+    show_grid: bool,
+    /// Grubość linii siatki w pikselach
+    grid_thickness: f32,
+    /// Co ile komórek rysować linię główną siatki (None - bez linii głównych)
+    major_grid_interval: Option<usize>,
+    /// Wartość wpisana w pole odstępu linii głównych (pamiętana także gdy są wyłączone)
+    major_grid_interval_input: usize,
     /// Czy sekcja instrukcji jest rozwinięta
     instructions_expanded: bool,
     /// Panel ustawień gry
@@ -67,24 +194,152 @@ pub struct SidePanel {
     styles: UIStyles,
     /// Aktualnie wybrany wzór do umieszczenia
     selected_pattern: Option<String>,
+    /// Wybrany wzór po obrotach i odbiciach - ten wariant jest podglądany i umieszczany
+    placement_pattern: Option<crate::assets::Pattern>,
     /// Selektor wzorów
     pattern_selector: PatternSelector,
+    /// Tekst wpisany w pole współrzędnej X (Go to)
+    goto_x_input: String,
+    /// Tekst wpisany w pole współrzędnej Y (Go to)
+    goto_y_input: String,
+    /// Komunikat błędu nawigacji do współrzędnych
+    goto_error: Option<String>,
+    /// Komunikat błędu wklejania planszy ze schowka lub eksportu statystyk
+    board_io_error: Option<String>,
+    /// Czy w historii są generacje, do których można się cofnąć
+    can_step_back: bool,
+    /// Czy zapisano planszę z chwili uruchomienia symulacji
+    can_restore_start: bool,
+    /// Opis działania następnego resetu (podpowiedź przycisku Reset)
+    next_reset_description: &'static str,
+    /// Liczba kroków wykonywanych przyciskiem "Step ×N"
+    step_count: u32,
+    /// Liczba kroków oczekujących na wykonanie (wyświetlana jako wskaźnik zajętości)
+    pending_steps: Option<u32>,
+    /// Czy wskaźnik zajętości został już narysowany dla oczekujących kroków
+    busy_indicator_shown: bool,
+    /// Wykryty stan ustalony symulacji (wymarcie, martwa natura, oscylator)
+    settled_state: Option<SettledState>,
+    /// Czy zatrzymywać symulację po wykryciu stanu ustalonego
+    stop_when_settled: bool,
+    /// Czy symulacja została zatrzymana automatycznie po ustabilizowaniu planszy
+    stopped_stable: bool,
+    /// Generacja, po osiągnięciu której symulacja zostaje zatrzymana (None - bez limitu)
+    stop_at_generation: Option<u64>,
+    /// Wartość wpisana w pole limitu generacji (pamiętana także gdy limit jest wyłączony)
+    stop_at_generation_input: u64,
+    /// Czy symulacja została zatrzymana automatycznie po osiągnięciu limitu generacji
+    stopped_at_generation: bool,
+    /// Liczba żywych komórek, po przekroczeniu której symulacja zostaje zatrzymana (None - bez limitu)
+    stop_above_population: Option<usize>,
+    /// Wartość wpisana w pole limitu populacji (pamiętana także gdy limit jest wyłączony)
+    stop_above_population_input: usize,
+    /// Czy symulacja została zatrzymana automatycznie po przekroczeniu limitu populacji
+    stopped_at_population: bool,
+    /// Liczba komórek utraconych na krawędzi planszy, odkąd widoczny jest komunikat,
+    /// i czas ostatniej utraty
+    boundary_loss: Option<(usize, Instant)>,
+    /// Czy komunikat o utracie komórek zamknięto (do następnego uruchomienia symulacji)
+    boundary_loss_dismissed: bool,
+    /// Czy plansza nie zmieni się w następnej generacji (None - nieznane, np. przy regułach probabilistycznych)
+    board_stable: Option<bool>,
+    /// Aktualne wymiary planszy (szerokość, wysokość)
+    board_size: (usize, usize),
+    /// Wynik ostatniej analizy okresu wzoru
+    pattern_analysis: Option<String>,
+    /// Nazwa wpisana dla zapisywanego wzoru
+    save_pattern_name: String,
+    /// Komunikat o wyniku ostatniego zapisu wzoru (tekst, czy błąd)
+    save_pattern_message: Option<(String, bool)>,
+    /// Aktywne narzędzie interakcji z planszą
+    board_tool: BoardTool,
+    /// Aktywny sposób edycji komórek
+    edit_mode: EditMode,
+    /// Aktywna symetria edycji
+    symmetry_mode: SymmetryMode,
+    /// Aktywny rozmiar pędzla
+    brush_size: BrushSize,
+    /// Współrzędne i stan komórki pod kursorem (None gdy kursor jest poza planszą)
+    hovered_cell: Option<((usize, usize), CellState)>,
+    /// Czy kolejne generacje są nagrywane
+    is_recording: bool,
+    /// Liczba klatek i rozmiar nagrania w bajtach (None - brak nagrania)
+    recording_info: Option<(usize, usize)>,
+    /// Ostrzeżenie o rozmiarze nagrania
+    recording_warning: Option<String>,
+    /// Aktualna klatka i liczba klatek odtwarzanego nagrania (None - brak odtwarzania)
+    playback_position: Option<(usize, usize)>,
+    /// Komunikat o wyniku zapisu/wczytania nagrania (tekst, czy błąd)
+    recording_message: Option<(String, bool)>,
 }
 
 impl Default for SidePanel {
     fn default() -> Self {
-        let config = crate::config::get_config();
+        let config = GameConfig::default();
         Self {
             simulation_state: SimulationState::Stopped,
             generation_count: 0,
             alive_cells_count: 0,
+            population_density: 0.0,
+            last_generation_changes: None,
+            total_births: 0,
+            total_deaths: 0,
             simulation_speed: config.ui_config.default_simulation_speed,
+            speed_unit: SpeedUnit::default(),
+            uncapped_speed: false,
+            speed_ramp_interval: None,
+            speed_ramp_interval_input: 50,
+            actual_speed: None,
             show_preview: false,
+            smooth_zoom: false,
+            show_minimap: true,
+            color_by_age: false,
+            show_grid: true,
+            grid_thickness: 1.0,
+            major_grid_interval: None,
+            major_grid_interval_input: 10,
             instructions_expanded: false,
             settings_panel: SettingsPanel::new(),
             styles: UIStyles::new(),
             selected_pattern: None,
+            placement_pattern: None,
             pattern_selector: PatternSelector::new(),
+            goto_x_input: String::new(),
+            goto_y_input: String::new(),
+            goto_error: None,
+            board_io_error: None,
+            can_step_back: false,
+            can_restore_start: false,
+            next_reset_description: "Reset to empty board",
+            step_count: 100,
+            pending_steps: None,
+            busy_indicator_shown: false,
+            settled_state: None,
+            stop_when_settled: false,
+            stopped_stable: false,
+            stop_at_generation: None,
+            stop_at_generation_input: 100,
+            stopped_at_generation: false,
+            stop_above_population: None,
+            stop_above_population_input: 10_000,
+            stopped_at_population: false,
+            boundary_loss: None,
+            boundary_loss_dismissed: false,
+            board_stable: None,
+            board_size: (0, 0),
+            pattern_analysis: None,
+            save_pattern_name: String::new(),
+            save_pattern_message: None,
+            board_tool: BoardTool::Draw,
+            edit_mode: EditMode::Toggle,
+            symmetry_mode: SymmetryMode::None,
+            brush_size: BrushSize::Single,
+            hovered_cell: None,
+            is_recording: false,
+            recording_info: None,
+            recording_warning: None,
+            playback_position: None,
+            recording_message: None,
         }
     }
 }
@@ -98,6 +353,69 @@ impl SidePanel {
     /// Ustawia stan symulacji
     pub fn set_simulation_state(&mut self, state: SimulationState) {
         self.simulation_state = state;
+        self.stopped_stable = false;
+        self.stopped_at_generation = false;
+        self.stopped_at_population = false;
+    }
+    
+    /// Zatrzymuje symulację z powodu ustabilizowania planszy
+    pub fn stop_stable(&mut self) {
+        self.simulation_state = SimulationState::Stopped;
+        self.stopped_stable = true;
+    }
+    
+    /// Zwraca generację, po osiągnięciu której symulacja ma się zatrzymać
+    pub fn stop_at_generation(&self) -> Option<u64> {
+        self.stop_at_generation
+    }
+    
+    /// Zatrzymuje symulację z powodu osiągnięcia limitu generacji
+    pub fn stop_at_generation_reached(&mut self) {
+        self.simulation_state = SimulationState::Stopped;
+        self.stopped_at_generation = true;
+    }
+    
+    /// Zwraca liczbę żywych komórek, po przekroczeniu której symulacja ma się zatrzymać
+    pub fn stop_above_population(&self) -> Option<usize> {
+        self.stop_above_population
+    }
+    
+    /// Zatrzymuje symulację z powodu przekroczenia limitu populacji
+    pub fn stop_population_limit_reached(&mut self) {
+        self.simulation_state = SimulationState::Stopped;
+        self.stopped_at_population = true;
+    }
+    
+    /// Zgłasza komórki, które nie narodziły się, bo leżałyby poza krawędzią planszy
+    /// 
+    /// Kolejne utraty są doliczane do widocznego komunikatu zamiast pokazywać nowy
+    /// w każdej generacji. Zamknięty komunikat nie pojawia się do ponownego uruchomienia.
+    pub fn report_boundary_loss(&mut self, cells: usize) {
+        if self.boundary_loss_dismissed {
+            return;
+        }
+        
+        let total = match self.boundary_loss {
+            Some((lost, last_loss)) if last_loss.elapsed() < BOUNDARY_LOSS_NOTICE_DURATION => lost + cells,
+            _ => cells,
+        };
+        self.boundary_loss = Some((total, Instant::now()));
+    }
+    
+    /// Ustawia czy plansza nie zmieni się w następnej generacji (None - nieznane)
+    pub fn set_board_stable(&mut self, stable: Option<bool>) {
+        self.board_stable = stable;
+    }
+    
+    /// Ustawia aktualne wymiary planszy wyświetlane w statystykach
+    pub fn set_board_size(&mut self, width: usize, height: usize) {
+        self.board_size = (width, height);
+    }
+    
+    /// Pozwala ponownie wyświetlać komunikat o utracie komórek na krawędzi
+    pub fn reset_boundary_loss_notice(&mut self) {
+        self.boundary_loss = None;
+        self.boundary_loss_dismissed = false;
     }
     
     /// Zwraca aktualny stan symulacji
@@ -110,24 +428,124 @@ impl SidePanel {
         self.generation_count = count;
     }
     
+    /// Zwraca liczbę wykonanych generacji
+    pub fn generation_count(&self) -> u64 {
+        self.generation_count
+    }
+    
     /// Zwiększa liczbę generacji o 1
     pub fn increment_generation(&mut self) {
         self.generation_count += 1;
     }
     
+    /// Ustawia liczbę kroków oczekujących na wykonanie (None - brak oczekujących kroków)
+    pub fn set_pending_steps(&mut self, steps: Option<u32>) {
+        self.pending_steps = steps;
+        self.busy_indicator_shown = false;
+    }
+    
+    /// Sprawdza czy wskaźnik zajętości był już widoczny na ekranie
+    pub fn busy_indicator_shown(&self) -> bool {
+        self.busy_indicator_shown
+    }
+    
+    /// Zmniejsza liczbę generacji o 1 (przy cofaniu symulacji)
+    pub fn decrement_generation(&mut self) {
+        self.generation_count = self.generation_count.saturating_sub(1);
+    }
+    
+    /// Ustawia czy przycisk cofania generacji ma być aktywny
+    pub fn set_can_step_back(&mut self, can_step_back: bool) {
+        self.can_step_back = can_step_back;
+    }
+    
+    /// Ustawia czy przycisk przywracania planszy z chwili uruchomienia ma być aktywny
+    pub fn set_can_restore_start(&mut self, can_restore_start: bool) {
+        self.can_restore_start = can_restore_start;
+    }
+    
+    /// Ustawia opis działania następnego resetu
+    pub fn set_next_reset_description(&mut self, description: &'static str) {
+        self.next_reset_description = description;
+    }
+    
+    /// Ustawia aktywne narzędzie interakcji z planszą (do wyświetlenia przycisku)
+    pub fn set_board_tool(&mut self, tool: BoardTool) {
+        self.board_tool = tool;
+    }
+    
+    /// Ustawia aktywny sposób edycji komórek (do wyświetlenia przełącznika)
+    pub fn set_edit_mode(&mut self, mode: EditMode) {
+        self.edit_mode = mode;
+    }
+    
+    /// Ustawia aktywną symetrię edycji (do wyświetlenia listy wyboru)
+    pub fn set_symmetry_mode(&mut self, mode: SymmetryMode) {
+        self.symmetry_mode = mode;
+    }
+    
+    /// Ustawia aktywny rozmiar pędzla (do wyświetlenia przełącznika)
+    pub fn set_brush_size(&mut self, size: BrushSize) {
+        self.brush_size = size;
+    }
+    
+    /// Ustawia komórkę wskazywaną kursorem (wyświetlaną w statystykach)
+    pub fn set_hovered_cell(&mut self, hovered: Option<((usize, usize), CellState)>) {
+        self.hovered_cell = hovered;
+    }
+    
+    /// Ustawia wynik analizy okresu wzoru (None ukrywa wynik)
+    pub fn set_pattern_analysis(&mut self, analysis: Option<String>) {
+        self.pattern_analysis = analysis;
+    }
+    
+    /// Ustawia wykryty stan ustalony symulacji
+    pub fn set_settled_state(&mut self, state: Option<SettledState>) {
+        self.settled_state = state;
+    }
+    
+    /// Zwraca czy symulacja ma się zatrzymać po wykryciu stanu ustalonego
+    pub fn stop_when_settled(&self) -> bool {
+        self.stop_when_settled
+    }
+    
     /// Resetuje licznik generacji
     pub fn reset_generation_count(&mut self) {
         self.generation_count = 0;
+        self.clear_generation_changes();
+    }
+    
+    /// Zapisuje narodziny i śmierci ostatniej generacji i dolicza je do sum
+    pub fn record_generation_changes(&mut self, births: usize, deaths: usize) {
+        self.last_generation_changes = Some((births, deaths));
+        self.total_births += births as u64;
+        self.total_deaths += deaths as u64;
+    }
+    
+    /// Zeruje statystyki narodzin i śmierci
+    pub fn clear_generation_changes(&mut self) {
+        self.last_generation_changes = None;
+        self.total_births = 0;
+        self.total_deaths = 0;
     }
     
-    /// Ustawia liczbę żywych komórek
-    pub fn set_alive_cells_count(&mut self, count: usize) {
+    /// Zapomina narodziny i śmierci ostatniej generacji (sumy pozostają bez zmian)
+    pub fn forget_last_generation_changes(&mut self) {
+        self.last_generation_changes = None;
+    }
+    
+    /// Ustawia liczbę żywych komórek i na jej podstawie gęstość populacji planszy
+    pub fn set_alive_cells_count(&mut self, count: usize, total_cells: usize) {
         self.alive_cells_count = count;
+        self.population_density = if total_cells == 0 {
+            0.0
+        } else {
+            count as f32 / total_cells as f32 * 100.0
+        };
     }
     
-    /// Ustawia prędkość symulacji
-    pub fn set_simulation_speed(&mut self, speed: f32) {
-        let config = crate::config::get_config();
+    /// Ustawia prędkość symulacji (ograniczoną do zakresu z konfiguracji)
+    pub fn set_simulation_speed(&mut self, speed: f32, config: &GameConfig) {
         self.simulation_speed = speed
             .max(config.ui_config.min_simulation_speed)
             .min(config.ui_config.max_simulation_speed);
@@ -143,6 +561,21 @@ impl SidePanel {
         1.0 / self.simulation_speed
     }
     
+    /// Sprawdza czy symulacja ma wykonywać krok w każdej klatce, bez ograniczenia prędkości
+    pub fn uncapped_speed(&self) -> bool {
+        self.uncapped_speed
+    }
+    
+    /// Zwraca co ile generacji prędkość ma być zwiększana (None - bez przyspieszania)
+    pub fn speed_ramp_interval(&self) -> Option<u64> {
+        self.speed_ramp_interval
+    }
+    
+    /// Ustawia prędkość faktycznie osiągniętą w ostatniej sekundzie (None - brak pomiaru)
+    pub fn set_actual_speed(&mut self, speed: Option<f32>) {
+        self.actual_speed = speed;
+    }
+    
     /// Ustawia czy pokazywać podgląd zmian
     pub fn set_show_preview(&mut self, show: bool) {
         self.show_preview = show;
@@ -153,6 +586,36 @@ impl SidePanel {
         self.show_preview
     }
     
+    /// Zwraca czy zmiany powiększenia widoku mają być animowane
+    pub fn smooth_zoom(&self) -> bool {
+        self.smooth_zoom
+    }
+    
+    /// Zwraca czy pokazywać minimapę planszy
+    pub fn show_minimap(&self) -> bool {
+        self.show_minimap
+    }
+    
+    /// Zwraca czy rysować linie siatki na planszy
+    pub fn show_grid(&self) -> bool {
+        self.show_grid
+    }
+    
+    /// Zwraca grubość linii siatki i odstęp między liniami głównymi
+    pub fn grid_style(&self) -> (f32, Option<usize>) {
+        (self.grid_thickness, self.major_grid_interval)
+    }
+    
+    /// Zwraca style interfejsu (np. do rysowania planszy w kolorach motywu)
+    pub fn styles(&self) -> &UIStyles {
+        &self.styles
+    }
+    
+    /// Zwraca kolory gradientu wieku (najmłodsze, najstarsze) lub None, gdy kolorowanie jest wyłączone
+    pub fn age_colors(&self) -> Option<(egui::Color32, egui::Color32)> {
+        self.color_by_age.then_some((self.styles.colors.age_young, self.styles.colors.age_old))
+    }
+    
     /// Zwraca czy pokazywać podgląd następnego stanu (dla kompatybilności wstecznej)
     pub fn show_next_state_preview(&self) -> bool {
         self.show_preview
@@ -164,9 +627,10 @@ impl SidePanel {
     }
     
     /// Renderuje panel boczny i zwraca akcję użytkownika
-    pub fn render(&mut self, ui: &mut egui::Ui) -> UserAction {
+    /// 
+    /// Zmiany ustawień wprowadzone w panelu są zapisywane w przekazanej konfiguracji.
+    pub fn render(&mut self, ui: &mut egui::Ui, config: &mut GameConfig) -> UserAction {
         let mut action = UserAction::None;
-        let config = crate::config::get_config();
         
         // Dodajemy scroll area do całego panelu
         egui::ScrollArea::vertical()
@@ -198,19 +662,100 @@ impl SidePanel {
                                 };
                             }
                             
-                            // Przycisk Reset
-                            if ui.add(helpers::styled_button("🔄 Reset", self.styles.colors.button_reset, &self.styles, ButtonType::Medium)).clicked() {
+                            // Przycisk Reset - podpowiedź mówi, do jakiego stanu wróci plansza
+                            if ui.add(helpers::styled_button("🔄 Reset", self.styles.colors.button_reset, &self.styles, ButtonType::Medium))
+                                .on_hover_text(self.next_reset_description)
+                                .clicked() {
                                 action = UserAction::Reset;
                             }
+                            if ui.add_enabled(self.can_restore_start, helpers::styled_button("⏮ Restore Start", self.styles.colors.button_reset, &self.styles, ButtonType::Medium))
+                                .on_hover_text("Return to the board from the moment Start was pressed")
+                                .on_disabled_hover_text("The simulation has not been started yet")
+                                .clicked() {
+                                action = UserAction::RestoreStartState;
+                            }
                             
-                            // Przycisk Step (tylko gdy symulacja zatrzymana)
+                            // Przyciski Step Back i Step (tylko gdy symulacja zatrzymana)
                             if self.simulation_state == SimulationState::Stopped {
+                                if ui.add_enabled(self.can_step_back, helpers::styled_button("◀ Step Back", self.styles.colors.button_step, &self.styles, ButtonType::Medium))
+                                    .on_disabled_hover_text("No earlier generations to restore")
+                                    .clicked() {
+                                    action = UserAction::StepBack;
+                                }
                                 if ui.add(helpers::styled_button("⏭ Step", self.styles.colors.button_step, &self.styles, ButtonType::Medium)).clicked() {
                                     action = UserAction::Step;
                                 }
                             }
                         });
                         
+                        // Wiele kroków naraz - bez animacji pośrednich generacji
+                        if self.simulation_state == SimulationState::Stopped {
+                            ui.horizontal(|ui| {
+                                let step_many_enabled = self.pending_steps.is_none();
+                                if ui.add_enabled(step_many_enabled, helpers::styled_button(&format!("⏩ Step ×{}", self.step_count), self.styles.colors.button_step, &self.styles, ButtonType::Medium))
+                                    .on_hover_text("Advance this many generations at once")
+                                    .clicked() {
+                                    action = UserAction::StepMany(self.step_count);
+                                }
+                                ui.add(egui::DragValue::new(&mut self.step_count)
+                                    .range(1..=MAX_STEPS_PER_CLICK)
+                                    .speed(1.0)
+                                    .suffix(" steps"));
+                            });
+                        }
+                        
+                        if let Some(steps) = self.pending_steps {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label(helpers::label_text(&format!("Computing {} generations…", steps), &self.styles));
+                            });
+                            self.busy_indicator_shown = true;
+                        }
+                        
+                        let mut pause_on_stable = config.pause_on_stable;
+                        if helpers::styled_checkbox(ui, &mut pause_on_stable, "Pause when stable", &self.styles)
+                            .on_hover_text("Stop the simulation once the board stops changing (oscillators keep running)")
+                            .changed() {
+                            config.set_pause_on_stable(pause_on_stable);
+                        }
+                        
+                        let mut confirm_reset = config.confirm_destructive_reset;
+                        if helpers::styled_checkbox(ui, &mut confirm_reset, "Confirm before clearing", &self.styles)
+                            .on_hover_text("Ask before a reset clears a board that still has live cells")
+                            .changed() {
+                            config.set_confirm_destructive_reset(confirm_reset);
+                        }
+                        
+                        // Limit generacji - do porównywania plansz w tej samej generacji
+                        ui.horizontal(|ui| {
+                            let mut limit_enabled = self.stop_at_generation.is_some();
+                            let mut limit_changed = helpers::styled_checkbox(ui, &mut limit_enabled, "Stop at generation", &self.styles)
+                                .on_hover_text("Stop the simulation when the generation counter reaches this value")
+                                .changed();
+                            limit_changed |= ui.add_enabled(limit_enabled, egui::DragValue::new(&mut self.stop_at_generation_input)
+                                .range(1..=u64::MAX)
+                                .speed(1.0))
+                                .changed();
+                            if limit_changed {
+                                self.stop_at_generation = limit_enabled.then_some(self.stop_at_generation_input);
+                            }
+                        });
+                        
+                        // Limit populacji - chroni przed niekontrolowanym wzrostem przy "wybuchowych" regułach
+                        ui.horizontal(|ui| {
+                            let mut limit_enabled = self.stop_above_population.is_some();
+                            let mut limit_changed = helpers::styled_checkbox(ui, &mut limit_enabled, "Stop above population", &self.styles)
+                                .on_hover_text("Stop the simulation when the number of alive cells exceeds this value")
+                                .changed();
+                            limit_changed |= ui.add_enabled(limit_enabled, egui::DragValue::new(&mut self.stop_above_population_input)
+                                .range(1..=usize::MAX)
+                                .speed(10.0))
+                                .changed();
+                            if limit_changed {
+                                self.stop_above_population = limit_enabled.then_some(self.stop_above_population_input);
+                            }
+                        });
+                        
                         ui.add_space(self.styles.dimensions.margin_medium);
                         
                         // Ustawienia prędkości w tej samej sekcji
@@ -218,9 +763,19 @@ impl SidePanel {
                         
                         // Kontener dla kontroli prędkości z lepszym layoutem
                         ui.vertical(|ui| {
-                            ui.label(helpers::subsection_header("Speed", &self.styles));
+                            ui.horizontal(|ui| {
+                                ui.label(helpers::subsection_header("Speed", &self.styles));
+                                ui.selectable_value(&mut self.speed_unit, SpeedUnit::GenerationsPerSecond, "gen/s");
+                                ui.selectable_value(&mut self.speed_unit, SpeedUnit::MillisecondsPerGeneration, "ms/gen");
+                            });
                             ui.add_space(self.styles.dimensions.margin_small);
                             
+                            // Zakres w ms/gen wynika z zakresu prędkości (odwrotność)
+                            let min_speed = config.ui_config.min_simulation_speed;
+                            let max_speed = config.ui_config.max_simulation_speed;
+                            let ms_range = (1000.0 / max_speed)..=(1000.0 / min_speed);
+                            let mut ms_per_generation = self.time_between_generations() * 1000.0;
+                            
                             ui.horizontal(|ui| {
                                 // Przycisk zmniejszenia prędkości
                                 let can_decrease = self.simulation_speed > config.ui_config.min_simulation_speed;
@@ -231,16 +786,23 @@ impl SidePanel {
                                 
                                 // Slider prędkości - wydłużony, zajmuje dostępną przestrzeń
                                 ui.allocate_ui_with_layout(
-                                    egui::Vec2::new(ui.available_width() - 80.0, self.styles.dimensions.slider_height),
+                                    egui::Vec2::new(ui.available_width() - 150.0, self.styles.dimensions.slider_height),
                                     egui::Layout::left_to_right(egui::Align::Center),
-                                    |ui| {
-                                        if ui.add(helpers::wide_slider(
-                                            &mut self.simulation_speed, 
-                                            config.ui_config.min_simulation_speed..=config.ui_config.max_simulation_speed,
-                                            "gen/s",
-                                            &self.styles
-                                        ).step_by(config.ui_config.simulation_speed_step as f64)).changed() {
-                                            // Prędkość została zmieniona
+                                    |ui| match self.speed_unit {
+                                        SpeedUnit::GenerationsPerSecond => {
+                                            ui.add(helpers::wide_slider(
+                                                &mut self.simulation_speed, 
+                                                min_speed..=max_speed,
+                                                "gen/s",
+                                                &self.styles
+                                            ).step_by(config.ui_config.simulation_speed_step as f64));
+                                        }
+                                        SpeedUnit::MillisecondsPerGeneration => {
+                                            if ui.add(egui::Slider::new(&mut ms_per_generation, ms_range.clone())
+                                                .text("ms/gen")
+                                                .max_decimals(0)).changed() {
+                                                self.set_simulation_speed(1000.0 / ms_per_generation, config);
+                                            }
                                         }
                                     }
                                 );
@@ -251,8 +813,83 @@ impl SidePanel {
                                     self.simulation_speed = (self.simulation_speed + config.ui_config.simulation_speed_step)
                                         .min(config.ui_config.max_simulation_speed);
                                 }
+                                
+                                // Pole do wpisania dokładnej wartości w wybranej jednostce
+                                match self.speed_unit {
+                                    SpeedUnit::GenerationsPerSecond => {
+                                        ui.add(egui::DragValue::new(&mut self.simulation_speed)
+                                            .range(min_speed..=max_speed)
+                                            .speed(0.1)
+                                            .max_decimals(2));
+                                    }
+                                    SpeedUnit::MillisecondsPerGeneration => {
+                                        let mut ms_value = self.time_between_generations() * 1000.0;
+                                        if ui.add(egui::DragValue::new(&mut ms_value)
+                                            .range(ms_range)
+                                            .speed(10.0)
+                                            .max_decimals(0)).changed() {
+                                            self.set_simulation_speed(1000.0 / ms_value, config);
+                                        }
+                                    }
+                                }
+                            });
+                            
+                            // Przyciski szybkiego wyboru prędkości (wielokrotności prędkości domyślnej)
+                            ui.horizontal(|ui| {
+                                for multiplier in config.ui_config.speed_presets {
+                                    let speed = (config.ui_config.default_simulation_speed * multiplier)
+                                        .clamp(min_speed, max_speed);
+                                    let active = !self.uncapped_speed && (self.simulation_speed - speed).abs() < f32::EPSILON;
+                                    if ui.selectable_label(active, format!("{}×", multiplier))
+                                        .on_hover_text(format!("{:.1} gen/s", speed))
+                                        .clicked() {
+                                        self.uncapped_speed = false;
+                                        self.simulation_speed = speed;
+                                    }
+                                }
+                                if ui.selectable_label(self.uncapped_speed, "Max")
+                                    .on_hover_text("Step once per frame, as fast as the display allows")
+                                    .clicked() {
+                                    self.uncapped_speed = true;
+                                }
+                            });
+                            
+                            // Tryb bez ograniczenia prędkości i stopniowe przyspieszanie
+                            ui.horizontal(|ui| {
+                                helpers::styled_checkbox(ui, &mut self.uncapped_speed, "Uncapped", &self.styles)
+                                    .on_hover_text("Step once per frame, as fast as the display allows");
+                                
+                                let mut ramp_enabled = self.speed_ramp_interval.is_some();
+                                let mut ramp_changed = ui.add_enabled(
+                                    !self.uncapped_speed,
+                                    egui::Checkbox::new(&mut ramp_enabled, "Speed up every"),
+                                )
+                                .on_hover_text("Increase the speed by one step every N generations, up to the maximum")
+                                .changed();
+                                ramp_changed |= ui.add_enabled(!self.uncapped_speed && ramp_enabled, egui::DragValue::new(&mut self.speed_ramp_interval_input)
+                                    .range(1..=u64::MAX)
+                                    .speed(1.0)
+                                    .suffix(" gen"))
+                                    .changed();
+                                if ramp_changed {
+                                    self.speed_ramp_interval = ramp_enabled.then_some(self.speed_ramp_interval_input);
+                                }
                             });
                         });
+                        
+                        ui.add_space(self.styles.dimensions.margin_medium);
+                        
+                        // Nawigacja do podanych współrzędnych
+                        if let Some(goto_action) = self.render_goto_controls(ui) {
+                            action = goto_action;
+                        }
+                        
+                        ui.add_space(self.styles.dimensions.margin_medium);
+                        
+                        // Nagrywanie i odtwarzanie przebiegu symulacji
+                        if let Some(recording_action) = self.render_recording_controls(ui) {
+                            action = recording_action;
+                        }
                     });
                     
                     ui.add_space(self.styles.separator_spacing());
@@ -267,25 +904,136 @@ impl SidePanel {
                                 
                                 ui.horizontal(|ui| {
                                     ui.label(helpers::label_text("Generation:", &self.styles));
-                                    ui.label(helpers::value_text(&format!("{}", self.generation_count), &self.styles));
+                                    helpers::fixed_width_value(ui, &format!("{}", self.generation_count), STAT_VALUE_CHARS, &self.styles);
                                 });
                                 
                                 ui.horizontal(|ui| {
                                     ui.label(helpers::label_text("Alive cells:", &self.styles));
-                                    ui.label(helpers::value_text(&format!("{}", self.alive_cells_count), &self.styles));
+                                    helpers::fixed_width_value(ui, &format!("{}", self.alive_cells_count), STAT_VALUE_CHARS, &self.styles);
+                                });
+                                
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("Board:", &self.styles))
+                                        .on_hover_text("Current board size and how it is managed");
+                                    let mode = match config.board_size_mode {
+                                        BoardSizeMode::Dynamic => "Dynamic",
+                                        BoardSizeMode::Static => "Static",
+                                    };
+                                    let (width, height) = self.board_size;
+                                    ui.label(helpers::label_text(&format!("{}×{} ({})", width, height, mode), &self.styles));
+                                });
+                                
+                                // Reguła jest odczytywana z konfiguracji przy każdym rysowaniu,
+                                // więc zmiana w ustawieniach jest widoczna od razu
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("Rule:", &self.styles))
+                                        .on_hover_text("Active rule: neighbor counts for birth (B) and survival (S)");
+                                    helpers::fixed_width_value(ui, &config.rule_string(), STAT_VALUE_CHARS, &self.styles);
+                                });
+                                
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("Density:", &self.styles))
+                                        .on_hover_text("Percentage of board cells that are alive");
+                                    helpers::fixed_width_value(ui, &format!("{:.1}%", self.population_density), STAT_VALUE_CHARS, &self.styles);
+                                });
+                                
+                                // Znaczniki pustej i stabilnej planszy
+                                let is_empty = self.alive_cells_count == 0;
+                                let is_stable = self.board_stable == Some(true);
+                                if is_empty || is_stable {
+                                    ui.horizontal(|ui| {
+                                        if is_empty {
+                                            self.status_chip(ui, "Empty", self.styles.colors.text_secondary)
+                                                .on_hover_text("The board has no alive cells");
+                                        }
+                                        if is_stable {
+                                            self.status_chip(ui, "Stable", self.styles.colors.success)
+                                                .on_hover_text("The board will not change in the next generation");
+                                        }
+                                    });
+                                }
+                                
+                                // Porównanie ustawionej i osiągniętej prędkości - na dużych planszach
+                                // obliczenia mogą nie nadążać za ustawioną prędkością
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("Speed:", &self.styles))
+                                        .on_hover_text("Requested speed and generations actually computed in the last second");
+                                    let speed_text = match self.actual_speed {
+                                        Some(actual) => format!("target: {:.1}, actual: {:.1} gen/s", self.simulation_speed, actual),
+                                        None => format!("target: {:.1} gen/s", self.simulation_speed),
+                                    };
+                                    ui.label(helpers::label_text(&speed_text, &self.styles));
+                                });
+                                
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("Births / deaths:", &self.styles))
+                                        .on_hover_text("Cells born and died in the last generation");
+                                    match self.last_generation_changes {
+                                        Some((births, deaths)) => self.changes_label(ui, config, births as u64, deaths as u64),
+                                        None => {
+                                            helpers::fixed_width_value(ui, "—", STAT_VALUE_CHARS, &self.styles);
+                                        }
+                                    }
+                                });
+                                
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("Total:", &self.styles))
+                                        .on_hover_text("Cells born and died since the last reset");
+                                    self.changes_label(ui, config, self.total_births, self.total_deaths);
+                                });
+                                
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("Cursor:", &self.styles));
+                                    let cursor_text = match self.hovered_cell {
+                                        Some(((x, y), CellState::Alive)) => format!("({}, {}) alive", x, y),
+                                        Some(((x, y), CellState::Dead)) => format!("({}, {}) dead", x, y),
+                                        None => "—".to_string(),
+                                    };
+                                    helpers::fixed_width_value(ui, &cursor_text, CURSOR_VALUE_CHARS, &self.styles);
                                 });
                                 
                                 ui.horizontal(|ui| {
                                     ui.label(helpers::label_text("Status:", &self.styles));
                                     let (status_text, status_color) = match self.simulation_state {
-                                        SimulationState::Stopped => ("Stopped", self.styles.colors.error),
-                                        SimulationState::Running => ("Running", self.styles.colors.success),
+                                        SimulationState::Stopped if self.stopped_stable => ("Stopped: stable".to_string(), self.styles.colors.warning),
+                                        SimulationState::Stopped if self.stopped_at_population => {
+                                            ("Stopped: population limit".to_string(), self.styles.colors.warning)
+                                        }
+                                        SimulationState::Stopped if self.stopped_at_generation => {
+                                            (format!("Reached generation {}", self.generation_count), self.styles.colors.warning)
+                                        }
+                                        SimulationState::Stopped => ("Stopped".to_string(), self.styles.colors.error),
+                                        SimulationState::Running => ("Running".to_string(), self.styles.colors.success),
                                     };
                                     ui.label(RichText::new(status_text)
                                         .font(self.styles.font_id(TextType::Medium))
                                         .color(status_color)
                                         .strong());
                                 });
+                                
+                                ui.horizontal(|ui| {
+                                    ui.label(helpers::label_text("State:", &self.styles));
+                                    match &self.settled_state {
+                                        Some(state) => ui.label(RichText::new(state.description())
+                                            .font(self.styles.font_id(TextType::Medium))
+                                            .color(self.styles.colors.warning)),
+                                        None => ui.label(helpers::value_text("Evolving", &self.styles)),
+                                    };
+                                });
+                                
+                                self.render_boundary_loss_notice(ui);
+                                
+                                helpers::styled_checkbox(ui, &mut self.stop_when_settled, "Stop when settled", &self.styles)
+                                    .on_hover_text("Stop the simulation once the board dies out, stops changing or starts repeating");
+                                
+                                if ui.add(helpers::styled_button("🔍 Analyze pattern", self.styles.colors.text_primary, &self.styles, ButtonType::Medium))
+                                    .on_hover_text("Find the period of the current board and whether it moves")
+                                    .clicked() {
+                                    action = UserAction::AnalyzePattern;
+                                }
+                                if let Some(analysis) = &self.pattern_analysis {
+                                    ui.label(helpers::value_text(analysis, &self.styles));
+                                }
                             });
                             
                             ui.separator();
@@ -310,11 +1058,58 @@ impl SidePanel {
                                     });
                                 });
                                 
+                                // Opcja płynnego powiększenia nie zależy od stanu symulacji
+                                ui.horizontal(|ui| {
+                                    helpers::styled_checkbox(ui, &mut self.smooth_zoom, "Smooth zoom", &self.styles)
+                                        .on_hover_text("Animate cell size and view changes instead of snapping");
+                                });
+                                
+                                ui.horizontal(|ui| {
+                                    helpers::styled_checkbox(ui, &mut self.show_minimap, "Show minimap", &self.styles)
+                                        .on_hover_text("Overview of the whole board when it does not fit in the view; click it to move there");
+                                });
+                                
+                                // Kolorowanie według wieku - także w trakcie symulacji
+                                ui.horizontal(|ui| {
+                                    helpers::styled_checkbox(ui, &mut self.color_by_age, "Color by age", &self.styles)
+                                        .on_hover_text("Newborn cells are bright, cells that survived many generations are dark");
+                                });
+                                
+                                ui.horizontal(|ui| {
+                                    helpers::styled_checkbox(ui, &mut self.show_grid, "Show grid", &self.styles)
+                                        .on_hover_text("Grid lines are always hidden when cells are very small");
+                                });
+                                
+                                // Styl siatki - grubość i linie główne jak na papierze milimetrowym
+                                ui.add_enabled_ui(self.show_grid, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.label(helpers::label_text("Line width:", &self.styles));
+                                        ui.add(egui::Slider::new(&mut self.grid_thickness, MIN_GRID_THICKNESS..=MAX_GRID_THICKNESS)
+                                            .step_by(0.5)
+                                            .suffix(" px"));
+                                    });
+                                    ui.horizontal(|ui| {
+                                        let mut major_enabled = self.major_grid_interval.is_some();
+                                        let mut major_changed = helpers::styled_checkbox(ui, &mut major_enabled, "Major lines every", &self.styles)
+                                            .on_hover_text("Draw a thicker, darker line every N cells; only these remain when cells are very small")
+                                            .changed();
+                                        major_changed |= ui.add_enabled(major_enabled, egui::DragValue::new(&mut self.major_grid_interval_input)
+                                            .range(2..=100)
+                                            .suffix(" cells"))
+                                            .changed();
+                                        if major_changed {
+                                            self.major_grid_interval = major_enabled.then_some(self.major_grid_interval_input);
+                                        }
+                                    });
+                                });
+                                
                                 // Pokazuj Birth/Deaths tylko gdy gra jest zatrzymana I show_preview jest zaznaczone
                                 if self.show_preview && !is_running {
+                                    // Legenda ma kolory podglądu rysowanego na planszy
+                                    let (birth_color, death_color) = self.preview_colors(config);
                                     ui.horizontal(|ui| {
-                                        ui.colored_label(self.styles.colors.preview_birth, "● Births");
-                                        ui.colored_label(self.styles.colors.preview_death, "● Deaths");
+                                        ui.colored_label(birth_color, "● Births");
+                                        ui.colored_label(death_color, "● Deaths");
                                     });
                                 }
                                 
@@ -324,7 +1119,96 @@ impl SidePanel {
                                     if ui.add(helpers::styled_button("🎲 Random Fill", self.styles.colors.button_step, &self.styles, ButtonType::Medium)).clicked() {
                                         action = UserAction::RandomFill;
                                     }
+                                    
+                                    ui.add_space(self.styles.dimensions.margin_small);
+                                    if ui.add(helpers::styled_button("◐ Invert", self.styles.colors.text_primary, &self.styles, ButtonType::Medium))
+                                        .on_hover_text("Swap alive and dead cells on the whole board")
+                                        .clicked() {
+                                        action = UserAction::InvertBoard;
+                                    }
+                                    
+                                    // Przełącznik narzędzia zaznaczania obszaru
+                                    ui.add_space(self.styles.dimensions.margin_small);
+                                    let (select_text, next_tool) = if self.board_tool == BoardTool::Draw {
+                                        ("⬚ Select Region", BoardTool::Select)
+                                    } else {
+                                        ("✏ Back to Drawing", BoardTool::Draw)
+                                    };
+                                    if ui.add(helpers::styled_button(select_text, self.styles.colors.text_primary, &self.styles, ButtonType::Medium))
+                                        .on_hover_text("Drag to select, Ctrl+C copy, Ctrl+X cut, Ctrl+V paste, Esc cancel")
+                                        .clicked() {
+                                        action = UserAction::SetBoardTool(next_tool);
+                                    }
+                                    
+                                    // Przełącznik wypełniania obszaru (jak "wiadro z farbą")
+                                    let mut fill_enabled = self.edit_mode == EditMode::FloodFill;
+                                    if helpers::styled_checkbox(ui, &mut fill_enabled, "🪣 Fill tool", &self.styles)
+                                        .on_hover_text("Click fills the connected region of same-state cells with the opposite state")
+                                        .changed() {
+                                        let mode = if fill_enabled { EditMode::FloodFill } else { EditMode::Toggle };
+                                        action = UserAction::SetEditMode(mode);
+                                    }
+                                    
+                                    // Symetria edycji - zmiany są odbijane względem środka planszy
+                                    ui.horizontal(|ui| {
+                                        ui.label(helpers::label_text("Symmetry:", &self.styles))
+                                            .on_hover_text("Mirror every edit across the board center");
+                                        let mut symmetry_mode = self.symmetry_mode;
+                                        egui::ComboBox::from_id_salt("edit_symmetry_mode")
+                                            .selected_text(symmetry_mode.name())
+                                            .show_ui(ui, |ui| {
+                                                for mode in SymmetryMode::ALL {
+                                                    ui.selectable_value(&mut symmetry_mode, mode, mode.name());
+                                                }
+                                            });
+                                        if symmetry_mode != self.symmetry_mode {
+                                            action = UserAction::SetSymmetryMode(symmetry_mode);
+                                        }
+                                    });
+                                    
+                                    // Rozmiar pędzla - wypełnianie obszaru go nie używa
+                                    ui.add_enabled_ui(self.edit_mode == EditMode::Toggle, |ui| {
+                                        ui.horizontal(|ui| {
+                                            ui.label(helpers::label_text("Brush:", &self.styles))
+                                                .on_hover_text("Square of cells set by each click or drag, centered on the cell under the cursor");
+                                            for size in BrushSize::ALL {
+                                                if ui.selectable_label(self.brush_size == size, size.name()).clicked() && size != self.brush_size {
+                                                    action = UserAction::SetBrushSize(size);
+                                                }
+                                            }
+                                        });
+                                    });
                                 });
+                                
+                                // Eksport planszy do schowka - dostępny także w trakcie symulacji
+                                ui.add_space(self.styles.dimensions.margin_small);
+                                if ui.add(helpers::styled_button("📋 Copy as RLE", self.styles.colors.text_primary, &self.styles, ButtonType::Medium))
+                                    .on_hover_text("Copy the live region of the board to the clipboard in RLE format")
+                                    .clicked() {
+                                    action = UserAction::CopyAsRle;
+                                }
+                                if ui.add(helpers::styled_button("📋 Copy as text", self.styles.colors.text_primary, &self.styles, ButtonType::Medium))
+                                    .on_hover_text("Copy the live region of the board to the clipboard as a grid of # and . characters")
+                                    .clicked() {
+                                    action = UserAction::CopyAsText;
+                                }
+                                ui.add_enabled_ui(!is_running, |ui| {
+                                    if ui.add(helpers::styled_button("📥 Paste board", self.styles.colors.text_primary, &self.styles, ButtonType::Medium))
+                                        .on_hover_text("Replace the board with a grid of # (or O, *) and . characters from the clipboard")
+                                        .clicked() {
+                                        action = UserAction::PasteBoard;
+                                    }
+                                });
+                                if ui.add(helpers::styled_button("📊 Export CSV", self.styles.colors.text_primary, &self.styles, ButtonType::Medium))
+                                    .on_hover_text("Save population, births, deaths and density of every generation since the last reset")
+                                    .clicked() {
+                                    action = UserAction::ExportStatistics;
+                                }
+                                if let Some(error) = &self.board_io_error {
+                                    ui.label(RichText::new(error)
+                                        .font(self.styles.font_id(TextType::Small))
+                                        .color(self.styles.colors.error));
+                                }
                                 // Gdy gra jest uruchomiona, nie pokazujemy wcale Birth/Deaths
                             });
                         });
@@ -333,15 +1217,20 @@ impl SidePanel {
                     ui.add_space(self.styles.separator_spacing());
                     
                     // Sekcja ustawień gry ze stylizowanymi zagnieżdżeniami
-                    let settings_action = self.render_styled_settings(ui);
+                    let settings_action = self.render_styled_settings(ui, config);
                     match settings_action {
                         SettingsAction::RulesChanged => action = UserAction::RulesChanged,
                         SettingsAction::BoardSettingsChanged => action = UserAction::BoardSettingsChanged,
-                        SettingsAction::BoardSizeChanged(size) => action = UserAction::BoardSizeChanged(size),
+                        SettingsAction::BoardSizeChanged(width, height) => action = UserAction::BoardSizeChanged(width, height),
                         SettingsAction::ResetRules => action = UserAction::RulesChanged,
                         SettingsAction::ResetBoardSettings => action = UserAction::BoardSettingsChanged,
                         SettingsAction::RandomizerChanged => {}, // Randomizer nie wymaga akcji - tylko zmiana konfiguracji
                         SettingsAction::ResetRandomizer => {}, // Reset randomizera też nie wymaga akcji
+                        SettingsAction::AppearanceChanged | SettingsAction::ResetAppearance => {}, // Renderer odczytuje kolory z konfiguracji
+                        SettingsAction::TrimBoard => action = UserAction::TrimBoard,
+                        SettingsAction::AddBoardLayers(layers) => action = UserAction::AddBoardLayers(layers),
+                        SettingsAction::RegenerateBoard => action = UserAction::RandomFill,
+                        SettingsAction::ApplyStartupPattern => action = UserAction::ApplyStartupPattern,
                         SettingsAction::None => {}
                     }
                     
@@ -357,18 +1246,40 @@ impl SidePanel {
                             action = UserAction::PatternSelected(selected_pattern_name);
                         }
                     }
+                    if let Some(format) = self.pattern_selector.take_save_request() {
+                        action = UserAction::SaveBoardToFile(format);
+                    }
+                    
+                    // Zapisywanie aktualnej planszy jako nowego wzoru
+                    if let Some(save_action) = self.render_save_pattern_controls(ui) {
+                        action = save_action;
+                    }
                     
                     // Jeśli jakiś wzór jest wybrany, pokaż informację
-                    if let Some(pattern_name) = &self.selected_pattern {
+                    if let Some(pattern_name) = self.selected_pattern.clone() {
                         ui.add_space(self.styles.dimensions.margin_small);
                         ui.group(|ui| {
                             ui.label(helpers::subsection_header(&format!("Selected: {}", pattern_name), &self.styles));
-                            ui.label(helpers::small_text("Click on board to place pattern", &self.styles));
+                            ui.label(helpers::small_text("Click on board to place pattern (R rotates, F flips, Esc cancels)", &self.styles));
                             ui.horizontal(|ui| {
+                                if ui.add(helpers::styled_button("⟳ Rotate", self.styles.colors.text_primary, &self.styles, ButtonType::Small)).clicked() {
+                                    self.rotate_placement_pattern();
+                                }
+                                if ui.add(helpers::styled_button("⇆ Flip H", self.styles.colors.text_primary, &self.styles, ButtonType::Small)).clicked() {
+                                    self.mirror_placement_pattern(true);
+                                }
+                                if ui.add(helpers::styled_button("⇅ Flip V", self.styles.colors.text_primary, &self.styles, ButtonType::Small)).clicked() {
+                                    self.mirror_placement_pattern(false);
+                                }
                                 if ui.add(helpers::styled_button("Cancel", self.styles.colors.button_reset, &self.styles, ButtonType::Small)).clicked() {
                                     action = UserAction::PatternCancelled;
                                 }
                             });
+                            if ui.add(helpers::styled_button("⌖ Place Centered", self.styles.colors.text_primary, &self.styles, ButtonType::Small))
+                                .on_hover_text("Clear the board and place the pattern in its center (Dynamic boards grow to fit it)")
+                                .clicked() {
+                                action = UserAction::PlacePatternCentered;
+                            }
                         });
                     }
                     
@@ -396,13 +1307,23 @@ impl SidePanel {
                             ui.label(helpers::label_text("• Use Reset to restore initial state", &self.styles));
                             ui.label(helpers::label_text("• Step executes one generation", &self.styles));
                             ui.label(helpers::label_text("• Adjust speed with the slider", &self.styles));
+                            ui.label(helpers::label_text("• Keys: Space start/stop, S or → step, R reset, +/- speed", &self.styles));
                             
                             ui.add_space(self.styles.dimensions.margin_small);
                             
                             ui.label(helpers::subsection_header("Editing:", &self.styles));
                             ui.label(helpers::label_text("• Click cells when stopped to edit", &self.styles));
                             ui.label(helpers::label_text("• Toggle cells between alive/dead", &self.styles));
+                            ui.label(helpers::label_text("• Fill tool: click fills a whole region", &self.styles));
                             ui.label(helpers::label_text("• Changes persist in next generations", &self.styles));
+                            ui.label(helpers::label_text("• Select Region: drag, then Ctrl+C/Ctrl+X", &self.styles));
+                            ui.label(helpers::label_text("• Click to paste the copy, Esc cancels", &self.styles));
+                            
+                            ui.add_space(self.styles.dimensions.margin_small);
+                            
+                            ui.label(helpers::subsection_header("View:", &self.styles));
+                            ui.label(helpers::label_text("• Scroll over the board to zoom", &self.styles));
+                            ui.label(helpers::label_text("• Drag with the middle button to pan", &self.styles));
                         }
                     });
                 });
@@ -411,22 +1332,242 @@ impl SidePanel {
         action
     }
     
+    /// Renderuje mały znacznik stanu planszy
+    fn status_chip(&self, ui: &mut egui::Ui, text: &str, color: egui::Color32) -> egui::Response {
+        self.styles.chip_style(color)
+            .show(ui, |ui| {
+                ui.label(RichText::new(text)
+                    .font(self.styles.font_id(TextType::Small))
+                    .color(color));
+            })
+            .response
+    }
+    
+    /// Renderuje komunikat o komórkach utraconych na krawędzi planszy (znika po kilku sekundach)
+    fn render_boundary_loss_notice(&mut self, ui: &mut egui::Ui) {
+        let Some((lost, last_loss)) = self.boundary_loss else {
+            return;
+        };
+        let Some(remaining) = BOUNDARY_LOSS_NOTICE_DURATION.checked_sub(last_loss.elapsed()) else {
+            self.boundary_loss = None;
+            return;
+        };
+        // Komunikat ma zniknąć także przy zatrzymanej symulacji
+        ui.ctx().request_repaint_after(remaining);
+        
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("⚠ {} cells lost at boundary", lost))
+                .font(self.styles.font_id(TextType::Medium))
+                .color(self.styles.colors.warning))
+                .on_hover_text("Cells that would be born outside the board are lost at its hard edge. Use Toroidal or Dynamic mode to keep them.");
+            if ui.small_button("✖").on_hover_text("Hide until the simulation is started again").clicked() {
+                self.boundary_loss = None;
+                self.boundary_loss_dismissed = true;
+            }
+        });
+    }
+    
+    /// Renderuje pola "Go to (x, y)" i zwraca akcję nawigacji po zatwierdzeniu
+    fn render_goto_controls(&mut self, ui: &mut egui::Ui) -> Option<UserAction> {
+        let mut action = None;
+        
+        ui.label(helpers::subsection_header("Go to cell", &self.styles));
+        ui.add_space(self.styles.dimensions.margin_small);
+        
+        ui.horizontal(|ui| {
+            ui.label(helpers::label_text("X:", &self.styles));
+            let x_response = ui.add(egui::TextEdit::singleline(&mut self.goto_x_input).desired_width(50.0));
+            ui.label(helpers::label_text("Y:", &self.styles));
+            let y_response = ui.add(egui::TextEdit::singleline(&mut self.goto_y_input).desired_width(50.0));
+            
+            // Zatwierdzenie klawiszem Enter w jednym z pól lub przyciskiem
+            let submitted = (x_response.lost_focus() || y_response.lost_focus())
+                && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            
+            if ui.add(helpers::styled_button("🎯 Go", self.styles.colors.button_reset, &self.styles, ButtonType::Small)).clicked() || submitted {
+                match (self.goto_x_input.trim().parse::<usize>(), self.goto_y_input.trim().parse::<usize>()) {
+                    (Ok(x), Ok(y)) => {
+                        self.goto_error = None;
+                        action = Some(UserAction::GoToCell(x, y));
+                    }
+                    _ => {
+                        self.goto_error = Some("Enter non-negative whole numbers".to_string());
+                    }
+                }
+            }
+            
+            if ui.add(helpers::styled_button("⟲ View", self.styles.colors.button_step, &self.styles, ButtonType::Small))
+                .on_hover_text("Reset view to show the whole board")
+                .clicked() {
+                self.goto_error = None;
+                action = Some(UserAction::ResetView);
+            }
+        });
+        
+        if let Some(error) = &self.goto_error {
+            ui.label(RichText::new(error)
+                .font(self.styles.font_id(TextType::Small))
+                .color(self.styles.colors.error));
+        }
+        
+        action
+    }
+    
+    /// Renderuje kontrolki nagrywania, zapisu i odtwarzania nagrań
+    fn render_recording_controls(&mut self, ui: &mut egui::Ui) -> Option<UserAction> {
+        let mut action = None;
+        let is_stopped = self.simulation_state == SimulationState::Stopped;
+        
+        ui.label(helpers::subsection_header("Recording", &self.styles));
+        ui.add_space(self.styles.dimensions.margin_small);
+        
+        ui.horizontal(|ui| {
+            let mut is_recording = self.is_recording;
+            if helpers::styled_checkbox(ui, &mut is_recording, "⏺ Record", &self.styles)
+                .on_hover_text("Keep every generation in memory so the run can be saved and replayed")
+                .changed() {
+                action = Some(UserAction::SetRecording(is_recording));
+            }
+            
+            let has_frames = self.recording_info.is_some_and(|(frames, _)| frames > 0);
+            if ui.add_enabled(has_frames, helpers::styled_button("💾 Save recording", self.styles.colors.text_primary, &self.styles, ButtonType::Small))
+                .on_disabled_hover_text("Record some generations first")
+                .clicked() {
+                action = Some(UserAction::SaveRecording);
+            }
+            if ui.add_enabled(is_stopped, helpers::styled_button("📂 Load", self.styles.colors.text_primary, &self.styles, ButtonType::Small))
+                .on_hover_text("Replay a saved recording frame by frame")
+                .on_disabled_hover_text("Stop the simulation to load a recording")
+                .clicked() {
+                action = Some(UserAction::LoadRecording);
+            }
+        });
+        
+        if let Some((frames, size_bytes)) = self.recording_info {
+            ui.label(helpers::small_text(&format!("{} frames, {:.1} MB", frames, size_bytes as f64 / (1024.0 * 1024.0)), &self.styles));
+        }
+        if let Some(warning) = &self.recording_warning {
+            ui.label(helpers::small_text(warning, &self.styles).color(self.styles.colors.warning));
+        }
+        
+        if let Some((frame, frame_count)) = self.playback_position {
+            ui.horizontal(|ui| {
+                ui.label(helpers::label_text(&format!("Playback: frame {} / {}", frame + 1, frame_count), &self.styles));
+                if ui.add(helpers::styled_button("⏹ Exit playback", self.styles.colors.button_reset, &self.styles, ButtonType::Small))
+                    .on_hover_text("Compute the next generations instead of reading them from the recording")
+                    .clicked() {
+                    action = Some(UserAction::ExitPlayback);
+                }
+            });
+        }
+        
+        if let Some((message, is_error)) = &self.recording_message {
+            let color = if *is_error { self.styles.colors.error } else { self.styles.colors.success };
+            ui.label(helpers::small_text(message, &self.styles).color(color));
+        }
+        
+        action
+    }
+    
+    /// Ustawia stan nagrywania: czy trwa, liczbę klatek i rozmiar oraz ostrzeżenie
+    pub fn set_recording_state(&mut self, is_recording: bool, info: Option<(usize, usize)>, warning: Option<String>) {
+        self.is_recording = is_recording;
+        self.recording_info = info;
+        self.recording_warning = warning;
+    }
+    
+    /// Ustawia pozycję odtwarzania (aktualna klatka, liczba klatek)
+    pub fn set_playback_position(&mut self, position: Option<(usize, usize)>) {
+        self.playback_position = position;
+    }
+    
+    /// Ustawia (lub czyści) komunikat o wyniku zapisu/wczytania nagrania
+    pub fn set_recording_message(&mut self, message: Option<(String, bool)>) {
+        self.recording_message = message;
+    }
+    
+    /// Ustawia (lub czyści) komunikat błędu wklejania planszy ze schowka lub eksportu statystyk
+    pub fn set_board_io_error(&mut self, error: Option<String>) {
+        self.board_io_error = error;
+    }
+    
+    /// Ustawia komunikat błędu nawigacji do współrzędnych
+    pub fn set_goto_error(&mut self, error: Option<String>) {
+        self.goto_error = error;
+    }
+    
+    /// Zwraca kolory (narodziny, śmierć) podglądu rysowanego na planszy
+    /// 
+    /// Bez kolorów motywu są to kolory wybrane w ustawieniach wyglądu (bez przezroczystości).
+    fn preview_colors(&self, config: &GameConfig) -> (egui::Color32, egui::Color32) {
+        if config.appearance.themed_preview_colors {
+            (self.styles.colors.preview_birth, self.styles.colors.preview_death)
+        } else {
+            let [br, bg, bb, _] = config.appearance.birth_preview_color;
+            let [dr, dg, db, _] = config.appearance.death_preview_color;
+            (egui::Color32::from_rgb(br, bg, bb), egui::Color32::from_rgb(dr, dg, db))
+        }
+    }
+    
+    /// Wyświetla parę narodziny/śmierci w kolorach podglądu zmian
+    fn changes_label(&self, ui: &mut egui::Ui, config: &GameConfig, births: u64, deaths: u64) {
+        let (birth_color, death_color) = self.preview_colors(config);
+        let font_id = egui::FontId::monospace(self.styles.dimensions.font_size_medium);
+        ui.label(RichText::new(format!("+{}", births)).font(font_id.clone()).color(birth_color));
+        ui.label(RichText::new("/").font(font_id.clone()).color(self.styles.colors.text_secondary));
+        ui.label(RichText::new(format!("−{}", deaths)).font(font_id).color(death_color));
+    }
+    
     /// Renderuje stylizowaną sekcję ustawień gry
-    fn render_styled_settings(&mut self, ui: &mut egui::Ui) -> SettingsAction {
+    fn render_styled_settings(&mut self, ui: &mut egui::Ui, config: &mut GameConfig) -> SettingsAction {
         // Delegujemy do settings_panel, ale z naszymi stylami
-        self.settings_panel.render_with_styles(ui, &self.styles)
+        self.settings_panel.set_simulation_stopped(self.simulation_state == SimulationState::Stopped);
+        self.settings_panel.render_with_styles(ui, config, &self.styles)
+    }
+    
+    /// Zapisuje wynik ostatniego losowania planszy (wyświetlany w ustawieniach randomizera)
+    pub fn set_last_fill(&mut self, alive_cells: usize, total_cells: usize) {
+        self.settings_panel.set_last_fill(alive_cells, total_cells);
     }
     
     /// Synchronizuje ustawienia z konfiguracją
-    pub fn sync_settings_with_config(&mut self) {
-        self.settings_panel.sync_with_config();
+    pub fn sync_settings_with_config(&mut self, config: &GameConfig) {
+        self.settings_panel.sync_with_config(config);
     }
     
     /// Ustawia wybrany wzór
     pub fn set_selected_pattern(&mut self, pattern_name: Option<String>) {
+        // Nowo wybrany wzór zaczyna w swojej oryginalnej orientacji
+        self.placement_pattern = pattern_name
+            .as_deref()
+            .and_then(|name| self.pattern_selector.get_pattern(name))
+            .cloned();
         self.selected_pattern = pattern_name;
     }
     
+    /// Zwraca wybrany wzór w aktualnej orientacji (do podglądu i umieszczenia)
+    pub fn placement_pattern(&self) -> Option<&crate::assets::Pattern> {
+        self.placement_pattern.as_ref()
+    }
+    
+    /// Obraca wybrany wzór o 90° zgodnie z ruchem wskazówek zegara
+    pub fn rotate_placement_pattern(&mut self) {
+        if let Some(pattern) = &self.placement_pattern {
+            self.placement_pattern = Some(pattern.rotated_cw());
+        }
+    }
+    
+    /// Odbija wybrany wzór w poziomie (true) lub w pionie (false)
+    pub fn mirror_placement_pattern(&mut self, horizontal: bool) {
+        if let Some(pattern) = &self.placement_pattern {
+            self.placement_pattern = Some(if horizontal {
+                pattern.mirrored_horizontal()
+            } else {
+                pattern.mirrored_vertical()
+            });
+        }
+    }
+    
     /// Zwraca aktualnie wybrany wzór
     pub fn selected_pattern(&self) -> Option<&String> {
         self.selected_pattern.as_ref()
@@ -441,4 +1582,59 @@ impl SidePanel {
     pub fn get_pattern(&self, name: &str) -> Option<&crate::assets::Pattern> {
         self.pattern_selector.get_pattern(name)
     }
+    
+    /// Ustawia (lub czyści) komunikat błędu zapisu/wczytywania pliku wzoru
+    pub fn set_pattern_file_error(&mut self, error: Option<String>) {
+        self.pattern_selector.set_file_error(error);
+    }
+    
+    /// Dodaje wzór zapisany z planszy do selektora wzorów
+    pub fn add_pattern(&mut self, pattern: crate::assets::Pattern) {
+        let name = pattern.name.clone();
+        self.pattern_selector.add_pattern(pattern);
+        self.save_pattern_name.clear();
+        self.save_pattern_message = Some((format!("Saved \"{}\"", name), false));
+    }
+    
+    /// Ustawia komunikat błędu zapisu wzoru
+    pub fn set_save_pattern_error(&mut self, error: String) {
+        self.save_pattern_message = Some((error, true));
+    }
+    
+    /// Renderuje pole nazwy i przycisk "Save as Pattern"
+    fn render_save_pattern_controls(&mut self, ui: &mut egui::Ui) -> Option<UserAction> {
+        let mut action = None;
+        let is_stopped = self.simulation_state == SimulationState::Stopped;
+        
+        ui.add_space(self.styles.dimensions.margin_small);
+        ui.group(|ui| {
+            ui.add_enabled_ui(is_stopped, |ui| {
+                ui.label(helpers::subsection_header("Save board as pattern", &self.styles));
+                
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut self.save_pattern_name)
+                        .desired_width(140.0)
+                        .hint_text("Pattern name"));
+                    
+                    if ui.add(helpers::styled_button("💾 Save as Pattern", self.styles.colors.text_primary, &self.styles, ButtonType::Small)).clicked() {
+                        let name = self.save_pattern_name.trim();
+                        if name.is_empty() {
+                            self.save_pattern_message = Some(("Enter a pattern name".to_string(), true));
+                        } else if self.pattern_selector.get_pattern(name).is_some() {
+                            self.save_pattern_message = Some((format!("A pattern named \"{}\" already exists", name), true));
+                        } else {
+                            action = Some(UserAction::SaveAsPattern(name.to_string()));
+                        }
+                    }
+                });
+                
+                if let Some((message, is_error)) = &self.save_pattern_message {
+                    let color = if *is_error { self.styles.colors.error } else { self.styles.colors.success };
+                    ui.label(helpers::small_text(message, &self.styles).color(color));
+                }
+            });
+        });
+        
+        action
+    }
 }
\ No newline at end of file
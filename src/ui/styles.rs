@@ -39,6 +39,10 @@ pub struct ColorPalette {
     pub preview_birth: Color32,
     pub preview_death: Color32,
     
+    // Kolory wieku komórek (gradient od nowo narodzonych do najstarszych)
+    pub age_young: Color32,
+    pub age_old: Color32,
+    
     // Kolory dla efektów
     pub glass_effect: Color32,    // Efekt szkła
     pub border_subtle: Color32,   // Subtelne bordery
@@ -79,6 +83,10 @@ impl Default for ColorPalette {
             preview_birth: Color32::from_rgba_unmultiplied(34, 197, 94, 160),   // Zielony z przezroczystością
             preview_death: Color32::from_rgba_unmultiplied(239, 68, 68, 160),   // Czerwony z przezroczystością
             
+            // Kolory wieku komórek - młode jasne, stare ciemne
+            age_young: Color32::from_rgb(251, 191, 36),     // Jasny bursztyn
+            age_old: Color32::from_rgb(49, 46, 129),        // Ciemne indygo
+            
             // Nowe kolory
             text_disabled: Color32::from_rgb(75, 85, 99),    // Szary dla wyłączonych elementów
             
@@ -184,6 +192,15 @@ impl UIStyles {
             })
     }
     
+    /// Zwraca styl małego znacznika stanu ("chip") w podanym kolorze
+    pub fn chip_style(&self, color: Color32) -> egui::Frame {
+        egui::Frame::new()
+            .fill(color.gamma_multiply(0.2))
+            .stroke(Stroke::new(1.0, color))
+            .corner_radius(CornerRadius::same(8))
+            .inner_margin(Margin::symmetric(6, 1))
+    }
+    
     /// Zwraca styl dla zagnieżdżonej grupy (podsekcji) - bez borderu
     pub fn nested_group_style(&self) -> egui::Frame {
         egui::Frame::group(&egui::Style::default())
@@ -300,6 +317,21 @@ pub mod helpers {
             .color(styles.colors.text_primary)
     }
     
+    /// Renderuje wartość liczbową w polu o stałej szerokości
+    /// 
+    /// Szerokość jest rezerwowana dla `reserved_chars` znaków czcionki monospace,
+    /// dzięki czemu rosnące liczby nie przesuwają sąsiednich elementów układu.
+    pub fn fixed_width_value(ui: &mut egui::Ui, text: &str, reserved_chars: usize, styles: &UIStyles) -> egui::Response {
+        let font_id = FontId::new(styles.dimensions.font_size_medium, FontFamily::Monospace);
+        let char_width = ui.fonts(|fonts| fonts.glyph_width(&font_id, '0'));
+        let row_height = ui.fonts(|fonts| fonts.row_height(&font_id));
+        let width = char_width * reserved_chars.max(text.chars().count()) as f32;
+        
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(width, row_height), egui::Sense::hover());
+        ui.painter().text(rect.left_center(), egui::Align2::LEFT_CENTER, text, font_id, styles.colors.text_primary);
+        response
+    }
+    
     /// Tworzy wyłączony (szary) tekst
     pub fn disabled_text(text: &str, styles: &UIStyles) -> RichText {
         RichText::new(text)
@@ -0,0 +1,83 @@
+/// Pasek zakładek plansz
+/// 
+/// Wyświetla listę otwartych zakładek oraz przyciski do ich tworzenia,
+/// duplikowania i zamykania.
+
+use egui::RichText;
+use crate::logic::tabs::TabStepMode;
+use super::side_panel::UserAction;
+use super::styles::{UIStyles, TextType, helpers};
+
+/// Pasek zakładek nad planszą
+pub struct TabBar {
+    /// Style UI
+    styles: UIStyles,
+}
+
+impl Default for TabBar {
+    fn default() -> Self {
+        Self {
+            styles: UIStyles::new(),
+        }
+    }
+}
+
+impl TabBar {
+    /// Tworzy nowy pasek zakładek
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Renderuje pasek zakładek i zwraca akcję użytkownika
+    pub fn render(
+        &self,
+        ui: &mut egui::Ui,
+        tab_names: &[String],
+        active_tab: usize,
+        step_mode: TabStepMode,
+    ) -> UserAction {
+        let mut action = UserAction::None;
+        
+        ui.horizontal(|ui| {
+            for (index, name) in tab_names.iter().enumerate() {
+                let is_active = index == active_tab;
+                let text = RichText::new(name)
+                    .font(self.styles.font_id(TextType::Medium))
+                    .color(if is_active { self.styles.colors.text_primary } else { self.styles.colors.text_muted });
+                
+                if ui.selectable_label(is_active, text).clicked() && !is_active {
+                    action = UserAction::SwitchTab(index);
+                }
+                
+                // Zamykanie zakładki - zawsze musi pozostać co najmniej jedna
+                if tab_names.len() > 1 && ui.small_button("✕").on_hover_text("Close tab").clicked() {
+                    action = UserAction::CloseTab(index);
+                }
+                
+                ui.separator();
+            }
+            
+            if ui.small_button("➕ New").on_hover_text("Open a new empty board").clicked() {
+                action = UserAction::NewTab;
+            }
+            if ui.small_button("⧉ Duplicate").on_hover_text("Copy the active board into a new tab").clicked() {
+                action = UserAction::DuplicateTab;
+            }
+            
+            ui.separator();
+            
+            let mut step_all = step_mode == TabStepMode::AllTogether;
+            if helpers::styled_checkbox(ui, &mut step_all, "Step all tabs", &self.styles)
+                .on_hover_text("Advance every tab together instead of only the visible one")
+                .changed() {
+                action = UserAction::SetTabStepMode(if step_all {
+                    TabStepMode::AllTogether
+                } else {
+                    TabStepMode::ActiveOnly
+                });
+            }
+        });
+        
+        action
+    }
+}